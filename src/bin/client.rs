@@ -2,7 +2,6 @@
 //! 
 //! Rewrite Redis client in Rust
 //! 
-use drill_redis;
 
 /// This is the entry point for the redis client.
 /// 
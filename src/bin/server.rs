@@ -3,8 +3,6 @@
 //! Rewrite Redis server in Rust
 //! 
 
-use drill_redis;
-
 /// This is the entry point for the redis server.
 /// 
 /// arguments of run function.
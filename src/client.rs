@@ -15,26 +15,22 @@ pub async fn run(addr: impl ToSocketAddrs) -> crate::Result<()> {
     let mut lines_from_stdin = BufReader::new(stdin()).lines().fuse();
     let mut writer = BufWriter::new(&stream);
     let mut reader = BufReader::new(&stream);
-    let mut decoder = Decoder::new();
+    let mut decoder = Decoder::for_responses();
 
     command_pronpt().await?;
-    loop {
-        match lines_from_stdin.next().await {
-            Some(line) => {
-                let line = line?;
-                let iter = line.split_whitespace();
-                let mut array = Vec::new();
+    while let Some(line) = lines_from_stdin.next().await {
+        let line = line?;
+        let iter = line.split_whitespace();
+        let mut array = Vec::new();
+
+        for param in iter {
+            let bulk = Data::Bulk(Vec::from(param.as_bytes()));
+            array.push(bulk);
+        }
+        let cmd = Data::Array(array);
+        let mut encoder = Encoder::new(cmd);
+        encoder.encode(&mut writer).await?;
 
-                for param in iter {
-                    let bulk = Data::Bulk(Vec::from(param.as_bytes()));
-                    array.push(bulk);
-                }
-                let cmd = Data::Array(array);
-                let mut encoder = Encoder::new(cmd);
-                encoder.encode(&mut writer).await?;
-            }
-            None => break,
-        };
         match decoder.decode(&mut reader).await {
             Ok(data) => {
                 display_data(&data)?;
@@ -88,6 +84,9 @@ fn display_data(data: &Data) -> crate::Result<()> {
             println!("(nil)");
         }
         Data::Array(array) => {
+            if array.is_empty() {
+                println!("(empty array)");
+            }
             for item in array {
                 display_data(item)?;
             }
@@ -2,22 +2,184 @@
 //! 
 //! <https://redis.io/commands>
 //! 
+use crate::db;
 use crate::protocol::resp::{Data, Parser};
+use crate::random;
 use async_trait::async_trait;
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
 
 // Refer to command modules
 mod append;
+mod bf_add;
+mod bf_exists;
+mod bf_info;
+mod bf_insert;
+mod bf_reserve;
+mod bitcount;
+mod bitfield;
+mod bitop;
+mod bitpos;
+mod blmove;
+mod blpop;
+mod bzpopmin;
+mod cf_add;
+mod cf_count;
+mod cf_del;
+mod cf_exists;
+mod cms_incrby;
+mod cms_initbydim;
+mod cms_merge;
+mod cms_query;
 mod del;
 mod exists;
 mod expire;
+mod ft_create;
+mod ft_dropindex;
+mod ft_info;
+mod ft_search;
+mod ft_sugadd;
+mod ft_sugdel;
+mod ft_sugget;
+mod ft_suglen;
+mod geoadd;
+mod geodist;
+mod geohash;
+mod geopos;
+mod geosearch;
 mod get;
+mod getbit;
+mod getdel;
 mod getex;
+mod getrange;
+mod getset;
+mod hdel;
+mod hexists;
+mod hget;
+mod hgetall;
+mod hincrby;
+mod hincrbyfloat;
+mod hkeys;
+mod hlen;
+mod hmget;
+mod hrandfield;
+mod hscan;
+mod hset;
+mod hsetnx;
+mod hstrlen;
+mod hvals;
+mod incr;
+mod incrbyfloat;
+mod json_arrappend;
+mod json_arrinsert;
+mod json_arrlen;
+mod json_arrpop;
+mod json_arrtrim;
+mod json_del;
+mod json_get;
+mod json_mget;
+mod json_numincrby;
+mod json_objkeys;
+mod json_objlen;
+mod json_set;
+mod json_strappend;
+mod json_toggle;
+mod json_type;
+mod lcs;
+mod lindex;
+mod linsert;
+mod llen;
+mod lmove;
+mod lpop;
+mod lpos;
+mod lpush;
+mod lrange;
+mod lrem;
+mod lset;
+mod ltrim;
+mod mget;
+mod mset;
 mod persist;
+mod pfadd;
+mod pfcount;
+mod pfmerge;
 mod ping;
+mod sadd;
+mod scard;
 mod set;
+mod setbit;
+mod setex;
+mod setnx;
+mod setrange;
+mod sinter;
+mod sintercard;
+mod sismember;
+mod smembers;
+mod smismember;
+mod smove;
+mod spop;
+mod srandmember;
+mod srem;
+mod sscan;
+mod strlen;
+mod tdigest_add;
+mod tdigest_cdf;
+mod tdigest_create;
+mod tdigest_merge;
+mod tdigest_min;
+mod tdigest_quantile;
+mod tdigest_rank;
+mod tdigest_reset;
+mod tdigest_trimmed_mean;
+mod topk_add;
+mod topk_info;
+mod topk_list;
+mod topk_query;
+mod topk_reserve;
+mod ts_add;
+mod ts_create;
+mod ts_createrule;
+mod ts_incrby;
+mod ts_madd;
+mod ts_mrange;
+mod ts_range;
 mod ttl;
+mod vadd;
+mod vcard;
+mod vdim;
+mod vemb;
+mod vrem;
+mod vsim;
+mod xack;
+mod xadd;
+mod xautoclaim;
+mod xclaim;
+mod xdel;
+mod xgroup;
+mod xinfo;
+mod xlen;
+mod xpending;
+mod xrange;
+mod xread;
+mod xreadgroup;
+mod xtrim;
+mod zadd;
+mod zcard;
+mod zcount;
+mod zincrby;
+mod zlexcount;
+mod zmscore;
+mod zpopmin;
+mod zrandmember;
+mod zrange;
+mod zrank;
+mod zrem;
+mod zremrangebylex;
+mod zremrangebyrank;
+mod zremrangebyscore;
+mod zscore;
+mod zunion;
 
 /// Time unit
 pub(crate) enum TimeUnit {
@@ -25,6 +187,325 @@ pub(crate) enum TimeUnit {
     Millisecond,
 }
 
+/// The end of the list.
+#[derive(Clone, Copy)]
+pub(crate) enum ListEnd {
+    Left,
+    Right,
+}
+
+/// The end of the sorted set.
+#[derive(Clone, Copy)]
+pub(crate) enum ScoreEnd {
+    Min,
+    Max,
+}
+
+/// Operation between multiple sets.
+#[derive(Clone, Copy)]
+pub(crate) enum SetOperation {
+    Inter,
+    Union,
+    Diff,
+}
+
+impl ListEnd {
+    /// Parse LEFT or RIGHT.
+    fn parse(param: &str) -> Option<ListEnd> {
+        match param {
+            "LEFT" => Some(ListEnd::Left),
+            "RIGHT" => Some(ListEnd::Right),
+            _ => None,
+        }
+    }
+    /// Pop an element from this end of the list.
+    fn pop(&self, list: &mut VecDeque<Vec<u8>>) -> Option<Vec<u8>> {
+        match self {
+            ListEnd::Left => list.pop_front(),
+            ListEnd::Right => list.pop_back(),
+        }
+    }
+    /// Push an element to this end of the list.
+    fn push(&self, list: &mut VecDeque<Vec<u8>>, element: Vec<u8>) {
+        match self {
+            ListEnd::Left => list.push_front(element),
+            ListEnd::Right => list.push_back(element),
+        }
+    }
+}
+
+/// Convert the start and stop index, which may be negative, into an inclusive range.
+/// Return None if the range is empty.
+pub(crate) fn range_index(start: i64, stop: i64, len: usize) -> Option<(usize, usize)> {
+    let len = len as i64;
+    let start = if start < 0 { (start + len).max(0) } else { start };
+    let stop = if stop < 0 { stop + len } else { stop.min(len - 1) };
+
+    if start > stop || start >= len {
+        None
+    } else {
+        Some((start as usize, stop as usize))
+    }
+}
+/// Convert the index, which may be negative, into a position.
+/// Return None if the index is out of range.
+pub(crate) fn position(index: i64, len: usize) -> Option<usize> {
+    let index = if index < 0 { index + len as i64 } else { index };
+
+    if 0 <= index && index < len as i64 {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+/// Parse the timeout of the blocking commands in seconds.
+/// Zero means blocking indefinitely.
+pub(crate) fn timeout(bytes: &[u8]) -> crate::Result<Option<Duration>> {
+    let timeout: f64 = match std::str::from_utf8(bytes)?.parse() {
+        Ok(timeout) => timeout,
+        Err(_) => return Err("timeout is not a float or out of range".into()),
+    };
+    if timeout < 0.0 {
+        Err("timeout is negative".into())
+    } else if timeout == 0.0 {
+        Ok(None)
+    } else if timeout.is_finite() {
+        Ok(Some(Duration::from_secs_f64(timeout)))
+    } else {
+        Err("timeout is out of range".into())
+    }
+}
+/// Parse the integer value strictly like Redis.
+/// No sign other than '-', no leading zeros, and no spaces are allowed.
+pub(crate) fn parse_integer(bytes: &[u8]) -> Option<i64> {
+    let digits = bytes.strip_prefix(b"-").unwrap_or(bytes);
+    if digits.is_empty()
+        || !digits.iter().all(u8::is_ascii_digit)
+        || (digits[0] == b'0' && bytes.len() > 1)
+    {
+        return None;
+    }
+    std::str::from_utf8(bytes).ok()?.parse().ok()
+}
+/// Parse the float value.
+/// Return None if the value is not a float or is NaN.
+pub(crate) fn parse_float(bytes: &[u8]) -> Option<f64> {
+    match std::str::from_utf8(bytes).ok()?.parse::<f64>() {
+        Ok(value) if !value.is_nan() => Some(value),
+        _ => None,
+    }
+}
+/// Format the float value for the response.
+pub(crate) fn format_float(value: f64) -> Vec<u8> {
+    if value.is_infinite() {
+        if value > 0.0 {
+            b"inf".to_vec()
+        } else {
+            b"-inf".to_vec()
+        }
+    } else {
+        value.to_string().into_bytes()
+    }
+}
+/// Pick random indexes for the commands that return random elements.
+/// Positive count picks distinct indexes, and negative count may pick the same index repeatedly.
+/// Negative count is limited to the same range as Redis.
+pub(crate) fn random_indexes(len: usize, count: i64) -> crate::Result<Vec<usize>> {
+    if count < -i64::MAX / 2 {
+        return Err("value is out of range".into());
+    }
+    if len == 0 {
+        return Ok(Vec::new());
+    }
+    if count < 0 {
+        // Not sized from the count, so the vector grows only as the indexes are picked.
+        let mut indexes = Vec::new();
+        for _ in 0..count.unsigned_abs() {
+            indexes.push(random::below(len));
+        }
+        return Ok(indexes);
+    }
+    // Floyd's sampling, so the time and memory depend on the count instead of the length.
+    let count = (count as usize).min(len);
+    let mut picked = HashSet::with_capacity(count);
+    let mut indexes = Vec::with_capacity(count);
+    for j in len - count..len {
+        let t = random::below(j + 1);
+        let index = if picked.insert(t) { t } else { j };
+        picked.insert(index);
+        indexes.push(index);
+    }
+    // Floyd's sampling doesn't randomize the order.
+    for i in (1..indexes.len()).rev() {
+        indexes.swap(i, random::below(i + 1));
+    }
+    Ok(indexes)
+}
+/// Pick random elements from the iterator in the same way as `random_indexes`.
+/// The iterator is walked once up to the last picked index without collecting the elements.
+pub(crate) fn random_elements<I>(iter: I, count: i64) -> crate::Result<Vec<I::Item>>
+where
+    I: ExactSizeIterator,
+    I::Item: Copy,
+{
+    let indexes = random_indexes(iter.len(), count)?;
+    let mut wanted = indexes.clone();
+    wanted.sort_unstable();
+    wanted.dedup();
+
+    let mut picked = HashMap::with_capacity(wanted.len());
+    let mut wanted = wanted.into_iter().peekable();
+    for (index, element) in iter.enumerate() {
+        match wanted.peek() {
+            Some(&next) if next == index => {
+                picked.insert(index, element);
+                wanted.next();
+            }
+            Some(_) => {}
+            None => break,
+        }
+    }
+    Ok(indexes.into_iter().map(|index| picked[&index]).collect())
+}
+/// Response of the sorted set members with or without scores.
+pub(crate) fn scored_members(members: Vec<(&[u8], f64)>, with_scores: bool) -> Data {
+    let mut array = Vec::with_capacity(members.len() * if with_scores { 2 } else { 1 });
+    for (member, score) in members {
+        array.push(Data::Bulk(member.to_vec()));
+        if with_scores {
+            array.push(Data::Bulk(format_float(score)));
+        }
+    }
+    Data::Array(array)
+}
+/// Response of the stream entry.
+pub(crate) fn stream_entry(id: db::StreamId, fields: db::Fields) -> Data {
+    let mut array = Vec::with_capacity(fields.len() * 2);
+    for (field, value) in fields {
+        array.push(Data::Bulk(field));
+        array.push(Data::Bulk(value));
+    }
+    Data::Array(vec![Data::Bulk(id.to_bytes()), Data::Array(array)])
+}
+/// Response of the stream entries.
+pub(crate) fn stream_entries(entries: Vec<(db::StreamId, db::Fields)>) -> Data {
+    Data::Array(
+        entries
+            .into_iter()
+            .map(|(id, fields)| stream_entry(id, fields))
+            .collect(),
+    )
+}
+/// Error message of the invalid stream ID.
+pub(crate) const INVALID_STREAM_ID: &str = "Invalid stream ID specified as stream command argument";
+/// Error response of the missing stream or consumer group.
+pub(crate) fn no_group(key: &[u8], group: &[u8]) -> Data {
+    Data::Error(
+        format!(
+            "NOGROUP No such key '{}' or consumer group '{}'",
+            String::from_utf8_lossy(key),
+            String::from_utf8_lossy(group)
+        )
+        .into_bytes(),
+    )
+}
+/// Expiration date in Unix time milliseconds.
+///
+/// The time is a Unix time if `absolute` is true, otherwise relative to now.
+/// Return None on overflow.
+pub(crate) fn expiration(time: i64, time_unit: &TimeUnit, absolute: bool) -> Option<i64> {
+    let time = match time_unit {
+        TimeUnit::Second => time.checked_mul(1000)?,
+        TimeUnit::Millisecond => time,
+    };
+    if absolute {
+        Some(time)
+    } else {
+        time.checked_add(db::unix_time_ms() as i64)
+    }
+}
+
+/// Error message of the JSON commands on the missing key.
+pub(crate) const NO_JSON_KEY: &str = "could not perform this operation on a key that doesn't exist";
+/// Parse the path of the JSON commands.
+pub(crate) fn json_path(bytes: &[u8]) -> crate::Result<db::JsonPath> {
+    match db::JsonPath::parse(bytes) {
+        Some(path) => Ok(path),
+        None => Err(format!("invalid JSON path '{}'", String::from_utf8_lossy(bytes)).into()),
+    }
+}
+/// Parse the value of the JSON commands.
+pub(crate) fn json_value(bytes: &[u8]) -> crate::Result<db::Json> {
+    Ok(db::Json::parse(bytes)?)
+}
+/// Apply the function to the JSON values at the path.
+///
+/// The function returns the response, or the type name of the value if it is not applicable.
+pub(crate) fn json_apply<F>(
+    json: &mut db::Json,
+    path: &db::JsonPath,
+    mut apply: F,
+) -> Vec<Result<Data, &'static str>>
+where
+    F: FnMut(&mut db::Json) -> Result<Data, &'static str>,
+{
+    path.locate(json)
+        .iter()
+        .map(|location| match json.get_mut(location) {
+            Some(value) => apply(value),
+            // The location has been moved by the previous update.
+            None => Ok(Data::NullBulk),
+        })
+        .collect()
+}
+/// Response of the results of the JSON command.
+///
+/// JSONPath responds the array of the results, where the inapplicable values are null.
+/// The legacy path responds the first result, or the error.
+pub(crate) fn json_response(
+    path: &[u8],
+    legacy: bool,
+    results: Vec<Result<Data, &'static str>>,
+    expected: &str,
+) -> Data {
+    if !legacy {
+        return Data::Array(
+            results
+                .into_iter()
+                .map(|result| result.unwrap_or(Data::NullBulk))
+                .collect(),
+        );
+    }
+    match results.into_iter().next() {
+        Some(Ok(response)) => response,
+        Some(Err(found)) => Data::error(&format!(
+            "wrong type of path value - expected {} but found {}",
+            expected, found
+        )),
+        None => Data::error(&format!(
+            "Path '{}' does not exist",
+            String::from_utf8_lossy(path)
+        )),
+    }
+}
+
+/// Parse the cursor of the SCAN family commands.
+pub(crate) fn cursor(bytes: &[u8]) -> crate::Result<u64> {
+    match std::str::from_utf8(bytes)?.parse() {
+        Ok(cursor) => Ok(cursor),
+        Err(_) => Err("invalid cursor".into()),
+    }
+}
+/// Convert the error into the error response.
+pub(crate) fn error_response(e: &crate::Error) -> Data {
+    match e.downcast_ref::<db::Error>() {
+        // Database errors have their own error prefix.
+        Some(db_err) => Data::Error(db_err.to_string().into_bytes()),
+        None => Data::error(&format!("{}", e)),
+    }
+}
+
 /// Next bytes macro.
 /// If command stream is EOF, return OK(error response).
 macro_rules! next_bytes {
@@ -35,11 +516,21 @@ macro_rules! next_bytes {
         }
     };
 }
-/// Next u64 macro.
+/// Next i64 macro.
+/// If command stream is EOF, return OK(error response).
+macro_rules! next_i64 {
+    ($cmd:expr) => {
+        match $cmd.next_i64()? {
+            Some(key) => key,
+            None => return Ok(Data::error("wrong number of arguments for command")),
+        }
+    };
+}
+/// Next string macro.
 /// If command stream is EOF, return OK(error response).
-macro_rules! next_u64 {
+macro_rules! next_string {
     ($cmd:expr) => {
-        match $cmd.next_u64()? {
+        match $cmd.next_string()? {
             Some(key) => key,
             None => return Ok(Data::error("wrong number of arguments for command")),
         }
@@ -59,12 +550,13 @@ macro_rules! check_end_of_param {
 // Allow macros to be used outside of this module.
 pub(crate) use check_end_of_param;
 pub(crate) use next_bytes;
-pub(crate) use next_u64;
+pub(crate) use next_i64;
+pub(crate) use next_string;
 
 /// Command type definition
 pub(crate) type Cmd = Box<dyn Command + Send + Sync>;
 /// Commnad manager singleton
-static COMMANDS: Lazy<CommandManager> = Lazy::new(|| CommandManager::new());
+static COMMANDS: Lazy<CommandManager> = Lazy::new(CommandManager::new);
 /// Command manager
 struct CommandManager {
     commands: HashMap<String, Cmd>,
@@ -77,16 +569,215 @@ impl CommandManager {
             commands: HashMap::from([
                 get::command(),
                 set::command(),
-                ttl::command(TimeUnit::Second),
-                ttl::command(TimeUnit::Millisecond),
+                ttl::command(TimeUnit::Second, false),
+                ttl::command(TimeUnit::Millisecond, false),
                 del::command(),
                 exists::command(),
                 ping::command(),
                 persist::command(),
                 append::command(),
                 getex::command(),
-                expire::command(TimeUnit::Second),
-                expire::command(TimeUnit::Millisecond),
+                expire::command(TimeUnit::Second, false),
+                expire::command(TimeUnit::Millisecond, false),
+                lpush::command(ListEnd::Left, false),
+                lpush::command(ListEnd::Right, false),
+                lpush::command(ListEnd::Left, true),
+                lpush::command(ListEnd::Right, true),
+                lpop::command(ListEnd::Left),
+                lpop::command(ListEnd::Right),
+                lrange::command(),
+                lindex::command(),
+                lset::command(),
+                lrem::command(),
+                linsert::command(),
+                ltrim::command(),
+                llen::command(),
+                lpos::command(),
+                lmove::command(),
+                blpop::command(ListEnd::Left),
+                blpop::command(ListEnd::Right),
+                blmove::command(),
+                hset::command(),
+                hsetnx::command(),
+                hget::command(),
+                hmget::command(),
+                hdel::command(),
+                hexists::command(),
+                hlen::command(),
+                hkeys::command(),
+                hvals::command(),
+                hgetall::command(),
+                hincrby::command(),
+                hincrbyfloat::command(),
+                hstrlen::command(),
+                hrandfield::command(),
+                hscan::command(),
+                sadd::command(),
+                srem::command(),
+                sismember::command(),
+                smismember::command(),
+                smembers::command(),
+                scard::command(),
+                spop::command(),
+                srandmember::command(),
+                smove::command(),
+                sinter::command(SetOperation::Inter, false),
+                sinter::command(SetOperation::Union, false),
+                sinter::command(SetOperation::Diff, false),
+                sinter::command(SetOperation::Inter, true),
+                sinter::command(SetOperation::Union, true),
+                sinter::command(SetOperation::Diff, true),
+                sintercard::command(),
+                sscan::command(),
+                zadd::command(),
+                zcard::command(),
+                zrange::command(false),
+                zrange::command(true),
+                zrank::command(false),
+                zrank::command(true),
+                zscore::command(),
+                zmscore::command(),
+                zincrby::command(),
+                zrem::command(),
+                zremrangebyrank::command(),
+                zremrangebyscore::command(),
+                zremrangebylex::command(),
+                zcount::command(),
+                zlexcount::command(),
+                zpopmin::command(ScoreEnd::Min),
+                zpopmin::command(ScoreEnd::Max),
+                bzpopmin::command(ScoreEnd::Min),
+                bzpopmin::command(ScoreEnd::Max),
+                zrandmember::command(),
+                zunion::command(SetOperation::Union, false),
+                zunion::command(SetOperation::Inter, false),
+                zunion::command(SetOperation::Diff, false),
+                zunion::command(SetOperation::Union, true),
+                zunion::command(SetOperation::Inter, true),
+                zunion::command(SetOperation::Diff, true),
+                xadd::command(),
+                xrange::command(false),
+                xrange::command(true),
+                xlen::command(),
+                xdel::command(),
+                xtrim::command(),
+                xinfo::command(),
+                xread::command(),
+                xgroup::command(),
+                xreadgroup::command(),
+                xack::command(),
+                xpending::command(),
+                xclaim::command(),
+                xautoclaim::command(),
+                incr::command(false, false),
+                incr::command(true, false),
+                incr::command(false, true),
+                incr::command(true, true),
+                incrbyfloat::command(),
+                getrange::command("GETRANGE"),
+                getrange::command("SUBSTR"),
+                setrange::command(),
+                strlen::command(),
+                mget::command(),
+                mset::command(false),
+                mset::command(true),
+                setnx::command(),
+                setex::command(TimeUnit::Second),
+                setex::command(TimeUnit::Millisecond),
+                getset::command(),
+                getdel::command(),
+                expire::command(TimeUnit::Second, true),
+                expire::command(TimeUnit::Millisecond, true),
+                ttl::command(TimeUnit::Second, true),
+                ttl::command(TimeUnit::Millisecond, true),
+                setbit::command(),
+                getbit::command(),
+                bitcount::command(),
+                bitpos::command(),
+                bitop::command(),
+                bitfield::command(false),
+                bitfield::command(true),
+                pfadd::command(),
+                pfcount::command(),
+                pfmerge::command(),
+                geoadd::command(),
+                geodist::command(),
+                geohash::command(),
+                geopos::command(),
+                geosearch::command(false),
+                geosearch::command(true),
+                lcs::command(),
+                json_set::command(),
+                json_get::command(),
+                json_del::command(),
+                json_type::command(),
+                json_mget::command(),
+                json_numincrby::command(),
+                json_strappend::command(),
+                json_arrappend::command(),
+                json_arrinsert::command(),
+                json_arrpop::command(),
+                json_arrtrim::command(),
+                json_arrlen::command(),
+                json_objkeys::command(),
+                json_objlen::command(),
+                json_toggle::command(),
+                bf_reserve::command(),
+                bf_add::command(false),
+                bf_add::command(true),
+                bf_exists::command(false),
+                bf_exists::command(true),
+                bf_insert::command(),
+                bf_info::command(),
+                cf_add::command(false),
+                cf_add::command(true),
+                cf_del::command(),
+                cf_exists::command(),
+                cf_count::command(),
+                cms_initbydim::command(false),
+                cms_initbydim::command(true),
+                cms_incrby::command(),
+                cms_query::command(),
+                cms_merge::command(),
+                topk_reserve::command(),
+                topk_add::command(false),
+                topk_add::command(true),
+                topk_query::command(),
+                topk_list::command(),
+                topk_info::command(),
+                tdigest_create::command(),
+                tdigest_add::command(),
+                tdigest_quantile::command(),
+                tdigest_cdf::command(),
+                tdigest_rank::command(false),
+                tdigest_rank::command(true),
+                tdigest_trimmed_mean::command(),
+                tdigest_min::command(ScoreEnd::Min),
+                tdigest_min::command(ScoreEnd::Max),
+                tdigest_merge::command(),
+                tdigest_reset::command(),
+                ts_create::command(),
+                ts_add::command(),
+                ts_madd::command(),
+                ts_incrby::command(),
+                ts_range::command(false),
+                ts_range::command(true),
+                ts_mrange::command(),
+                ts_createrule::command(),
+                vadd::command(),
+                vrem::command(),
+                vsim::command(),
+                vcard::command(),
+                vdim::command(),
+                vemb::command(),
+                ft_create::command(),
+                ft_search::command(),
+                ft_dropindex::command(),
+                ft_info::command(),
+                ft_sugadd::command(),
+                ft_sugget::command(),
+                ft_sugdel::command(),
+                ft_suglen::command(),
             ]),
         }
     }
@@ -97,7 +788,7 @@ impl CommandManager {
                 if let Some(cmd_func) = self.commands.get(&cmd_name) {
                     match cmd_func.execute(cmd).await {
                         Ok(response) => response,
-                        Err(e) => Data::error(&format!("{}", e)),
+                        Err(e) => error_response(&e),
                     }
                 } else {
                     Data::error(&format!("Unknown or disabled command '{}'", cmd_name))
@@ -115,6 +806,30 @@ pub(crate) trait Command {
     async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data>;
 }
 
+/// Check if the command may block the client until it is served or times out.
+/// XREAD and XREADGROUP block only with the BLOCK option.
+pub(crate) fn is_blocking(cmd: &Data) -> bool {
+    let params = match cmd {
+        Data::Array(params) => params,
+        _ => return false,
+    };
+    let mut names = params.iter().map(|param| match param {
+        Data::Bulk(bulk) => bulk.to_ascii_uppercase(),
+        _ => Vec::new(),
+    });
+    let skip = match names.next().as_deref() {
+        Some(b"BLPOP" | b"BRPOP" | b"BLMOVE" | b"BZPOPMIN" | b"BZPOPMAX") => return true,
+        Some(b"XREAD") => 0,
+        // Skip GROUP group consumer.
+        Some(b"XREADGROUP") => 3,
+        _ => return false,
+    };
+    names
+        .skip(skip)
+        .take_while(|option| option != b"STREAMS")
+        .any(|option| option == b"BLOCK")
+}
+
 /// Execute command.
 pub(crate) async fn execute(cmd: Data) -> crate::Result<Data> {
     if let Some(mut parser) = Parser::new(cmd) {
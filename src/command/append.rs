@@ -24,7 +24,7 @@ impl super::Command for Append {
         let key = super::next_bytes!(cmd);
         let value = super::next_bytes!(cmd);
 
-        let length = db::DB.write().await.append(key, value);
+        let length = db::DB.write().await.append(key, value)?;
 
         Ok(Data::Integer(length as i64))
     }
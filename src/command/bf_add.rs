@@ -0,0 +1,71 @@
+//! BF.ADD, BF.MADD command
+//!
+//! # command syntax
+//! BF.ADD key item
+//!
+//! <https://redis.io/commands/bf.add>
+//!
+//! BF.MADD key item [item ...]
+//!
+//! <https://redis.io/commands/bf.madd>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BfAdd commnad struct
+pub(super) struct BfAdd {
+    /// Add multiple items.
+    multiple: bool,
+}
+
+/// command register function
+pub(super) fn command(multiple: bool) -> (String, super::Cmd) {
+    let name = if multiple { "BF.MADD" } else { "BF.ADD" };
+    (String::from(name), Box::new(BfAdd { multiple }))
+}
+
+/// Add the item and return the response.
+pub(super) fn add(filter: &mut db::BloomFilter, item: &[u8]) -> Data {
+    match filter.add(item) {
+        Ok(added) => Data::Integer(added as i64),
+        Err(_) => Data::error("non scaling filter is full"),
+    }
+}
+
+#[async_trait]
+impl super::Command for BfAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut items = vec![super::next_bytes!(cmd)];
+        if self.multiple {
+            while let Some(item) = cmd.next_bytes()? {
+                items.push(item);
+            }
+        }
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let filter = db
+            .get_or_insert_with(key, || {
+                db::Value::BloomFilter(
+                    db::BloomFilter::new(
+                        db::BloomFilter::DEFAULT_CAPACITY,
+                        db::BloomFilter::DEFAULT_ERROR_RATE,
+                        Some(db::BloomFilter::DEFAULT_EXPANSION),
+                    )
+                    .unwrap(),
+                )
+            })
+            .value
+            .as_bloom_filter_mut()?;
+        let mut responses: Vec<Data> = items.iter().map(|item| add(filter, item)).collect();
+        if self.multiple {
+            Ok(Data::Array(responses))
+        } else {
+            Ok(responses.pop().unwrap())
+        }
+    }
+}
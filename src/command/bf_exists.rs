@@ -0,0 +1,57 @@
+//! BF.EXISTS, BF.MEXISTS command
+//!
+//! # command syntax
+//! BF.EXISTS key item
+//!
+//! <https://redis.io/commands/bf.exists>
+//!
+//! BF.MEXISTS key item [item ...]
+//!
+//! <https://redis.io/commands/bf.mexists>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BfExists commnad struct
+pub(super) struct BfExists {
+    /// Check multiple items.
+    multiple: bool,
+}
+
+/// command register function
+pub(super) fn command(multiple: bool) -> (String, super::Cmd) {
+    let name = if multiple { "BF.MEXISTS" } else { "BF.EXISTS" };
+    (String::from(name), Box::new(BfExists { multiple }))
+}
+
+#[async_trait]
+impl super::Command for BfExists {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut items = vec![super::next_bytes!(cmd)];
+        if self.multiple {
+            while let Some(item) = cmd.next_bytes()? {
+                items.push(item);
+            }
+        }
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let filter = match db.get(&key) {
+            Some(entry) => Some(entry.value.as_bloom_filter()?),
+            None => None,
+        };
+        let mut responses: Vec<Data> = items
+            .iter()
+            .map(|item| Data::Integer(filter.is_some_and(|filter| filter.contains(item)) as i64))
+            .collect();
+        if self.multiple {
+            Ok(Data::Array(responses))
+        } else {
+            Ok(responses.pop().unwrap())
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! BF.INFO command
+//!
+//! # command syntax
+//! BF.INFO key [CAPACITY | SIZE | FILTERS | ITEMS | EXPANSION]
+//!
+//! <https://redis.io/commands/bf.info>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BfInfo commnad empty struct
+pub(super) struct BfInfo;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("BF.INFO"), Box::new(BfInfo))
+}
+
+#[async_trait]
+impl super::Command for BfInfo {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let field = cmd.next_string()?;
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let filter = match db.get(&key) {
+            Some(entry) => entry.value.as_bloom_filter()?,
+            None => return Ok(Data::error("not found")),
+        };
+        let fields = [
+            (
+                "CAPACITY",
+                "Capacity",
+                Data::Integer(filter.capacity() as i64),
+            ),
+            ("SIZE", "Size", Data::Integer(filter.size() as i64)),
+            (
+                "FILTERS",
+                "Number of filters",
+                Data::Integer(filter.filters() as i64),
+            ),
+            (
+                "ITEMS",
+                "Number of items inserted",
+                Data::Integer(filter.items() as i64),
+            ),
+            (
+                "EXPANSION",
+                "Expansion rate",
+                filter
+                    .expansion()
+                    .map_or(Data::NullBulk, |expansion| Data::Integer(expansion as i64)),
+            ),
+        ];
+        match field {
+            Some(field) => match fields.into_iter().find(|(name, _, _)| *name == field) {
+                Some((_, _, value)) => Ok(Data::Array(vec![value])),
+                None => Ok(Data::error("Invalid information value")),
+            },
+            None => Ok(Data::Array(
+                fields
+                    .into_iter()
+                    .flat_map(|(_, label, value)| [Data::Bulk(label.as_bytes().to_vec()), value])
+                    .collect(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! BF.INSERT command
+//!
+//! # command syntax
+//! BF.INSERT key [CAPACITY capacity] [ERROR error] [EXPANSION expansion] \[NOCREATE\]
+//!   \[NONSCALING\] ITEMS item [item ...]
+//!
+//! <https://redis.io/commands/bf.insert>
+//!
+use super::bf_add::add;
+use super::bf_reserve::{capacity, error_rate, expansion, new_filter};
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BfInsert commnad empty struct
+pub(super) struct BfInsert;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("BF.INSERT"), Box::new(BfInsert))
+}
+
+#[async_trait]
+impl super::Command for BfInsert {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut capacity_option = None;
+        let mut error_rate_option = None;
+        let mut expansion_option = None;
+        let (mut no_create, mut non_scaling) = (false, false);
+        loop {
+            match super::next_string!(cmd).as_str() {
+                "CAPACITY" => capacity_option = Some(capacity(super::next_i64!(cmd))?),
+                "ERROR" => error_rate_option = Some(error_rate(&super::next_bytes!(cmd))?),
+                "EXPANSION" => expansion_option = Some(expansion(super::next_i64!(cmd))?),
+                "NOCREATE" => no_create = true,
+                "NONSCALING" => non_scaling = true,
+                "ITEMS" => break,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+        let mut items = vec![super::next_bytes!(cmd)];
+        while let Some(item) = cmd.next_bytes()? {
+            items.push(item);
+        }
+        if no_create && (capacity_option.is_some() || error_rate_option.is_some()) {
+            return Ok(Data::error(
+                "NOCREATE cannot be used together with CAPACITY or ERROR",
+            ));
+        }
+        if non_scaling && expansion_option.is_some() {
+            return Ok(Data::error("Nonscaling filters cannot expand"));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let filter = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_bloom_filter_mut()?,
+            None if no_create => return Ok(Data::error("not found")),
+            None => {
+                let expansion = if non_scaling {
+                    None
+                } else {
+                    Some(expansion_option.unwrap_or(db::BloomFilter::DEFAULT_EXPANSION))
+                };
+                let filter = new_filter(
+                    capacity_option.unwrap_or(db::BloomFilter::DEFAULT_CAPACITY),
+                    error_rate_option.unwrap_or(db::BloomFilter::DEFAULT_ERROR_RATE),
+                    expansion,
+                )?;
+                db.get_or_insert_with(key, || db::Value::BloomFilter(filter))
+                    .value
+                    .as_bloom_filter_mut()?
+            }
+        };
+        Ok(Data::Array(
+            items.iter().map(|item| add(filter, item)).collect(),
+        ))
+    }
+}
@@ -0,0 +1,99 @@
+//! BF.RESERVE command
+//!
+//! # command syntax
+//! BF.RESERVE key error_rate capacity [EXPANSION expansion] \[NONSCALING\]
+//!
+//! <https://redis.io/commands/bf.reserve>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BfReserve commnad empty struct
+pub(super) struct BfReserve;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("BF.RESERVE"), Box::new(BfReserve))
+}
+
+/// Parse the error rate between 0 and 1, exclusive.
+pub(super) fn error_rate(bytes: &[u8]) -> crate::Result<f64> {
+    match super::parse_float(bytes) {
+        Some(error_rate) if 0.0 < error_rate && error_rate < 1.0 => Ok(error_rate),
+        Some(_) => Err("(0 < error rate range < 1)".into()),
+        None => Err("bad error rate".into()),
+    }
+}
+
+/// Check the capacity is positive.
+pub(super) fn capacity(capacity: i64) -> crate::Result<u64> {
+    if capacity > 0 {
+        Ok(capacity as u64)
+    } else {
+        Err("(capacity should be larger than 0)".into())
+    }
+}
+
+/// Check the expansion is positive and not larger than the maximum.
+pub(super) fn expansion(expansion: i64) -> crate::Result<u64> {
+    if expansion < 1 {
+        Err("expansion should be greater or equal to 1".into())
+    } else if expansion as u64 > db::BloomFilter::MAX_EXPANSION {
+        Err(format!(
+            "expansion should be less or equal to {}",
+            db::BloomFilter::MAX_EXPANSION
+        )
+        .into())
+    } else {
+        Ok(expansion as u64)
+    }
+}
+
+/// Create the filter.
+pub(super) fn new_filter(
+    capacity: u64,
+    error_rate: f64,
+    expansion: Option<u64>,
+) -> crate::Result<db::BloomFilter> {
+    match db::BloomFilter::new(capacity, error_rate, expansion) {
+        Some(filter) => Ok(filter),
+        None => Err("filter size is too large".into()),
+    }
+}
+
+#[async_trait]
+impl super::Command for BfReserve {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let error_rate = error_rate(&super::next_bytes!(cmd))?;
+        let capacity = capacity(super::next_i64!(cmd))?;
+        let mut expansion_rate = None;
+        let mut non_scaling = false;
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "EXPANSION" => expansion_rate = Some(expansion(super::next_i64!(cmd))?),
+                "NONSCALING" => non_scaling = true,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+        if non_scaling && expansion_rate.is_some() {
+            return Ok(Data::error("Nonscaling filters cannot expand"));
+        }
+        let expansion_rate = if non_scaling {
+            None
+        } else {
+            Some(expansion_rate.unwrap_or(db::BloomFilter::DEFAULT_EXPANSION))
+        };
+
+        let mut db = db::DB.write().await;
+
+        if db.get(&key).is_some() {
+            return Ok(Data::error("item exists"));
+        }
+        let filter = new_filter(capacity, error_rate, expansion_rate)?;
+        db.insert(key, db::Value::BloomFilter(filter));
+        Ok(Data::ok())
+    }
+}
@@ -0,0 +1,93 @@
+//! BITCOUNT command
+//!
+//! # command syntax
+//! BITCOUNT key [start end [BYTE|BIT]]
+//!
+//! <https://redis.io/commands/bitcount>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BitCount commnad empty struct
+pub(super) struct BitCount;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("BITCOUNT"), Box::new(BitCount))
+}
+
+/// Parse BYTE or BIT.
+/// Return true for BIT.
+pub(super) fn bit_unit(param: &str) -> Option<bool> {
+    match param {
+        "BYTE" => Some(false),
+        "BIT" => Some(true),
+        _ => None,
+    }
+}
+
+/// Convert the start and end indexes, which may be negative, into an inclusive bit range.
+/// The indexes are bit indexes if `bit` is true, otherwise byte indexes.
+/// Return None if the range is empty.
+pub(super) fn bit_range(start: i64, end: i64, len: usize, bit: bool) -> Option<(usize, usize)> {
+    if start < 0 && end < 0 && start > end {
+        return None;
+    }
+    let total = if bit { len as i64 * 8 } else { len as i64 };
+    let start = if start < 0 {
+        (start + total).max(0)
+    } else {
+        start
+    };
+    let end = if end < 0 {
+        (end + total).max(0)
+    } else {
+        end.min(total - 1)
+    };
+    if start > end || total == 0 {
+        None
+    } else if bit {
+        Some((start as usize, end as usize))
+    } else {
+        Some((start as usize * 8, end as usize * 8 + 7))
+    }
+}
+
+#[async_trait]
+impl super::Command for BitCount {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let range = match cmd.next_i64()? {
+            Some(start) => {
+                let end = match cmd.next_i64()? {
+                    Some(end) => end,
+                    None => return Ok(Data::error("syntax error")),
+                };
+                let bit = match cmd.next_string()? {
+                    Some(param) => match bit_unit(&param) {
+                        Some(bit) => bit,
+                        None => return Ok(Data::error("syntax error")),
+                    },
+                    None => false,
+                };
+                super::check_end_of_param!(cmd);
+                (start, end, bit)
+            }
+            None => (0, -1, false),
+        };
+
+        let db = db::DB.read().await;
+
+        let string = match db.get(&key) {
+            Some(entry) => entry.value.as_string()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        let (start, end, bit) = range;
+        match bit_range(start, end, string.len(), bit) {
+            Some((start, end)) => Ok(Data::Integer(db::bitcount(&string, start, end) as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
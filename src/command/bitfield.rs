@@ -0,0 +1,198 @@
+//! BITFIELD, BITFIELD_RO command
+//!
+//! # command syntax
+//! BITFIELD key [GET encoding offset | [OVERFLOW <WRAP | SAT | FAIL>]
+//!   <SET encoding offset value | INCRBY encoding offset increment>
+//!   [GET encoding offset | [OVERFLOW <WRAP | SAT | FAIL>]
+//!   <SET encoding offset value | INCRBY encoding offset increment>
+//!   ...]]
+//!
+//! <https://redis.io/commands/bitfield>
+//!
+//! BITFIELD_RO key [GET encoding offset [GET encoding offset ...]]
+//!
+//! <https://redis.io/commands/bitfield_ro>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// BitField commnad struct
+pub(super) struct BitField {
+    /// Only GET is allowed.
+    read_only: bool,
+}
+
+/// command register function
+pub(super) fn command(read_only: bool) -> (String, super::Cmd) {
+    let name = if read_only { "BITFIELD_RO" } else { "BITFIELD" };
+    (String::from(name), Box::new(BitField { read_only }))
+}
+
+/// Operation on the field.
+enum Operation {
+    Get,
+    Set(i64),
+    IncrBy(i64),
+}
+
+/// Integer field in the string.
+struct Field {
+    operation: Operation,
+    /// Bit offset.
+    offset: usize,
+    /// Width in bits.
+    bits: u32,
+    signed: bool,
+    overflow: db::Overflow,
+}
+
+/// Parse the encoding such as i16 and u8.
+fn encoding(param: &str) -> Option<(u32, bool)> {
+    let (signed, bits) = match param.split_at(param.len().min(1)) {
+        ("I", bits) => (true, bits),
+        ("U", bits) => (false, bits),
+        _ => return None,
+    };
+    match bits.parse() {
+        Ok(bits) if signed && (1..=64).contains(&bits) => Some((bits, signed)),
+        Ok(bits) if !signed && (1..=63).contains(&bits) => Some((bits, signed)),
+        _ => None,
+    }
+}
+
+/// Parse the offset, which is multiplied by the width if prefixed with `#`.
+fn offset(param: &str, bits: u32) -> Option<usize> {
+    let offset: i64 = match param.strip_prefix('#') {
+        Some(index) => index.parse::<i64>().ok()?.checked_mul(bits as i64)?,
+        None => param.parse().ok()?,
+    };
+    if offset < 0 || offset.checked_add(bits as i64)? > Data::MAX_BULK_BYTE * 8 {
+        None
+    } else {
+        Some(offset as usize)
+    }
+}
+
+#[async_trait]
+impl super::Command for BitField {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+
+        let mut fields = Vec::new();
+        let mut overflow = db::Overflow::Wrap;
+        while let Some(param) = cmd.next_string()? {
+            match param.as_str() {
+                "GET" => {}
+                "OVERFLOW" | "SET" | "INCRBY" if self.read_only => {
+                    return Ok(Data::error("BITFIELD_RO only supports the GET subcommand"));
+                }
+                "OVERFLOW" => {
+                    overflow = match super::next_string!(cmd).as_str() {
+                        "WRAP" => db::Overflow::Wrap,
+                        "SAT" => db::Overflow::Sat,
+                        "FAIL" => db::Overflow::Fail,
+                        _ => return Ok(Data::error("Invalid OVERFLOW type specified")),
+                    };
+                    continue;
+                }
+                "SET" | "INCRBY" => {}
+                _ => return Ok(Data::error("syntax error")),
+            }
+            let (bits, signed) = match encoding(&super::next_string!(cmd)) {
+                Some(encoding) => encoding,
+                None => {
+                    return Ok(Data::error(
+                        "Invalid bitfield type. Use something like i16 u8. Note that u64 is not supported but i64 is.",
+                    ))
+                }
+            };
+            let offset = match offset(&super::next_string!(cmd), bits) {
+                Some(offset) => offset,
+                None => return Ok(Data::error("bit offset is not an integer or out of range")),
+            };
+            let operation = match param.as_str() {
+                "GET" => Operation::Get,
+                "SET" => Operation::Set(super::next_i64!(cmd)),
+                _ => Operation::IncrBy(super::next_i64!(cmd)),
+            };
+            fields.push(Field {
+                operation,
+                offset,
+                bits,
+                signed,
+                overflow,
+            });
+        }
+
+        let write = fields
+            .iter()
+            .any(|field| !matches!(field.operation, Operation::Get));
+        if !write {
+            let db = db::DB.read().await;
+
+            let string = match db.get(&key) {
+                Some(entry) => entry.value.as_string()?,
+                None => Cow::Borrowed(&[][..]),
+            };
+            return Ok(Data::Array(
+                fields
+                    .iter()
+                    .map(|field| {
+                        Data::Integer(db::get_field(
+                            &string,
+                            field.offset,
+                            field.bits,
+                            field.signed,
+                        ))
+                    })
+                    .collect(),
+            ));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let string = db
+            .get_or_insert_with(key.clone(), || db::Value::String(Vec::new()))
+            .value
+            .as_string_mut()?;
+        // Pad the string to contain all the written fields.
+        let len = fields
+            .iter()
+            .filter(|field| !matches!(field.operation, Operation::Get))
+            .map(|field| (field.offset + field.bits as usize).div_ceil(8))
+            .max()
+            .unwrap_or(0);
+        if string.len() < len {
+            string.resize(len, 0);
+        }
+
+        let mut response = Vec::with_capacity(fields.len());
+        for field in &fields {
+            let value = db::get_field(string, field.offset, field.bits, field.signed);
+            let new_value = match field.operation {
+                Operation::Get => {
+                    response.push(Data::Integer(value));
+                    continue;
+                }
+                Operation::Set(new_value) => new_value as i128,
+                Operation::IncrBy(increment) => value as i128 + increment as i128,
+            };
+            match db::fit_field(new_value, field.bits, field.signed, field.overflow) {
+                Some(new_value) => {
+                    db::set_field(string, field.offset, field.bits, new_value);
+                    match field.operation {
+                        Operation::Set(_) => response.push(Data::Integer(value)),
+                        _ => response.push(Data::Integer(new_value)),
+                    }
+                }
+                None => response.push(Data::NullBulk),
+            }
+        }
+        db.reindex(&key);
+
+        Ok(Data::Array(response))
+    }
+}
@@ -0,0 +1,67 @@
+//! BITOP command
+//!
+//! # command syntax
+//! BITOP <AND | OR | XOR | NOT> destkey key [key ...]
+//!
+//! <https://redis.io/commands/bitop>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// BitOp commnad empty struct
+pub(super) struct BitOp;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("BITOP"), Box::new(BitOp))
+}
+
+#[async_trait]
+impl super::Command for BitOp {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let operation = match super::next_string!(cmd).as_str() {
+            "AND" => db::BitOperation::And,
+            "OR" => db::BitOperation::Or,
+            "XOR" => db::BitOperation::Xor,
+            "NOT" => db::BitOperation::Not,
+            _ => return Ok(Data::error("syntax error")),
+        };
+        let destination = super::next_bytes!(cmd);
+        let mut keys = vec![super::next_bytes!(cmd)];
+        while let Some(key) = cmd.next_bytes()? {
+            keys.push(key);
+        }
+        if let db::BitOperation::Not = operation {
+            if keys.len() > 1 {
+                return Ok(Data::error(
+                    "BITOP NOT must be called with a single source key.",
+                ));
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        // Missing keys are empty strings.
+        let mut sources = Vec::with_capacity(keys.len());
+        for key in &keys {
+            match db.get(key) {
+                Some(entry) => sources.push(entry.value.as_string()?),
+                None => sources.push(Cow::Borrowed(&[][..])),
+            }
+        }
+        let sources: Vec<&[u8]> = sources.iter().map(|source| &source[..]).collect();
+        let result = db::bitop(operation, &sources);
+        let len = result.len();
+
+        if result.is_empty() {
+            db.del(destination);
+        } else {
+            db.insert(destination, db::Value::string(result));
+        }
+
+        Ok(Data::Integer(len as i64))
+    }
+}
@@ -0,0 +1,59 @@
+//! BITPOS command
+//!
+//! # command syntax
+//! BITPOS key bit [start [end [BYTE|BIT]]]
+//!
+//! <https://redis.io/commands/bitpos>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BitPos commnad empty struct
+pub(super) struct BitPos;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("BITPOS"), Box::new(BitPos))
+}
+
+#[async_trait]
+impl super::Command for BitPos {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let bit = match &super::next_bytes!(cmd)[..] {
+            b"0" => false,
+            b"1" => true,
+            _ => return Ok(Data::error("The bit argument must be 1 or 0.")),
+        };
+        let start = cmd.next_i64()?.unwrap_or(0);
+        let end = cmd.next_i64()?;
+        let bit_unit = match cmd.next_string()? {
+            Some(param) => match super::bitcount::bit_unit(&param) {
+                Some(bit_unit) => bit_unit,
+                None => return Ok(Data::error("syntax error")),
+            },
+            None => false,
+        };
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let string = match db.get(&key) {
+            Some(entry) => entry.value.as_string()?,
+            None => return Ok(Data::Integer(if bit { -1 } else { 0 })),
+        };
+        let (start, last) =
+            match super::bitcount::bit_range(start, end.unwrap_or(-1), string.len(), bit_unit) {
+                Some(range) => range,
+                None => return Ok(Data::Integer(-1)),
+            };
+        match db::bitpos(&string, bit, start, last) {
+            Some(position) => Ok(Data::Integer(position as i64)),
+            // Without the end, the string is considered padded with zeros.
+            None if !bit && end.is_none() => Ok(Data::Integer(last as i64 + 1)),
+            None => Ok(Data::Integer(-1)),
+        }
+    }
+}
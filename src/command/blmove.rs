@@ -0,0 +1,73 @@
+//! BLMOVE command
+//!
+//! # command syntax
+//! BLMOVE source destination LEFT|RIGHT LEFT|RIGHT timeout
+//!
+//! <https://redis.io/commands/blmove>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BLMove commnad empty struct
+pub(super) struct BLMove;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("BLMOVE"), Box::new(BLMove))
+}
+
+#[async_trait]
+impl super::Command for BLMove {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let source = super::next_bytes!(cmd);
+        let destination = super::next_bytes!(cmd);
+        let wherefrom = match super::ListEnd::parse(&super::next_string!(cmd)) {
+            Some(end) => end,
+            None => return Ok(Data::error("syntax error")),
+        };
+        let whereto = match super::ListEnd::parse(&super::next_string!(cmd)) {
+            Some(end) => end,
+            None => return Ok(Data::error("syntax error")),
+        };
+        let timeout = super::timeout(&super::next_bytes!(cmd))?;
+        super::check_end_of_param!(cmd);
+
+        let (id, receiver) = {
+            let mut db = db::DB.write().await;
+
+            let element = super::lmove::lmove(
+                &mut db,
+                source.clone(),
+                destination.clone(),
+                wherefrom,
+                whereto,
+            )?;
+            if let Some(element) = element {
+                db.serve_blocked();
+                return Ok(Data::Bulk(element));
+            }
+            db.block(
+                vec![source],
+                Box::new(move |db, key| {
+                    match super::lmove::lmove(
+                        db,
+                        key.to_vec(),
+                        destination.clone(),
+                        wherefrom,
+                        whereto,
+                    ) {
+                        Ok(element) => element.map(Data::Bulk),
+                        Err(e) => Some(super::error_response(&e)),
+                    }
+                }),
+            )
+        };
+
+        match db::wait(id, receiver, timeout).await {
+            Some(response) => Ok(response),
+            None => Ok(Data::NullArray),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! BLPOP, BRPOP command
+//!
+//! # command syntax
+//! BLPOP key [key ...] timeout
+//!
+//! <https://redis.io/commands/blpop>
+//!
+//! BRPOP key [key ...] timeout
+//!
+//! <https://redis.io/commands/brpop>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BPop commnad struct
+pub(super) struct BPop {
+    end: super::ListEnd,
+}
+
+/// command register function
+pub(super) fn command(end: super::ListEnd) -> (String, super::Cmd) {
+    match end {
+        super::ListEnd::Left => (String::from("BLPOP"), Box::new(BPop { end })),
+        super::ListEnd::Right => (String::from("BRPOP"), Box::new(BPop { end })),
+    }
+}
+
+#[async_trait]
+impl super::Command for BPop {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let mut keys = vec![super::next_bytes!(cmd)];
+        while let Some(key) = cmd.next_bytes()? {
+            keys.push(key);
+        }
+        if keys.len() < 2 {
+            return Ok(Data::error("wrong number of arguments for command"));
+        }
+        let timeout = super::timeout(&keys.pop().unwrap())?;
+
+        let (id, receiver) = {
+            let mut db = db::DB.write().await;
+
+            for key in &keys {
+                if let Some(response) = pop(&mut db, key, self.end)? {
+                    return Ok(response);
+                }
+            }
+            let end = self.end;
+            db.block(
+                keys,
+                Box::new(move |db, key| pop(db, key, end).ok().flatten()),
+            )
+        };
+
+        match db::wait(id, receiver, timeout).await {
+            Some(response) => Ok(response),
+            None => Ok(Data::NullArray),
+        }
+    }
+}
+
+/// Pop an element from the list.
+/// Return the key and the element.
+fn pop(db: &mut db::DBManager, key: &[u8], end: super::ListEnd) -> crate::Result<Option<Data>> {
+    let element = match db.get_mut(key) {
+        Some(entry) => end.pop(entry.value.as_list_mut()?),
+        None => return Ok(None),
+    };
+    db.remove_if_empty(key);
+
+    Ok(element.map(|element| Data::Array(vec![Data::Bulk(key.to_vec()), Data::Bulk(element)])))
+}
@@ -0,0 +1,85 @@
+//! BZPOPMIN, BZPOPMAX command
+//!
+//! # command syntax
+//! BZPOPMIN key [key ...] timeout
+//!
+//! <https://redis.io/commands/bzpopmin>
+//!
+//! BZPOPMAX key [key ...] timeout
+//!
+//! <https://redis.io/commands/bzpopmax>
+//!
+use super::ScoreEnd;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// BZPop commnad struct
+pub(super) struct BZPop {
+    end: ScoreEnd,
+}
+
+/// command register function
+pub(super) fn command(end: ScoreEnd) -> (String, super::Cmd) {
+    match end {
+        ScoreEnd::Min => (String::from("BZPOPMIN"), Box::new(BZPop { end })),
+        ScoreEnd::Max => (String::from("BZPOPMAX"), Box::new(BZPop { end })),
+    }
+}
+
+#[async_trait]
+impl super::Command for BZPop {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let mut keys = vec![super::next_bytes!(cmd)];
+        while let Some(key) = cmd.next_bytes()? {
+            keys.push(key);
+        }
+        if keys.len() < 2 {
+            return Ok(Data::error("wrong number of arguments for command"));
+        }
+        let timeout = super::timeout(&keys.pop().unwrap())?;
+
+        let (id, receiver) = {
+            let mut db = db::DB.write().await;
+
+            for key in &keys {
+                if let Some(response) = pop(&mut db, key, self.end)? {
+                    return Ok(response);
+                }
+            }
+            let end = self.end;
+            db.block(
+                keys,
+                Box::new(move |db, key| pop(db, key, end).ok().flatten()),
+            )
+        };
+
+        match db::wait(id, receiver, timeout).await {
+            Some(response) => Ok(response),
+            None => Ok(Data::NullArray),
+        }
+    }
+}
+
+/// Pop a member from the sorted set.
+/// Return the key, the member and the score.
+fn pop(db: &mut db::DBManager, key: &[u8], end: ScoreEnd) -> crate::Result<Option<Data>> {
+    let popped = match db.get_mut(key) {
+        Some(entry) => entry
+            .value
+            .as_zset_mut()?
+            .pop(matches!(end, ScoreEnd::Max), 1)
+            .pop(),
+        None => return Ok(None),
+    };
+    db.remove_if_empty(key);
+
+    Ok(popped.map(|(member, score)| {
+        Data::Array(vec![
+            Data::Bulk(key.to_vec()),
+            Data::Bulk(member),
+            Data::Bulk(super::format_float(score)),
+        ])
+    }))
+}
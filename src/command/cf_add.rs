@@ -0,0 +1,50 @@
+//! CF.ADD, CF.ADDNX command
+//!
+//! # command syntax
+//! CF.ADD key item
+//!
+//! <https://redis.io/commands/cf.add>
+//!
+//! CF.ADDNX key item
+//!
+//! <https://redis.io/commands/cf.addnx>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// CfAdd commnad struct
+pub(super) struct CfAdd {
+    /// Add the item only if it does not exist.
+    not_exists: bool,
+}
+
+/// command register function
+pub(super) fn command(not_exists: bool) -> (String, super::Cmd) {
+    let name = if not_exists { "CF.ADDNX" } else { "CF.ADD" };
+    (String::from(name), Box::new(CfAdd { not_exists }))
+}
+
+#[async_trait]
+impl super::Command for CfAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let item = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let filter = db
+            .get_or_insert_with(key, || {
+                db::Value::CuckooFilter(db::CuckooFilter::new(db::CuckooFilter::DEFAULT_CAPACITY))
+            })
+            .value
+            .as_cuckoo_filter_mut()?;
+        if self.not_exists && filter.contains(&item) {
+            return Ok(Data::Integer(0));
+        }
+        filter.add(&item);
+        Ok(Data::Integer(1))
+    }
+}
@@ -0,0 +1,37 @@
+//! CF.COUNT command
+//!
+//! # command syntax
+//! CF.COUNT key item
+//!
+//! <https://redis.io/commands/cf.count>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// CfCount commnad empty struct
+pub(super) struct CfCount;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("CF.COUNT"), Box::new(CfCount))
+}
+
+#[async_trait]
+impl super::Command for CfCount {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let item = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        match db.get(&key) {
+            Some(entry) => Ok(Data::Integer(
+                entry.value.as_cuckoo_filter()?.count(&item) as i64
+            )),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! CF.DEL command
+//!
+//! # command syntax
+//! CF.DEL key item
+//!
+//! <https://redis.io/commands/cf.del>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// CfDel commnad empty struct
+pub(super) struct CfDel;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("CF.DEL"), Box::new(CfDel))
+}
+
+#[async_trait]
+impl super::Command for CfDel {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let item = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        match db.get_mut(&key) {
+            Some(entry) => Ok(Data::Integer(
+                entry.value.as_cuckoo_filter_mut()?.remove(&item) as i64,
+            )),
+            None => Ok(Data::error("Not found")),
+        }
+    }
+}
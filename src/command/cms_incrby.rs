@@ -0,0 +1,48 @@
+//! CMS.INCRBY command
+//!
+//! # command syntax
+//! CMS.INCRBY key item increment [item increment ...]
+//!
+//! <https://redis.io/commands/cms.incrby>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// CmsIncrBy commnad empty struct
+pub(super) struct CmsIncrBy;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("CMS.INCRBY"), Box::new(CmsIncrBy))
+}
+
+#[async_trait]
+impl super::Command for CmsIncrBy {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut items = vec![(super::next_bytes!(cmd), super::next_i64!(cmd))];
+        while let Some(item) = cmd.next_bytes()? {
+            items.push((item, super::next_i64!(cmd)));
+        }
+        if items.iter().any(|(_, increment)| *increment < 0) {
+            return Ok(Data::error("CMS: Cannot parse number"));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let sketch = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_count_min_sketch_mut()?,
+            None => return Ok(Data::error("CMS: key does not exist")),
+        };
+        Ok(Data::Array(
+            items
+                .iter()
+                .map(|(item, increment)| {
+                    Data::Integer(sketch.incr_by(item, *increment as u64) as i64)
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,77 @@
+//! CMS.INITBYDIM, CMS.INITBYPROB command
+//!
+//! # command syntax
+//! CMS.INITBYDIM key width depth
+//!
+//! <https://redis.io/commands/cms.initbydim>
+//!
+//! CMS.INITBYPROB key error probability
+//!
+//! <https://redis.io/commands/cms.initbyprob>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// CmsInit commnad struct
+pub(super) struct CmsInit {
+    /// Initialize by the error rate and the probability.
+    by_probability: bool,
+}
+
+/// command register function
+pub(super) fn command(by_probability: bool) -> (String, super::Cmd) {
+    let name = if by_probability {
+        "CMS.INITBYPROB"
+    } else {
+        "CMS.INITBYDIM"
+    };
+    (String::from(name), Box::new(CmsInit { by_probability }))
+}
+
+/// Parse the number between 0 and 1, exclusive.
+fn probability(bytes: &[u8]) -> Option<f64> {
+    super::parse_float(bytes).filter(|value| 0.0 < *value && *value < 1.0)
+}
+
+#[async_trait]
+impl super::Command for CmsInit {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let sketch = if self.by_probability {
+            let error = match probability(&super::next_bytes!(cmd)) {
+                Some(error) => error,
+                None => return Ok(Data::error("CMS: invalid overestimation value")),
+            };
+            let probability = match probability(&super::next_bytes!(cmd)) {
+                Some(probability) => probability,
+                None => return Ok(Data::error("CMS: invalid prob value")),
+            };
+            db::CountMinSketch::with_error(error, probability)
+        } else {
+            let width = super::next_i64!(cmd);
+            if width < 1 {
+                return Ok(Data::error("CMS: invalid width"));
+            }
+            let depth = super::next_i64!(cmd);
+            if depth < 1 {
+                return Ok(Data::error("CMS: invalid depth"));
+            }
+            db::CountMinSketch::new(width as u64, depth as u64)
+        };
+        super::check_end_of_param!(cmd);
+        let sketch = match sketch {
+            Some(sketch) => sketch,
+            None => return Ok(Data::error("CMS: sketch is too large")),
+        };
+
+        let mut db = db::DB.write().await;
+
+        if db.get(&key).is_some() {
+            return Ok(Data::error("CMS: key already exists"));
+        }
+        db.insert(key, db::Value::CountMinSketch(sketch));
+        Ok(Data::ok())
+    }
+}
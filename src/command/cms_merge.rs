@@ -0,0 +1,70 @@
+//! CMS.MERGE command
+//!
+//! # command syntax
+//! CMS.MERGE destination numKeys source [source ...] [WEIGHTS weight [weight ...]]
+//!
+//! <https://redis.io/commands/cms.merge>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// CmsMerge commnad empty struct
+pub(super) struct CmsMerge;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("CMS.MERGE"), Box::new(CmsMerge))
+}
+
+#[async_trait]
+impl super::Command for CmsMerge {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let destination = super::next_bytes!(cmd);
+        let num_keys = super::next_i64!(cmd);
+        if num_keys < 1 {
+            return Ok(Data::error("CMS: Number of keys must be positive"));
+        }
+        let mut keys = Vec::new();
+        for _ in 0..num_keys {
+            keys.push(super::next_bytes!(cmd));
+        }
+        let mut weights = vec![1; keys.len()];
+        if let Some(option) = cmd.next_string()? {
+            if option != "WEIGHTS" {
+                return Ok(Data::error("syntax error"));
+            }
+            for weight in weights.iter_mut() {
+                *weight = super::next_i64!(cmd);
+            }
+        }
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let (width, depth) = match db.get(&destination) {
+            Some(entry) => {
+                let sketch = entry.value.as_count_min_sketch()?;
+                (sketch.width(), sketch.depth())
+            }
+            None => return Ok(Data::error("CMS: key does not exist")),
+        };
+        let mut sketches = Vec::new();
+        for (key, weight) in keys.iter().zip(weights) {
+            let sketch = match db.get(key) {
+                Some(entry) => entry.value.as_count_min_sketch()?,
+                None => return Ok(Data::error("CMS: key does not exist")),
+            };
+            if sketch.width() != width || sketch.depth() != depth {
+                return Ok(Data::error("CMS: width/depth is not equal"));
+            }
+            sketches.push((sketch, weight));
+        }
+        let merged = db::CountMinSketch::merge(&sketches);
+        if let Some(entry) = db.get_mut(&destination) {
+            *entry.value.as_count_min_sketch_mut()? = merged;
+        }
+        Ok(Data::ok())
+    }
+}
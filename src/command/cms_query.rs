@@ -0,0 +1,43 @@
+//! CMS.QUERY command
+//!
+//! # command syntax
+//! CMS.QUERY key item [item ...]
+//!
+//! <https://redis.io/commands/cms.query>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// CmsQuery commnad empty struct
+pub(super) struct CmsQuery;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("CMS.QUERY"), Box::new(CmsQuery))
+}
+
+#[async_trait]
+impl super::Command for CmsQuery {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut items = vec![super::next_bytes!(cmd)];
+        while let Some(item) = cmd.next_bytes()? {
+            items.push(item);
+        }
+
+        let db = db::DB.read().await;
+
+        let sketch = match db.get(&key) {
+            Some(entry) => entry.value.as_count_min_sketch()?,
+            None => return Ok(Data::error("CMS: key does not exist")),
+        };
+        Ok(Data::Array(
+            items
+                .iter()
+                .map(|item| Data::Integer(sketch.query(item) as i64))
+                .collect(),
+        ))
+    }
+}
@@ -26,7 +26,7 @@ impl super::Command for Exists {
 
         while let Some(key) = cmd.next_bytes()? {
             key_exist = true;
-            if let Some(_) = db::DB.write().await.get(&key) {
+            if db::DB.write().await.get(&key).is_some() {
                 exist_num += 1;
             }
         }
@@ -1,4 +1,4 @@
-//! EXPIRE, PEXPIRE command
+//! EXPIRE, PEXPIRE, EXPIREAT, PEXPIREAT command
 //! 
 //! # command syntax
 //! EXPIRE key seconds [NX|XX|GT|LT]
@@ -9,23 +9,33 @@
 //! 
 //! <https://redis.io/commands/pexpire>
 //! 
+//! EXPIREAT key unix-time-seconds [NX|XX|GT|LT]
+//! 
+//! <https://redis.io/commands/expireat>
+//! 
+//! PEXPIREAT key unix-time-milliseconds [NX|XX|GT|LT]
+//! 
+//! <https://redis.io/commands/pexpireat>
+//! 
 use crate::db;
 use crate::protocol::resp::{Data, Parser};
 use async_trait::async_trait;
-use std::ops::Add;
-use std::time::{Duration, Instant};
 
 /// Expire commnad struct
 pub(super) struct Expire {
     time_unit: super::TimeUnit,
+    absolute: bool,
 }
 
 /// command register function
-pub(super) fn command(time_unit: super::TimeUnit) -> (String, super::Cmd) {
-    match time_unit {
-        super::TimeUnit::Second =>(String::from("PEXPIRE"), Box::new(Expire { time_unit })),
-        super::TimeUnit::Millisecond =>(String::from("EXPIRE"), Box::new(Expire { time_unit }))   
-    }
+pub(super) fn command(time_unit: super::TimeUnit, absolute: bool) -> (String, super::Cmd) {
+    let name = match (&time_unit, absolute) {
+        (super::TimeUnit::Second, false) => "EXPIRE",
+        (super::TimeUnit::Millisecond, false) => "PEXPIRE",
+        (super::TimeUnit::Second, true) => "EXPIREAT",
+        (super::TimeUnit::Millisecond, true) => "PEXPIREAT",
+    };
+    (String::from(name), Box::new(Expire { time_unit, absolute }))
 }
 
 #[async_trait]
@@ -33,9 +43,22 @@ impl super::Command for Expire {
     /// Get command body
     async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
         let key = super::next_bytes!(cmd);
-        let expiration = match self.time_unit {
-            super::TimeUnit::Second =>Some(Instant::now().add(Duration::from_millis(super::next_u64!(cmd)))),
-            super::TimeUnit::Millisecond =>Some(Instant::now().add(Duration::from_secs(super::next_u64!(cmd)))),   
+        let time = super::next_i64!(cmd);
+        // A time in the past expires the key immediately.
+        let expiration = match super::expiration(time, &self.time_unit, self.absolute) {
+            Some(expiration) => Some(expiration.max(0) as u64),
+            None => {
+                let name = match (&self.time_unit, self.absolute) {
+                    (super::TimeUnit::Second, false) => "expire",
+                    (super::TimeUnit::Millisecond, false) => "pexpire",
+                    (super::TimeUnit::Second, true) => "expireat",
+                    (super::TimeUnit::Millisecond, true) => "pexpireat",
+                };
+                return Ok(Data::error(&format!(
+                    "invalid expire time in '{}' command",
+                    name
+                )));
+            }
         };
 
         let set_condition;
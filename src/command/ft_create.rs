@@ -0,0 +1,137 @@
+//! FT.CREATE command
+//!
+//! # command syntax
+//! FT.CREATE index [ON STRING] [PREFIX count prefix [prefix ...]]
+//!   SCHEMA field [AS attribute] TEXT [WEIGHT weight] [SORTABLE]
+//!     | TAG [SEPARATOR separator] [CASESENSITIVE] [SORTABLE] | NUMERIC [SORTABLE] ...
+//!
+//! <https://redis.io/commands/ft.create>
+//!
+//! The string keys with the prefixes are indexed if their values are JSON objects.
+//! The fields are the JSONPaths in the objects.
+//!
+use super::ts_range::{args, keyword, next_arg};
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// FtCreate commnad empty struct
+pub(super) struct FtCreate;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("FT.CREATE"), Box::new(FtCreate))
+}
+
+/// Options of the fields. The other arguments start the next field.
+const FIELD_OPTIONS: [&str; 4] = ["WEIGHT", "SEPARATOR", "CASESENSITIVE", "SORTABLE"];
+
+#[async_trait]
+impl super::Command for FtCreate {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let name = super::next_bytes!(cmd);
+        let mut args = args(cmd)?;
+        let mut prefixes = Vec::new();
+        loop {
+            let option = match args.next() {
+                Some(option) => keyword(&option),
+                None => return Ok(Data::error("wrong number of arguments for command")),
+            };
+            match option.as_str() {
+                "ON" => {
+                    if keyword(&next_arg(&mut args)?) != "STRING" {
+                        return Ok(Data::error("only STRING keys can be indexed"));
+                    }
+                }
+                "PREFIX" => match super::parse_integer(&next_arg(&mut args)?) {
+                    Some(count) if count > 0 => {
+                        for _ in 0..count {
+                            prefixes.push(next_arg(&mut args)?);
+                        }
+                    }
+                    _ => return Ok(Data::error("Bad arguments for PREFIX")),
+                },
+                "SCHEMA" => break,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+        let mut fields: Vec<db::SearchField> = Vec::new();
+        while let Some(identifier) = args.next() {
+            let path = match db::JsonPath::parse(&identifier) {
+                Some(path) => path,
+                None => return Ok(Data::error("Invalid JSONPath in SCHEMA")),
+            };
+            let identifier = String::from_utf8_lossy(&identifier).into_owned();
+            let mut name = identifier.clone();
+            let mut kind = keyword(&next_arg(&mut args)?);
+            if kind == "AS" {
+                name = String::from_utf8_lossy(&next_arg(&mut args)?).into_owned();
+                kind = keyword(&next_arg(&mut args)?);
+            }
+            let mut kind = match kind.as_str() {
+                "TEXT" => db::FieldKind::Text { weight: 1.0 },
+                "TAG" => db::FieldKind::Tag {
+                    separator: ',',
+                    case_sensitive: false,
+                },
+                "NUMERIC" => db::FieldKind::Numeric,
+                _ => {
+                    return Ok(Data::error(&format!(
+                        "Invalid field type for field `{}`",
+                        name
+                    )))
+                }
+            };
+            let mut sortable = false;
+            while let Some(option) =
+                args.next_if(|arg| FIELD_OPTIONS.contains(&keyword(arg).as_str()))
+            {
+                match (keyword(&option).as_str(), &mut kind) {
+                    ("SORTABLE", _) => sortable = true,
+                    ("WEIGHT", db::FieldKind::Text { weight }) => {
+                        match super::parse_float(&next_arg(&mut args)?) {
+                            Some(value) if value >= 0.0 && value.is_finite() => *weight = value,
+                            _ => return Ok(Data::error("Bad arguments for WEIGHT")),
+                        }
+                    }
+                    ("SEPARATOR", db::FieldKind::Tag { separator, .. }) => {
+                        match next_arg(&mut args)?.as_slice() {
+                            &[byte] if byte.is_ascii() => *separator = byte as char,
+                            _ => {
+                                return Ok(Data::error("Tag separator must be a single character"))
+                            }
+                        }
+                    }
+                    ("CASESENSITIVE", db::FieldKind::Tag { case_sensitive, .. }) => {
+                        *case_sensitive = true
+                    }
+                    _ => return Ok(Data::error("syntax error")),
+                }
+            }
+            if fields.iter().any(|field| field.name == name) {
+                return Ok(Data::error(&format!(
+                    "Duplicate field in schema - {}",
+                    name
+                )));
+            }
+            fields.push(db::SearchField {
+                identifier,
+                name,
+                path,
+                kind,
+                sortable,
+            });
+        }
+        if fields.is_empty() {
+            return Ok(Data::error("Fields arguments are missing"));
+        }
+
+        let mut db = db::DB.write().await;
+
+        if !db.create_search_index(name, db::SearchIndex::new(prefixes, fields)) {
+            return Ok(Data::error("Index already exists"));
+        }
+        Ok(Data::ok())
+    }
+}
@@ -0,0 +1,45 @@
+//! FT.DROPINDEX command
+//!
+//! # command syntax
+//! FT.DROPINDEX index [DD]
+//!
+//! <https://redis.io/commands/ft.dropindex>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// FtDropIndex commnad empty struct
+pub(super) struct FtDropIndex;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("FT.DROPINDEX"), Box::new(FtDropIndex))
+}
+
+#[async_trait]
+impl super::Command for FtDropIndex {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let name = super::next_bytes!(cmd);
+        let delete_documents = match cmd.next_string()? {
+            Some(option) if option == "DD" => true,
+            Some(_) => return Ok(Data::error("syntax error")),
+            None => false,
+        };
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let index = match db.drop_search_index(&name) {
+            Some(index) => index,
+            None => return Ok(Data::error("Unknown index name")),
+        };
+        if delete_documents {
+            for key in index.keys() {
+                db.del(key.to_vec());
+            }
+        }
+        Ok(Data::ok())
+    }
+}
@@ -0,0 +1,94 @@
+//! FT.INFO command
+//!
+//! # command syntax
+//! FT.INFO index
+//!
+//! <https://redis.io/commands/ft.info>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// FtInfo commnad empty struct
+pub(super) struct FtInfo;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("FT.INFO"), Box::new(FtInfo))
+}
+
+/// Bulk string of the text.
+fn bulk(text: &str) -> Data {
+    Data::Bulk(text.as_bytes().to_vec())
+}
+
+/// Description of the field.
+fn attribute(field: &db::SearchField) -> Data {
+    let mut attribute = vec![
+        bulk("identifier"),
+        bulk(&field.identifier),
+        bulk("attribute"),
+        bulk(&field.name),
+        bulk("type"),
+    ];
+    match field.kind {
+        db::FieldKind::Text { weight } => {
+            attribute.push(bulk("TEXT"));
+            attribute.push(bulk("WEIGHT"));
+            attribute.push(Data::Bulk(super::format_float(weight)));
+        }
+        db::FieldKind::Tag {
+            separator,
+            case_sensitive,
+        } => {
+            attribute.push(bulk("TAG"));
+            attribute.push(bulk("SEPARATOR"));
+            attribute.push(bulk(&separator.to_string()));
+            if case_sensitive {
+                attribute.push(bulk("CASESENSITIVE"));
+            }
+        }
+        db::FieldKind::Numeric => attribute.push(bulk("NUMERIC")),
+    }
+    if field.sortable {
+        attribute.push(bulk("SORTABLE"));
+    }
+    Data::Array(attribute)
+}
+
+#[async_trait]
+impl super::Command for FtInfo {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let name = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let index = match db.search_index(&name) {
+            Some(index) => index,
+            None => return Ok(Data::error("Unknown index name")),
+        };
+        Ok(Data::Array(vec![
+            bulk("index_name"),
+            Data::Bulk(name),
+            bulk("index_definition"),
+            Data::Array(vec![
+                bulk("key_type"),
+                bulk("STRING"),
+                bulk("prefixes"),
+                Data::Array(index.prefixes().iter().cloned().map(Data::Bulk).collect()),
+            ]),
+            bulk("attributes"),
+            Data::Array(index.fields().iter().map(attribute).collect()),
+            bulk("num_docs"),
+            Data::Integer(index.len() as i64),
+            bulk("num_terms"),
+            Data::Integer(index.term_count() as i64),
+            bulk("num_records"),
+            Data::Integer(index.record_count() as i64),
+            bulk("indexing_failures"),
+            Data::Integer(index.failures() as i64),
+        ]))
+    }
+}
@@ -0,0 +1,144 @@
+//! FT.SEARCH command
+//!
+//! # command syntax
+//! FT.SEARCH index query [NOCONTENT] [WITHSCORES] [RETURN count field [field ...]]
+//!   [SORTBY field [ASC | DESC]] [LIMIT offset num]
+//!
+//! <https://redis.io/commands/ft.search>
+//!
+//! The content of the document is the value of the key as `$`, or the fields to return.
+//!
+use super::ts_range::{args, keyword, next_arg};
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// FtSearch commnad empty struct
+pub(super) struct FtSearch;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("FT.SEARCH"), Box::new(FtSearch))
+}
+
+#[async_trait]
+impl super::Command for FtSearch {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let name = super::next_bytes!(cmd);
+        let query = match db::SearchQuery::parse(&super::next_bytes!(cmd)) {
+            Ok(query) => query,
+            Err(message) => return Ok(Data::error(&message)),
+        };
+        let mut args = args(cmd)?;
+        let mut no_content = false;
+        let mut with_scores = false;
+        let mut returns = None;
+        let mut sort_by = None;
+        let mut offset = 0;
+        let mut num = 10;
+        while let Some(option) = args.next() {
+            match keyword(&option).as_str() {
+                "NOCONTENT" => no_content = true,
+                "WITHSCORES" => with_scores = true,
+                "RETURN" => match super::parse_integer(&next_arg(&mut args)?) {
+                    Some(count) if count >= 0 => {
+                        let mut fields = Vec::new();
+                        for _ in 0..count {
+                            fields
+                                .push(String::from_utf8_lossy(&next_arg(&mut args)?).into_owned());
+                        }
+                        returns = Some(fields);
+                    }
+                    _ => return Ok(Data::error("Bad arguments for RETURN")),
+                },
+                "SORTBY" => {
+                    let field = String::from_utf8_lossy(&next_arg(&mut args)?).into_owned();
+                    let ascending =
+                        match args.next_if(|arg| matches!(keyword(arg).as_str(), "ASC" | "DESC")) {
+                            Some(order) => keyword(&order) == "ASC",
+                            None => true,
+                        };
+                    sort_by = Some((field, ascending));
+                }
+                "LIMIT" => {
+                    let first = super::parse_integer(&next_arg(&mut args)?);
+                    let count = super::parse_integer(&next_arg(&mut args)?);
+                    match (first, count) {
+                        (Some(first), Some(count)) if first >= 0 && count >= 0 => {
+                            offset = first as usize;
+                            num = count as usize;
+                        }
+                        _ => return Ok(Data::error("Bad arguments for LIMIT")),
+                    }
+                }
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        let db = db::DB.read().await;
+
+        let index = match db.search_index(&name) {
+            Some(index) => index,
+            None => return Ok(Data::error("Unknown index name")),
+        };
+        let sort_by = match sort_by {
+            Some((field, ascending)) => match index.field(&field) {
+                Some(field) => Some((field, ascending)),
+                None => {
+                    return Ok(Data::error(&format!(
+                        "Property `{}` not loaded nor in schema",
+                        field
+                    )))
+                }
+            },
+            None => None,
+        };
+        let hits = match index.search(&query, sort_by) {
+            Ok(hits) => hits,
+            Err(message) => return Ok(Data::error(&message)),
+        };
+        // The expired keys may not have been removed yet.
+        let hits: Vec<(&[u8], f64, Cow<[u8]>)> = hits
+            .into_iter()
+            .filter_map(|(key, score)| Some((key, score, db.get(key)?.value.as_string().ok()?)))
+            .collect();
+        let mut response = vec![Data::Integer(hits.len() as i64)];
+        for (key, score, value) in hits.into_iter().skip(offset).take(num) {
+            let value = value.into_owned();
+            response.push(Data::Bulk(key.to_vec()));
+            if with_scores {
+                response.push(Data::Bulk(super::format_float(score)));
+            }
+            if no_content {
+                continue;
+            }
+            let content = match &returns {
+                None => vec![Data::Bulk(b"$".to_vec()), Data::Bulk(value)],
+                Some(fields) => {
+                    let mut content = Vec::new();
+                    for field in fields {
+                        let value = match field.as_str() {
+                            "$" => Some(value.clone()),
+                            _ => index
+                                .field(field)
+                                .and_then(|field| index.value(key, field))
+                                .map(|value| match value {
+                                    db::Json::String(string) => string.clone().into_bytes(),
+                                    value => value.to_bytes(),
+                                }),
+                        };
+                        if let Some(value) = value {
+                            content.push(Data::Bulk(field.clone().into_bytes()));
+                            content.push(Data::Bulk(value));
+                        }
+                    }
+                    content
+                }
+            };
+            response.push(Data::Array(content));
+        }
+        Ok(Data::Array(response))
+    }
+}
@@ -0,0 +1,49 @@
+//! FT.SUGADD command
+//!
+//! # command syntax
+//! FT.SUGADD key string score [INCR] [PAYLOAD payload]
+//!
+//! <https://redis.io/commands/ft.sugadd>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// FtSugAdd commnad empty struct
+pub(super) struct FtSugAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("FT.SUGADD"), Box::new(FtSugAdd))
+}
+
+#[async_trait]
+impl super::Command for FtSugAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let string = super::next_bytes!(cmd);
+        let score = match super::parse_float(&super::next_bytes!(cmd)) {
+            Some(score) => score,
+            None => return Ok(Data::error("invalid score")),
+        };
+        let mut increment = false;
+        let mut payload = None;
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "INCR" => increment = true,
+                "PAYLOAD" => payload = Some(super::next_bytes!(cmd)),
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        let trie = db
+            .get_or_insert_with(key, || db::Value::Trie(db::Trie::default()))
+            .value
+            .as_trie_mut()?;
+        trie.add(string, score, increment, payload);
+        Ok(Data::Integer(trie.len() as i64))
+    }
+}
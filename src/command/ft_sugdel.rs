@@ -0,0 +1,37 @@
+//! FT.SUGDEL command
+//!
+//! # command syntax
+//! FT.SUGDEL key string
+//!
+//! <https://redis.io/commands/ft.sugdel>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// FtSugDel commnad empty struct
+pub(super) struct FtSugDel;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("FT.SUGDEL"), Box::new(FtSugDel))
+}
+
+#[async_trait]
+impl super::Command for FtSugDel {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let string = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let removed = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_trie_mut()?.remove(&string),
+            None => false,
+        };
+        db.remove_if_empty(&key);
+        Ok(Data::Integer(removed as i64))
+    }
+}
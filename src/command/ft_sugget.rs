@@ -0,0 +1,64 @@
+//! FT.SUGGET command
+//!
+//! # command syntax
+//! FT.SUGGET key prefix [FUZZY] [WITHSCORES] [WITHPAYLOADS] [MAX max]
+//!
+//! <https://redis.io/commands/ft.sugget>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// FtSugGet commnad empty struct
+pub(super) struct FtSugGet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("FT.SUGGET"), Box::new(FtSugGet))
+}
+
+#[async_trait]
+impl super::Command for FtSugGet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let prefix = super::next_bytes!(cmd);
+        let mut fuzzy = false;
+        let mut with_scores = false;
+        let mut with_payloads = false;
+        let mut max = 5;
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "FUZZY" => fuzzy = true,
+                "WITHSCORES" => with_scores = true,
+                "WITHPAYLOADS" => with_payloads = true,
+                "MAX" => match cmd.next_i64()? {
+                    Some(value) if value >= 0 => max = value as usize,
+                    _ => return Ok(Data::error("Invalid MAX")),
+                },
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        let db = db::DB.read().await;
+
+        let trie = match db.get(&key) {
+            Some(entry) => entry.value.as_trie()?,
+            None => return Ok(Data::Array(vec![])),
+        };
+        let mut response = Vec::new();
+        for suggestion in trie.search(&prefix, fuzzy, max) {
+            response.push(Data::Bulk(suggestion.string.clone()));
+            if with_scores {
+                response.push(Data::Bulk(super::format_float(suggestion.score)));
+            }
+            if with_payloads {
+                response.push(match &suggestion.payload {
+                    Some(payload) => Data::Bulk(payload.clone()),
+                    None => Data::NullBulk,
+                });
+            }
+        }
+        Ok(Data::Array(response))
+    }
+}
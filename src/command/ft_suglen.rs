@@ -0,0 +1,34 @@
+//! FT.SUGLEN command
+//!
+//! # command syntax
+//! FT.SUGLEN key
+//!
+//! <https://redis.io/commands/ft.suglen>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// FtSugLen commnad empty struct
+pub(super) struct FtSugLen;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("FT.SUGLEN"), Box::new(FtSugLen))
+}
+
+#[async_trait]
+impl super::Command for FtSugLen {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        match db.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_trie()?.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! GEOADD command
+//!
+//! # command syntax
+//! GEOADD key [NX | XX] \[CH\] longitude latitude member [longitude latitude member ...]
+//!
+//! <https://redis.io/commands/geoadd>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GeoAdd commnad empty struct
+pub(super) struct GeoAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("GEOADD"), Box::new(GeoAdd))
+}
+
+#[async_trait]
+impl super::Command for GeoAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut args = Vec::new();
+        while let Some(arg) = cmd.next_bytes()? {
+            args.push(arg);
+        }
+
+        let (mut nx, mut xx, mut ch) = (false, false, false);
+        let mut index = 0;
+        while index < args.len() {
+            match &args[index].to_ascii_uppercase()[..] {
+                b"NX" => nx = true,
+                b"XX" => xx = true,
+                b"CH" => ch = true,
+                _ => break,
+            }
+            index += 1;
+        }
+        let args = &args[index..];
+        if args.is_empty() || args.len() % 3 != 0 {
+            return Ok(Data::error(
+                "syntax error. Try GEOADD key [x1] [y1] [name1] [x2] [y2] [name2] ... ",
+            ));
+        }
+        if nx && xx {
+            return Ok(Data::error(
+                "XX and NX options at the same time are not compatible",
+            ));
+        }
+        let mut points = Vec::with_capacity(args.len() / 3);
+        for point in args.chunks(3) {
+            let (longitude, latitude) =
+                match (super::parse_float(&point[0]), super::parse_float(&point[1])) {
+                    (Some(longitude), Some(latitude)) => (longitude, latitude),
+                    _ => return Ok(Data::error("value is not a valid float")),
+                };
+            if !db::is_valid_position(longitude, latitude) {
+                return Ok(Data::error(&format!(
+                    "invalid longitude,latitude pair {:.6},{:.6}",
+                    longitude, latitude
+                )));
+            }
+            points.push((db::geo_encode(longitude, latitude), point[2].clone()));
+        }
+
+        let mut db = db::DB.write().await;
+
+        if let Some(entry) = db.get(&key) {
+            entry.value.as_zset()?;
+        } else if xx {
+            return Ok(Data::Integer(0));
+        }
+        let zset = db
+            .get_or_insert_with(key.clone(), || db::Value::ZSet(db::ZSet::new()))
+            .value
+            .as_zset_mut()?;
+
+        let mut added = 0;
+        let mut updated = 0;
+        for (score, member) in points {
+            match zset.score(&member) {
+                Some(current) => {
+                    if nx {
+                        continue;
+                    }
+                    if score != current {
+                        zset.insert(member, score);
+                        updated += 1;
+                    }
+                }
+                None => {
+                    if xx {
+                        continue;
+                    }
+                    zset.insert(member, score);
+                    added += 1;
+                }
+            }
+        }
+        db.remove_if_empty(&key);
+        db.serve_blocked();
+
+        if ch {
+            Ok(Data::Integer(added + updated))
+        } else {
+            Ok(Data::Integer(added))
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! GEODIST command
+//!
+//! # command syntax
+//! GEODIST key member1 member2 [M | KM | FT | MI]
+//!
+//! <https://redis.io/commands/geodist>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GeoDist commnad empty struct
+pub(super) struct GeoDist;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("GEODIST"), Box::new(GeoDist))
+}
+
+/// Parse the unit and return its length in meters.
+pub(super) fn unit(param: &str) -> Option<f64> {
+    match param {
+        "M" => Some(1.0),
+        "KM" => Some(1000.0),
+        "FT" => Some(0.3048),
+        "MI" => Some(1609.34),
+        _ => None,
+    }
+}
+
+/// Format the distance in the unit for the response.
+pub(super) fn format_distance(distance: f64, unit: f64) -> Vec<u8> {
+    format!("{:.4}", distance / unit).into_bytes()
+}
+
+#[async_trait]
+impl super::Command for GeoDist {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let member1 = super::next_bytes!(cmd);
+        let member2 = super::next_bytes!(cmd);
+        let unit = match cmd.next_string()? {
+            Some(param) => match unit(&param) {
+                Some(unit) => unit,
+                None => {
+                    return Ok(Data::error(
+                        "unsupported unit provided. please use M, KM, FT, MI",
+                    ))
+                }
+            },
+            None => 1.0,
+        };
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let zset = match db.get(&key) {
+            Some(entry) => entry.value.as_zset()?,
+            None => return Ok(Data::NullBulk),
+        };
+        match (zset.score(&member1), zset.score(&member2)) {
+            (Some(score1), Some(score2)) => {
+                let distance = db::geo_distance(db::geo_decode(score1), db::geo_decode(score2));
+                Ok(Data::Bulk(format_distance(distance, unit)))
+            }
+            _ => Ok(Data::NullBulk),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! GEOHASH command
+//!
+//! # command syntax
+//! GEOHASH key [member [member ...]]
+//!
+//! <https://redis.io/commands/geohash>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GeoHash commnad empty struct
+pub(super) struct GeoHash;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("GEOHASH"), Box::new(GeoHash))
+}
+
+#[async_trait]
+impl super::Command for GeoHash {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut members = Vec::new();
+        while let Some(member) = cmd.next_bytes()? {
+            members.push(member);
+        }
+
+        let db = db::DB.read().await;
+
+        let empty = db::ZSet::new();
+        let zset = match db.get(&key) {
+            Some(entry) => entry.value.as_zset()?,
+            None => &empty,
+        };
+        Ok(Data::Array(
+            members
+                .iter()
+                .map(|member| match zset.score(member) {
+                    Some(score) => {
+                        let (longitude, latitude) = db::geo_decode(score);
+                        Data::Bulk(db::geo_hash(longitude, latitude).into_bytes())
+                    }
+                    None => Data::NullBulk,
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+//! GEOPOS command
+//!
+//! # command syntax
+//! GEOPOS key [member [member ...]]
+//!
+//! <https://redis.io/commands/geopos>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GeoPos commnad empty struct
+pub(super) struct GeoPos;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("GEOPOS"), Box::new(GeoPos))
+}
+
+/// Format the position for the response.
+pub(super) fn format_position((longitude, latitude): (f64, f64)) -> Data {
+    Data::Array(vec![
+        Data::Bulk(super::format_float(longitude)),
+        Data::Bulk(super::format_float(latitude)),
+    ])
+}
+
+#[async_trait]
+impl super::Command for GeoPos {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut members = Vec::new();
+        while let Some(member) = cmd.next_bytes()? {
+            members.push(member);
+        }
+
+        let db = db::DB.read().await;
+
+        let empty = db::ZSet::new();
+        let zset = match db.get(&key) {
+            Some(entry) => entry.value.as_zset()?,
+            None => &empty,
+        };
+        Ok(Data::Array(
+            members
+                .iter()
+                .map(|member| match zset.score(member) {
+                    Some(score) => format_position(db::geo_decode(score)),
+                    None => Data::NullArray,
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,248 @@
+//! GEOSEARCH, GEOSEARCHSTORE command
+//!
+//! # command syntax
+//! GEOSEARCH key <FROMMEMBER member | FROMLONLAT longitude latitude>
+//!   <BYRADIUS radius <M | KM | FT | MI> | BYBOX width height <M | KM | FT | MI>>
+//!   [ASC | DESC] [COUNT count \[ANY\]] \[WITHCOORD\] \[WITHDIST\] \[WITHHASH\]
+//!
+//! <https://redis.io/commands/geosearch>
+//!
+//! GEOSEARCHSTORE destination source <FROMMEMBER member | FROMLONLAT longitude latitude>
+//!   <BYRADIUS radius <M | KM | FT | MI> | BYBOX width height <M | KM | FT | MI>>
+//!   [ASC | DESC] [COUNT count \[ANY\]] \[STOREDIST\]
+//!
+//! <https://redis.io/commands/geosearchstore>
+//!
+use super::geodist::{format_distance, unit};
+use super::geopos::format_position;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GeoSearch commnad struct
+pub(super) struct GeoSearch {
+    /// Store the result in the destination.
+    store: bool,
+}
+
+/// command register function
+pub(super) fn command(store: bool) -> (String, super::Cmd) {
+    let name = if store { "GEOSEARCHSTORE" } else { "GEOSEARCH" };
+    (String::from(name), Box::new(GeoSearch { store }))
+}
+
+/// Center of the search.
+enum From {
+    Member(Vec<u8>),
+    Position(f64, f64),
+}
+
+#[async_trait]
+impl super::Command for GeoSearch {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let name = if self.store {
+            "GEOSEARCHSTORE"
+        } else {
+            "GEOSEARCH"
+        };
+        let destination = if self.store {
+            Some(super::next_bytes!(cmd))
+        } else {
+            None
+        };
+        let key = super::next_bytes!(cmd);
+
+        let mut from = None;
+        let mut by = None;
+        let mut unit_length = 1.0;
+        let mut order = None;
+        let mut count = None;
+        let mut any = false;
+        let (mut with_coord, mut with_dist, mut with_hash, mut store_dist) =
+            (false, false, false, false);
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "FROMMEMBER" if from.is_none() => {
+                    from = Some(From::Member(super::next_bytes!(cmd)));
+                }
+                "FROMLONLAT" if from.is_none() => {
+                    let longitude = super::parse_float(&super::next_bytes!(cmd));
+                    let latitude = super::parse_float(&super::next_bytes!(cmd));
+                    let (longitude, latitude) = match (longitude, latitude) {
+                        (Some(longitude), Some(latitude)) => (longitude, latitude),
+                        _ => return Ok(Data::error("value is not a valid float")),
+                    };
+                    if !db::is_valid_position(longitude, latitude) {
+                        return Ok(Data::error(&format!(
+                            "invalid longitude,latitude pair {:.6},{:.6}",
+                            longitude, latitude
+                        )));
+                    }
+                    from = Some(From::Position(longitude, latitude));
+                }
+                "FROMMEMBER" | "FROMLONLAT" => {
+                    return Ok(Data::error(&format!(
+                        "exactly one of FROMMEMBER or FROMLONLAT can be specified for {}",
+                        name
+                    )));
+                }
+                "BYRADIUS" if by.is_none() => {
+                    let radius = match super::parse_float(&super::next_bytes!(cmd)) {
+                        Some(radius) => radius,
+                        None => return Ok(Data::error("need numeric radius")),
+                    };
+                    if radius < 0.0 {
+                        return Ok(Data::error("radius cannot be negative"));
+                    }
+                    unit_length = match unit(&super::next_string!(cmd)) {
+                        Some(unit) => unit,
+                        None => {
+                            return Ok(Data::error(
+                                "unsupported unit provided. please use M, KM, FT, MI",
+                            ))
+                        }
+                    };
+                    by = Some(db::GeoShape::Radius(radius * unit_length));
+                }
+                "BYBOX" if by.is_none() => {
+                    let width = super::parse_float(&super::next_bytes!(cmd));
+                    let height = super::parse_float(&super::next_bytes!(cmd));
+                    let (width, height) = match (width, height) {
+                        (Some(width), Some(height)) => (width, height),
+                        _ => return Ok(Data::error("value is not a valid float")),
+                    };
+                    if width < 0.0 || height < 0.0 {
+                        return Ok(Data::error("height or width cannot be negative"));
+                    }
+                    unit_length = match unit(&super::next_string!(cmd)) {
+                        Some(unit) => unit,
+                        None => {
+                            return Ok(Data::error(
+                                "unsupported unit provided. please use M, KM, FT, MI",
+                            ))
+                        }
+                    };
+                    by = Some(db::GeoShape::Box(width * unit_length, height * unit_length));
+                }
+                "BYRADIUS" | "BYBOX" => {
+                    return Ok(Data::error(&format!(
+                        "exactly one of BYRADIUS and BYBOX can be specified for {}",
+                        name
+                    )));
+                }
+                "ASC" => order = Some(false),
+                "DESC" => order = Some(true),
+                "COUNT" => {
+                    let value = super::next_i64!(cmd);
+                    if value <= 0 {
+                        return Ok(Data::error("COUNT must be > 0"));
+                    }
+                    count = Some(value as usize);
+                }
+                "ANY" => any = true,
+                "WITHCOORD" if !self.store => with_coord = true,
+                "WITHDIST" if !self.store => with_dist = true,
+                "WITHHASH" if !self.store => with_hash = true,
+                "STOREDIST" if self.store => store_dist = true,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+        let from = match from {
+            Some(from) => from,
+            None => {
+                return Ok(Data::error(&format!(
+                    "exactly one of FROMMEMBER or FROMLONLAT can be specified for {}",
+                    name
+                )))
+            }
+        };
+        let shape = match by {
+            Some(shape) => shape,
+            None => {
+                return Ok(Data::error(&format!(
+                    "exactly one of BYRADIUS and BYBOX can be specified for {}",
+                    name
+                )))
+            }
+        };
+        if any && count.is_none() {
+            return Ok(Data::error("the ANY argument requires COUNT argument"));
+        }
+        // Return the nearest members if COUNT is given without the order.
+        let order = match order {
+            None if count.is_some() && !any => Some(false),
+            order => order,
+        };
+
+        let mut db = db::DB.write().await;
+
+        let empty = db::ZSet::new();
+        let zset = match db.get(&key) {
+            Some(entry) => entry.value.as_zset()?,
+            None => &empty,
+        };
+        let center = match from {
+            From::Member(member) => match zset.score(&member) {
+                Some(score) => db::geo_decode(score),
+                None => return Ok(Data::error("could not decode requested zset member")),
+            },
+            From::Position(longitude, latitude) => (longitude, latitude),
+        };
+        let limit = if any { count } else { None };
+        let mut members = db::geo_search(zset, center, &shape, limit);
+        if let Some(reverse) = order {
+            members.sort_by(|a, b| {
+                if reverse {
+                    b.2.total_cmp(&a.2)
+                } else {
+                    a.2.total_cmp(&b.2)
+                }
+            });
+        }
+        members.truncate(count.unwrap_or(usize::MAX));
+
+        match destination {
+            Some(destination) => {
+                let mut result = db::ZSet::new();
+                for (member, score, distance) in members {
+                    let score = if store_dist {
+                        distance / unit_length
+                    } else {
+                        score
+                    };
+                    result.insert(member.to_vec(), score);
+                }
+                let len = result.len();
+                db.insert(destination, db::Value::ZSet(result));
+                db.serve_blocked();
+
+                Ok(Data::Integer(len as i64))
+            }
+            None if !(with_coord || with_dist || with_hash) => Ok(Data::Array(
+                members
+                    .into_iter()
+                    .map(|(member, _, _)| Data::Bulk(member.to_vec()))
+                    .collect(),
+            )),
+            None => Ok(Data::Array(
+                members
+                    .into_iter()
+                    .map(|(member, score, distance)| {
+                        let mut item = vec![Data::Bulk(member.to_vec())];
+                        if with_dist {
+                            item.push(Data::Bulk(format_distance(distance, unit_length)));
+                        }
+                        if with_hash {
+                            item.push(Data::Integer(score as i64));
+                        }
+                        if with_coord {
+                            item.push(format_position(db::geo_decode(score)));
+                        }
+                        Data::Array(item)
+                    })
+                    .collect(),
+            )),
+        }
+    }
+}
@@ -24,7 +24,7 @@ impl super::Command for Get {
         let key = super::next_bytes!(cmd);
         super::check_end_of_param!(cmd);
 
-        match db::DB.read().await.get_value(&key) {
+        match db::DB.read().await.get_value(&key)? {
             Some(value) => Ok(Data::checked_bulk(value)),
             None => Ok(Data::NullBulk),
         }
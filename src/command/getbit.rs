@@ -0,0 +1,41 @@
+//! GETBIT command
+//!
+//! # command syntax
+//! GETBIT key offset
+//!
+//! <https://redis.io/commands/getbit>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GetBit commnad empty struct
+pub(super) struct GetBit;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("GETBIT"), Box::new(GetBit))
+}
+
+#[async_trait]
+impl super::Command for GetBit {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let offset = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let offset = match super::setbit::bit_offset(&offset) {
+            Some(offset) => offset,
+            None => return Ok(Data::error("bit offset is not an integer or out of range")),
+        };
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => {
+                let bit = db::getbit(&entry.value.as_string()?, offset);
+                Ok(Data::Integer(bit as i64))
+            }
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
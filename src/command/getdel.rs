@@ -0,0 +1,37 @@
+//! GETDEL command
+//!
+//! # command syntax
+//! GETDEL key
+//!
+//! <https://redis.io/commands/getdel>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GetDel commnad empty struct
+pub(super) struct GetDel;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("GETDEL"), Box::new(GetDel))
+}
+
+#[async_trait]
+impl super::Command for GetDel {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        match db.get_value(&key)? {
+            Some(value) => {
+                db.del(key);
+                Ok(Data::checked_bulk(value))
+            }
+            None => Ok(Data::NullBulk),
+        }
+    }
+}
@@ -1,15 +1,13 @@
 //! GETEX command
 //! 
 //! # command syntax
-//! GETEX key [EX seconds|PX milliseconds|PERSIST]
+//! GETEX key [EX seconds|PX milliseconds|EXAT unix-time-seconds|PXAT unix-time-milliseconds|PERSIST]
 //! 
 //! <https://redis.io/commands/getex>
 //! 
 use crate::db;
 use crate::protocol::resp::{Data, Parser};
 use async_trait::async_trait;
-use std::ops::Add;
-use std::time::{Duration, Instant};
 
 /// GetEx commnad empty struct
 pub(super) struct GetEx;
@@ -25,12 +23,12 @@ impl super::Command for GetEx {
     async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
         let key = super::next_bytes!(cmd);
 
-        let mut expiration: Option<Instant> = None;
+        let mut expiration: Option<u64> = None;
         let mut persist = false;
 
         while let Some(param) = cmd.next_string()? {
             match param.as_str() {
-                "EX" => match expiration {
+                "EX" | "PX" | "EXAT" | "PXAT" => match expiration {
                     Some(_) => {
                         return Ok(Data::error("syntax error"));
                     }
@@ -38,20 +36,19 @@ impl super::Command for GetEx {
                         if persist {
                             return Ok(Data::error("syntax error"));
                         }
-                        let duration = super::next_u64!(cmd);
-                        expiration = Some(Instant::now().add(Duration::from_secs(duration)));
-                    }
-                },
-                "PX" => match expiration {
-                    Some(_) => {
-                        return Ok(Data::error("syntax error"));
-                    }
-                    None => {
-                        if persist {
-                            return Ok(Data::error("syntax error"));
+                        let time = super::next_i64!(cmd);
+                        let (time_unit, absolute) = match param.as_str() {
+                            "EX" => (super::TimeUnit::Second, false),
+                            "PX" => (super::TimeUnit::Millisecond, false),
+                            "EXAT" => (super::TimeUnit::Second, true),
+                            _ => (super::TimeUnit::Millisecond, true),
+                        };
+                        match super::expiration(time, &time_unit, absolute) {
+                            Some(when) if time > 0 => expiration = Some(when as u64),
+                            _ => {
+                                return Ok(Data::error("invalid expire time in 'getex' command"));
+                            }
                         }
-                        let duration = super::next_u64!(cmd);
-                        expiration = Some(Instant::now().add(Duration::from_millis(duration)));
                     }
                 },
                 "PERSIST" => match expiration {
@@ -71,7 +68,7 @@ impl super::Command for GetEx {
             }
         }
 
-        match db::DB.write().await.getex(key, expiration, persist) {
+        match db::DB.write().await.getex(key, expiration, persist)? {
             Some(value) => Ok(Data::checked_bulk(value)),
             None => Ok(Data::NullBulk),
         }
@@ -0,0 +1,59 @@
+//! GETRANGE, SUBSTR command
+//!
+//! # command syntax
+//! GETRANGE key start end
+//!
+//! <https://redis.io/commands/getrange>
+//!
+//! SUBSTR key start end
+//!
+//! <https://redis.io/commands/substr>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GetRange commnad empty struct
+pub(super) struct GetRange;
+
+/// command register function
+pub(super) fn command(name: &str) -> (String, super::Cmd) {
+    (String::from(name), Box::new(GetRange))
+}
+
+#[async_trait]
+impl super::Command for GetRange {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let start = super::next_i64!(cmd);
+        let end = super::next_i64!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let string = match db.get(&key) {
+            Some(entry) => entry.value.as_string()?,
+            None => return Ok(Data::Bulk(Vec::new())),
+        };
+        let len = string.len() as i64;
+        if (start < 0 && end < 0 && start > end) || len == 0 {
+            return Ok(Data::Bulk(Vec::new()));
+        }
+        let start = if start < 0 {
+            (start + len).max(0)
+        } else {
+            start
+        };
+        let end = if end < 0 {
+            (end + len).max(0)
+        } else {
+            end.min(len - 1)
+        };
+        if start > end {
+            return Ok(Data::Bulk(Vec::new()));
+        }
+
+        Ok(Data::Bulk(string[start as usize..=end as usize].to_vec()))
+    }
+}
@@ -0,0 +1,37 @@
+//! GETSET command
+//!
+//! # command syntax
+//! GETSET key value
+//!
+//! <https://redis.io/commands/getset>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// GetSet commnad empty struct
+pub(super) struct GetSet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("GETSET"), Box::new(GetSet))
+}
+
+#[async_trait]
+impl super::Command for GetSet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let value = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB
+            .write()
+            .await
+            .set(key, value, None, db::SetCondition::NONE, false, true)?
+        {
+            Some(value) => Ok(Data::checked_bulk(value)),
+            None => Ok(Data::NullBulk),
+        }
+    }
+}
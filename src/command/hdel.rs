@@ -0,0 +1,46 @@
+//! HDEL command
+//!
+//! # command syntax
+//! HDEL key field [field ...]
+//!
+//! <https://redis.io/commands/hdel>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HDel commnad empty struct
+pub(super) struct HDel;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HDEL"), Box::new(HDel))
+}
+
+#[async_trait]
+impl super::Command for HDel {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut fields = vec![super::next_bytes!(cmd)];
+        while let Some(field) = cmd.next_bytes()? {
+            fields.push(field);
+        }
+
+        let mut db = db::DB.write().await;
+
+        let hash = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_hash_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        let mut deleted = 0;
+        for field in fields {
+            if hash.remove(&field) {
+                deleted += 1;
+            }
+        }
+        db.remove_if_empty(&key);
+
+        Ok(Data::Integer(deleted))
+    }
+}
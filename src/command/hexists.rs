@@ -0,0 +1,33 @@
+//! HEXISTS command
+//!
+//! # command syntax
+//! HEXISTS key field
+//!
+//! <https://redis.io/commands/hexists>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HExists commnad empty struct
+pub(super) struct HExists;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HEXISTS"), Box::new(HExists))
+}
+
+#[async_trait]
+impl super::Command for HExists {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let field = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_hash()?.contains(&field) as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
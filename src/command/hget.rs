@@ -0,0 +1,39 @@
+//! HGET command
+//!
+//! # command syntax
+//! HGET key field
+//!
+//! <https://redis.io/commands/hget>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HGet commnad empty struct
+pub(super) struct HGet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HGET"), Box::new(HGet))
+}
+
+#[async_trait]
+impl super::Command for HGet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let field = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let hash = match db.get(&key) {
+            Some(entry) => entry.value.as_hash()?,
+            None => return Ok(Data::NullBulk),
+        };
+        match hash.get(&field) {
+            Some(value) => Ok(Data::Bulk(value.clone())),
+            None => Ok(Data::NullBulk),
+        }
+    }
+}
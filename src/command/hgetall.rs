@@ -0,0 +1,41 @@
+//! HGETALL command
+//!
+//! # command syntax
+//! HGETALL key
+//!
+//! <https://redis.io/commands/hgetall>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HGetAll commnad empty struct
+pub(super) struct HGetAll;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HGETALL"), Box::new(HGetAll))
+}
+
+#[async_trait]
+impl super::Command for HGetAll {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Array(
+                entry
+                    .value
+                    .as_hash()?
+                    .iter()
+                    .flat_map(|(field, value)| {
+                        [Data::Bulk(field.clone()), Data::Bulk(value.clone())]
+                    })
+                    .collect(),
+            )),
+            None => Ok(Data::Array(Vec::new())),
+        }
+    }
+}
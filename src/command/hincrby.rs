@@ -0,0 +1,50 @@
+//! HINCRBY command
+//!
+//! # command syntax
+//! HINCRBY key field increment
+//!
+//! <https://redis.io/commands/hincrby>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HIncrBy commnad empty struct
+pub(super) struct HIncrBy;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HINCRBY"), Box::new(HIncrBy))
+}
+
+#[async_trait]
+impl super::Command for HIncrBy {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let field = super::next_bytes!(cmd);
+        let increment = super::next_i64!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let hash = db
+            .get_or_insert_with(key, || db::Value::Hash(db::Hash::new()))
+            .value
+            .as_hash_mut()?;
+        let current = match hash.get(&field) {
+            Some(value) => match super::parse_integer(value) {
+                Some(current) => current,
+                None => return Ok(Data::error("hash value is not an integer")),
+            },
+            None => 0,
+        };
+        match current.checked_add(increment) {
+            Some(value) => {
+                hash.insert(field, value.to_string().into_bytes());
+                Ok(Data::Integer(value))
+            }
+            None => Ok(Data::error("increment or decrement would overflow")),
+        }
+    }
+}
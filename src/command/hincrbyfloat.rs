@@ -0,0 +1,57 @@
+//! HINCRBYFLOAT command
+//!
+//! # command syntax
+//! HINCRBYFLOAT key field increment
+//!
+//! <https://redis.io/commands/hincrbyfloat>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HIncrByFloat commnad empty struct
+pub(super) struct HIncrByFloat;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HINCRBYFLOAT"), Box::new(HIncrByFloat))
+}
+
+#[async_trait]
+impl super::Command for HIncrByFloat {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let field = super::next_bytes!(cmd);
+        let increment = match super::parse_float(&super::next_bytes!(cmd)) {
+            Some(increment) => increment,
+            None => return Ok(Data::error("value is not a valid float")),
+        };
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        // Validate the new value before creating the hash, so an error leaves no empty hash.
+        let current = match db.get(&key) {
+            Some(entry) => match entry.value.as_hash()?.get(&field) {
+                Some(value) => match super::parse_float(value) {
+                    Some(current) => current,
+                    None => return Ok(Data::error("hash value is not a float")),
+                },
+                None => 0.0,
+            },
+            None => 0.0,
+        };
+        let value = current + increment;
+        if !value.is_finite() {
+            return Ok(Data::error("increment would produce NaN or Infinity"));
+        }
+        let value = super::format_float(value);
+        db.get_or_insert_with(key, || db::Value::Hash(db::Hash::new()))
+            .value
+            .as_hash_mut()?
+            .insert(field, value.clone());
+
+        Ok(Data::Bulk(value))
+    }
+}
@@ -0,0 +1,39 @@
+//! HKEYS command
+//!
+//! # command syntax
+//! HKEYS key
+//!
+//! <https://redis.io/commands/hkeys>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HKeys commnad empty struct
+pub(super) struct HKeys;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HKEYS"), Box::new(HKeys))
+}
+
+#[async_trait]
+impl super::Command for HKeys {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Array(
+                entry
+                    .value
+                    .as_hash()?
+                    .iter()
+                    .map(|(field, _)| Data::Bulk(field.clone()))
+                    .collect(),
+            )),
+            None => Ok(Data::Array(Vec::new())),
+        }
+    }
+}
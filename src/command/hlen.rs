@@ -0,0 +1,32 @@
+//! HLEN command
+//!
+//! # command syntax
+//! HLEN key
+//!
+//! <https://redis.io/commands/hlen>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HLen commnad empty struct
+pub(super) struct HLen;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HLEN"), Box::new(HLen))
+}
+
+#[async_trait]
+impl super::Command for HLen {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_hash()?.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! HMGET command
+//!
+//! # command syntax
+//! HMGET key field [field ...]
+//!
+//! <https://redis.io/commands/hmget>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HMGet commnad empty struct
+pub(super) struct HMGet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HMGET"), Box::new(HMGet))
+}
+
+#[async_trait]
+impl super::Command for HMGet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut fields = vec![super::next_bytes!(cmd)];
+        while let Some(field) = cmd.next_bytes()? {
+            fields.push(field);
+        }
+
+        let db = db::DB.read().await;
+
+        let hash = match db.get(&key) {
+            Some(entry) => Some(entry.value.as_hash()?),
+            None => None,
+        };
+        Ok(Data::Array(
+            fields
+                .iter()
+                .map(|field| match hash.and_then(|hash| hash.get(field)) {
+                    Some(value) => Data::Bulk(value.clone()),
+                    None => Data::NullBulk,
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,61 @@
+//! HRANDFIELD command
+//!
+//! # command syntax
+//! HRANDFIELD key [count \[WITHVALUES\]]
+//!
+//! <https://redis.io/commands/hrandfield>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HRandField commnad empty struct
+pub(super) struct HRandField;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HRANDFIELD"), Box::new(HRandField))
+}
+
+#[async_trait]
+impl super::Command for HRandField {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let count = cmd.next_i64()?;
+        let with_values = match cmd.next_string()? {
+            Some(param) if param == "WITHVALUES" && count.is_some() => true,
+            Some(_) => return Ok(Data::error("syntax error")),
+            None => false,
+        };
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let hash = match db.get(&key) {
+            Some(entry) => entry.value.as_hash()?,
+            None => {
+                return match count {
+                    Some(_) => Ok(Data::Array(Vec::new())),
+                    None => Ok(Data::NullBulk),
+                }
+            }
+        };
+        // Negative count may return the same field multiple times.
+        let picked = super::random_elements(hash.iter(), count.unwrap_or(1))?;
+        if count.is_none() {
+            return Ok(picked
+                .first()
+                .map_or(Data::NullBulk, |(field, _)| Data::Bulk(field.to_vec())));
+        }
+
+        let mut array = Vec::new();
+        for (field, value) in picked {
+            array.push(Data::Bulk(field.clone()));
+            if with_values {
+                array.push(Data::Bulk(value.clone()));
+            }
+        }
+        Ok(Data::Array(array))
+    }
+}
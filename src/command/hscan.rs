@@ -0,0 +1,82 @@
+//! HSCAN command
+//!
+//! # command syntax
+//! HSCAN key cursor [MATCH pattern] [COUNT count] \[NOVALUES\]
+//!
+//! <https://redis.io/commands/hscan>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HScan commnad empty struct
+pub(super) struct HScan;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HSCAN"), Box::new(HScan))
+}
+
+#[async_trait]
+impl super::Command for HScan {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let cursor = super::cursor(&super::next_bytes!(cmd))?;
+
+        let mut pattern = None;
+        let mut count = 10;
+        let mut novalues = false;
+
+        while let Some(param) = cmd.next_string()? {
+            match param.as_str() {
+                "MATCH" => pattern = Some(super::next_bytes!(cmd)),
+                "COUNT" => {
+                    count = super::next_i64!(cmd);
+                    if count < 1 {
+                        return Ok(Data::error("syntax error"));
+                    }
+                }
+                "NOVALUES" => novalues = true,
+                _ => {
+                    return Ok(Data::error("syntax error"));
+                }
+            }
+        }
+
+        let db = db::DB.read().await;
+
+        let hash = match db.get(&key) {
+            Some(entry) => entry.value.as_hash()?,
+            None => {
+                return Ok(Data::Array(vec![
+                    Data::Bulk(b"0".to_vec()),
+                    Data::Array(Vec::new()),
+                ]))
+            }
+        };
+        let (cursor, pairs) = db::scan(
+            hash.iter()
+                .map(|(field, value)| (&field[..], (field, value))),
+            cursor,
+            count as usize,
+        );
+
+        let mut array = Vec::new();
+        for (field, value) in pairs {
+            if let Some(pattern) = &pattern {
+                if !db::glob_match(pattern, field) {
+                    continue;
+                }
+            }
+            array.push(Data::Bulk(field.clone()));
+            if !novalues {
+                array.push(Data::Bulk(value.clone()));
+            }
+        }
+        Ok(Data::Array(vec![
+            Data::Bulk(cursor.to_string().into_bytes()),
+            Data::Array(array),
+        ]))
+    }
+}
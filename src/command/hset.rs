@@ -0,0 +1,45 @@
+//! HSET command
+//!
+//! # command syntax
+//! HSET key field value [field value ...]
+//!
+//! <https://redis.io/commands/hset>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HSet commnad empty struct
+pub(super) struct HSet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HSET"), Box::new(HSet))
+}
+
+#[async_trait]
+impl super::Command for HSet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut pairs = vec![(super::next_bytes!(cmd), super::next_bytes!(cmd))];
+        while let Some(field) = cmd.next_bytes()? {
+            pairs.push((field, super::next_bytes!(cmd)));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let hash = db
+            .get_or_insert_with(key, || db::Value::Hash(db::Hash::new()))
+            .value
+            .as_hash_mut()?;
+        let mut added = 0;
+        for (field, value) in pairs {
+            if hash.insert(field, value) {
+                added += 1;
+            }
+        }
+
+        Ok(Data::Integer(added))
+    }
+}
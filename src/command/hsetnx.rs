@@ -0,0 +1,42 @@
+//! HSETNX command
+//!
+//! # command syntax
+//! HSETNX key field value
+//!
+//! <https://redis.io/commands/hsetnx>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HSetNx commnad empty struct
+pub(super) struct HSetNx;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HSETNX"), Box::new(HSetNx))
+}
+
+#[async_trait]
+impl super::Command for HSetNx {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let field = super::next_bytes!(cmd);
+        let value = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let hash = db
+            .get_or_insert_with(key, || db::Value::Hash(db::Hash::new()))
+            .value
+            .as_hash_mut()?;
+        if hash.contains(&field) {
+            Ok(Data::Integer(0))
+        } else {
+            hash.insert(field, value);
+            Ok(Data::Integer(1))
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! HSTRLEN command
+//!
+//! # command syntax
+//! HSTRLEN key field
+//!
+//! <https://redis.io/commands/hstrlen>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HStrLen commnad empty struct
+pub(super) struct HStrLen;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HSTRLEN"), Box::new(HStrLen))
+}
+
+#[async_trait]
+impl super::Command for HStrLen {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let field = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let hash = match db.get(&key) {
+            Some(entry) => entry.value.as_hash()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        match hash.get(&field) {
+            Some(value) => Ok(Data::Integer(value.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! HVALS command
+//!
+//! # command syntax
+//! HVALS key
+//!
+//! <https://redis.io/commands/hvals>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// HVals commnad empty struct
+pub(super) struct HVals;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("HVALS"), Box::new(HVals))
+}
+
+#[async_trait]
+impl super::Command for HVals {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Array(
+                entry
+                    .value
+                    .as_hash()?
+                    .iter()
+                    .map(|(_, value)| Data::Bulk(value.clone()))
+                    .collect(),
+            )),
+            None => Ok(Data::Array(Vec::new())),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! INCR, DECR, INCRBY, DECRBY command
+//!
+//! # command syntax
+//! INCR key
+//!
+//! <https://redis.io/commands/incr>
+//!
+//! DECR key
+//!
+//! <https://redis.io/commands/decr>
+//!
+//! INCRBY key increment
+//!
+//! <https://redis.io/commands/incrby>
+//!
+//! DECRBY key decrement
+//!
+//! <https://redis.io/commands/decrby>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// Incr commnad struct
+pub(super) struct Incr {
+    /// Decrement instead of increment.
+    decrement: bool,
+    /// The amount is given.
+    by: bool,
+}
+
+/// command register function
+pub(super) fn command(decrement: bool, by: bool) -> (String, super::Cmd) {
+    let name = match (decrement, by) {
+        (false, false) => "INCR",
+        (true, false) => "DECR",
+        (false, true) => "INCRBY",
+        (true, true) => "DECRBY",
+    };
+    (String::from(name), Box::new(Incr { decrement, by }))
+}
+
+#[async_trait]
+impl super::Command for Incr {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let amount = if self.by { super::next_i64!(cmd) } else { 1 };
+        super::check_end_of_param!(cmd);
+        let delta = if self.decrement {
+            match amount.checked_neg() {
+                Some(delta) => delta,
+                None => return Ok(Data::error("decrement would overflow")),
+            }
+        } else {
+            amount
+        };
+
+        let mut db = db::DB.write().await;
+
+        // The expiration of the existing entry is kept.
+        let entry = db.get_or_insert_with(key.clone(), || db::Value::Integer(0));
+        let current = match entry.value.as_integer()? {
+            Some(current) => current,
+            None => return Ok(Data::error("value is not an integer or out of range")),
+        };
+        let value = match current.checked_add(delta) {
+            Some(value) => value,
+            None => return Ok(Data::error("increment or decrement would overflow")),
+        };
+        entry.value = db::Value::Integer(value);
+        db.reindex(&key);
+
+        Ok(Data::Integer(value))
+    }
+}
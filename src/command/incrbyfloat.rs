@@ -0,0 +1,53 @@
+//! INCRBYFLOAT command
+//!
+//! # command syntax
+//! INCRBYFLOAT key increment
+//!
+//! <https://redis.io/commands/incrbyfloat>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// IncrByFloat commnad empty struct
+pub(super) struct IncrByFloat;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("INCRBYFLOAT"), Box::new(IncrByFloat))
+}
+
+#[async_trait]
+impl super::Command for IncrByFloat {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let increment = match super::parse_float(&super::next_bytes!(cmd)) {
+            Some(increment) => increment,
+            None => return Ok(Data::error("value is not a valid float")),
+        };
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        // Validate the new value before creating the entry, so an error leaves no key.
+        let current = match db.get(&key) {
+            Some(entry) => match super::parse_float(&entry.value.as_string()?) {
+                Some(current) => current,
+                None => return Ok(Data::error("value is not a valid float")),
+            },
+            None => 0.0,
+        };
+        let value = current + increment;
+        if !value.is_finite() {
+            return Ok(Data::error("increment would produce NaN or Infinity"));
+        }
+        let value = super::format_float(value);
+        // The expiration of the existing entry is kept.
+        db.get_or_insert_with(key.clone(), || db::Value::Integer(0))
+            .value = db::Value::string(value.clone());
+        db.reindex(&key);
+
+        Ok(Data::Bulk(value))
+    }
+}
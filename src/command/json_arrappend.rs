@@ -0,0 +1,52 @@
+//! JSON.ARRAPPEND command
+//!
+//! # command syntax
+//! JSON.ARRAPPEND key path value [value ...]
+//!
+//! <https://redis.io/commands/json.arrappend>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonArrAppend commnad empty struct
+pub(super) struct JsonArrAppend;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.ARRAPPEND"), Box::new(JsonArrAppend))
+}
+
+#[async_trait]
+impl super::Command for JsonArrAppend {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path_bytes = super::next_bytes!(cmd);
+        let path = super::json_path(&path_bytes)?;
+        let mut values = vec![super::json_value(&super::next_bytes!(cmd))?];
+        while let Some(value) = cmd.next_bytes()? {
+            values.push(super::json_value(&value)?);
+        }
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => return Ok(Data::error(super::NO_JSON_KEY)),
+        };
+        let results = super::json_apply(json, &path, |value| match value {
+            db::Json::Array(array) => {
+                array.extend(values.iter().cloned());
+                Ok(Data::Integer(array.len() as i64))
+            }
+            value => Err(value.type_name()),
+        });
+        Ok(super::json_response(
+            &path_bytes,
+            path.is_legacy(),
+            results,
+            "array",
+        ))
+    }
+}
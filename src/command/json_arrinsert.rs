@@ -0,0 +1,71 @@
+//! JSON.ARRINSERT command
+//!
+//! # command syntax
+//! JSON.ARRINSERT key path index value [value ...]
+//!
+//! <https://redis.io/commands/json.arrinsert>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonArrInsert commnad empty struct
+pub(super) struct JsonArrInsert;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.ARRINSERT"), Box::new(JsonArrInsert))
+}
+
+/// Position to insert before, which may be the end of the array.
+fn insert_position(index: i64, len: usize) -> Option<usize> {
+    let index = if index < 0 { index + len as i64 } else { index };
+    if (0..=len as i64).contains(&index) {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+#[async_trait]
+impl super::Command for JsonArrInsert {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path_bytes = super::next_bytes!(cmd);
+        let path = super::json_path(&path_bytes)?;
+        let index = super::next_i64!(cmd);
+        let mut values = vec![super::json_value(&super::next_bytes!(cmd))?];
+        while let Some(value) = cmd.next_bytes()? {
+            values.push(super::json_value(&value)?);
+        }
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => return Ok(Data::error(super::NO_JSON_KEY)),
+        };
+        // Check all the arrays before updating any of them.
+        let out_of_range = path.locate(json).iter().any(|location| {
+            matches!(json.get(location), Some(db::Json::Array(array)) if insert_position(index, array.len()).is_none())
+        });
+        if out_of_range {
+            return Ok(Data::error("index out of bounds"));
+        }
+        let results = super::json_apply(json, &path, |value| match value {
+            db::Json::Array(array) => {
+                let position = insert_position(index, array.len()).unwrap();
+                array.splice(position..position, values.iter().cloned());
+                Ok(Data::Integer(array.len() as i64))
+            }
+            value => Err(value.type_name()),
+        });
+        Ok(super::json_response(
+            &path_bytes,
+            path.is_legacy(),
+            results,
+            "array",
+        ))
+    }
+}
@@ -0,0 +1,51 @@
+//! JSON.ARRLEN command
+//!
+//! # command syntax
+//! JSON.ARRLEN key [path]
+//!
+//! <https://redis.io/commands/json.arrlen>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonArrLen commnad empty struct
+pub(super) struct JsonArrLen;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.ARRLEN"), Box::new(JsonArrLen))
+}
+
+#[async_trait]
+impl super::Command for JsonArrLen {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path_bytes = cmd.next_bytes()?.unwrap_or_else(|| b".".to_vec());
+        let path = super::json_path(&path_bytes)?;
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let json = match db.get(&key) {
+            Some(entry) => entry.value.as_json()?,
+            None => return Ok(Data::NullBulk),
+        };
+        let results = path
+            .locate(json)
+            .iter()
+            .filter_map(|location| json.get(location))
+            .map(|value| match value {
+                db::Json::Array(array) => Ok(Data::Integer(array.len() as i64)),
+                value => Err(value.type_name()),
+            })
+            .collect();
+        Ok(super::json_response(
+            &path_bytes,
+            path.is_legacy(),
+            results,
+            "array",
+        ))
+    }
+}
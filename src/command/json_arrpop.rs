@@ -0,0 +1,54 @@
+//! JSON.ARRPOP command
+//!
+//! # command syntax
+//! JSON.ARRPOP key [path [index]]
+//!
+//! <https://redis.io/commands/json.arrpop>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonArrPop commnad empty struct
+pub(super) struct JsonArrPop;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.ARRPOP"), Box::new(JsonArrPop))
+}
+
+#[async_trait]
+impl super::Command for JsonArrPop {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path_bytes = cmd.next_bytes()?.unwrap_or_else(|| b".".to_vec());
+        let path = super::json_path(&path_bytes)?;
+        let index = cmd.next_i64()?.unwrap_or(-1);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => return Ok(Data::error(super::NO_JSON_KEY)),
+        };
+        let results = super::json_apply(json, &path, |value| match value {
+            db::Json::Array(array) if array.is_empty() => Ok(Data::NullBulk),
+            db::Json::Array(array) => {
+                // The index out of range pops the first or last element.
+                let len = array.len() as i64;
+                let index = if index < 0 { index + len } else { index };
+                let element = array.remove(index.clamp(0, len - 1) as usize);
+                Ok(Data::Bulk(element.to_bytes()))
+            }
+            value => Err(value.type_name()),
+        });
+        Ok(super::json_response(
+            &path_bytes,
+            path.is_legacy(),
+            results,
+            "array",
+        ))
+    }
+}
@@ -0,0 +1,57 @@
+//! JSON.ARRTRIM command
+//!
+//! # command syntax
+//! JSON.ARRTRIM key path start stop
+//!
+//! <https://redis.io/commands/json.arrtrim>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonArrTrim commnad empty struct
+pub(super) struct JsonArrTrim;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.ARRTRIM"), Box::new(JsonArrTrim))
+}
+
+#[async_trait]
+impl super::Command for JsonArrTrim {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path_bytes = super::next_bytes!(cmd);
+        let path = super::json_path(&path_bytes)?;
+        let start = super::next_i64!(cmd);
+        let stop = super::next_i64!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => return Ok(Data::error(super::NO_JSON_KEY)),
+        };
+        let results = super::json_apply(json, &path, |value| match value {
+            db::Json::Array(array) => {
+                match super::range_index(start, stop, array.len()) {
+                    Some((start, stop)) => {
+                        array.truncate(stop + 1);
+                        array.drain(..start);
+                    }
+                    None => array.clear(),
+                }
+                Ok(Data::Integer(array.len() as i64))
+            }
+            value => Err(value.type_name()),
+        });
+        Ok(super::json_response(
+            &path_bytes,
+            path.is_legacy(),
+            results,
+            "array",
+        ))
+    }
+}
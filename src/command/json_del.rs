@@ -0,0 +1,45 @@
+//! JSON.DEL command
+//!
+//! # command syntax
+//! JSON.DEL key [path]
+//!
+//! <https://redis.io/commands/json.del>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonDel commnad empty struct
+pub(super) struct JsonDel;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.DEL"), Box::new(JsonDel))
+}
+
+#[async_trait]
+impl super::Command for JsonDel {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path = match cmd.next_bytes()? {
+            Some(path) => super::json_path(&path)?,
+            None => super::json_path(b".")?,
+        };
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        // Deleting the root deletes the key.
+        if path.is_root() {
+            db.del(key);
+            return Ok(Data::Integer(1));
+        }
+        let locations = path.locate(json);
+        Ok(Data::Integer(json.remove(locations) as i64))
+    }
+}
@@ -0,0 +1,81 @@
+//! JSON.GET command
+//!
+//! # command syntax
+//! JSON.GET key [INDENT indent] [NEWLINE newline] [SPACE space] [path [path ...]]
+//!
+//! <https://redis.io/commands/json.get>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonGet commnad empty struct
+pub(super) struct JsonGet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.GET"), Box::new(JsonGet))
+}
+
+/// Select the values at the path.
+/// The legacy path selects the first value, or None if there is no value.
+/// Otherwise the array of the values is selected.
+pub(super) fn select(json: &db::Json, path: &db::JsonPath, legacy: bool) -> Option<db::Json> {
+    let mut values = path
+        .locate(json)
+        .iter()
+        .filter_map(|location| json.get(location).cloned())
+        .collect::<Vec<_>>();
+    if !legacy {
+        Some(db::Json::Array(values))
+    } else if values.is_empty() {
+        None
+    } else {
+        Some(values.swap_remove(0))
+    }
+}
+
+#[async_trait]
+impl super::Command for JsonGet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut format = db::JsonFormat::default();
+        let mut paths = Vec::new();
+        while let Some(param) = cmd.next_bytes()? {
+            match &param.to_ascii_uppercase()[..] {
+                b"INDENT" => format.indent = String::from_utf8(super::next_bytes!(cmd))?,
+                b"NEWLINE" => format.newline = String::from_utf8(super::next_bytes!(cmd))?,
+                b"SPACE" => format.space = String::from_utf8(super::next_bytes!(cmd))?,
+                _ => paths.push((super::json_path(&param)?, param)),
+            }
+        }
+        if paths.is_empty() {
+            paths.push((super::json_path(b".")?, b".".to_vec()));
+        }
+
+        let db = db::DB.read().await;
+
+        let json = match db.get(&key) {
+            Some(entry) => entry.value.as_json()?,
+            None => return Ok(Data::NullBulk),
+        };
+        // The legacy paths are used only if all the paths are legacy.
+        let legacy = paths.iter().all(|(path, _)| path.is_legacy());
+        let mut values = Vec::with_capacity(paths.len());
+        for (path, name) in paths {
+            let name = String::from_utf8_lossy(&name).into_owned();
+            match select(json, &path, legacy) {
+                Some(value) => values.push((name, value)),
+                None => return Ok(Data::error(&format!("Path '{}' does not exist", name))),
+            }
+        }
+
+        let value = if values.len() == 1 {
+            values.pop().unwrap().1
+        } else {
+            db::Json::Object(values)
+        };
+        Ok(Data::Bulk(value.to_bytes_with(&format)))
+    }
+}
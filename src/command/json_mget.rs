@@ -0,0 +1,49 @@
+//! JSON.MGET command
+//!
+//! # command syntax
+//! JSON.MGET key [key ...] path
+//!
+//! <https://redis.io/commands/json.mget>
+//!
+use super::json_get::select;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonMGet commnad empty struct
+pub(super) struct JsonMGet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.MGET"), Box::new(JsonMGet))
+}
+
+#[async_trait]
+impl super::Command for JsonMGet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let mut keys = vec![super::next_bytes!(cmd)];
+        while let Some(key) = cmd.next_bytes()? {
+            keys.push(key);
+        }
+        // The last parameter is the path.
+        if keys.len() < 2 {
+            return Ok(Data::error("wrong number of arguments for command"));
+        }
+        let path = super::json_path(&keys.pop().unwrap())?;
+
+        let db = db::DB.read().await;
+
+        // Missing keys and the other kinds of values are null.
+        Ok(Data::Array(
+            keys.iter()
+                .map(|key| {
+                    db.get(key)
+                        .and_then(|entry| entry.value.as_json().ok())
+                        .and_then(|json| select(json, &path, path.is_legacy()))
+                        .map_or(Data::NullBulk, |value| Data::Bulk(value.to_bytes()))
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,91 @@
+//! JSON.NUMINCRBY command
+//!
+//! # command syntax
+//! JSON.NUMINCRBY key path value
+//!
+//! <https://redis.io/commands/json.numincrby>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonNumIncrBy commnad empty struct
+pub(super) struct JsonNumIncrBy;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.NUMINCRBY"), Box::new(JsonNumIncrBy))
+}
+
+/// Add the numbers.
+/// Integers are added exactly unless the sum overflows.
+fn add(value: &db::Json, increment: &db::Json) -> Option<db::Json> {
+    if let (db::Json::Integer(value), db::Json::Integer(increment)) = (value, increment) {
+        if let Some(sum) = value.checked_add(*increment) {
+            return Some(db::Json::Integer(sum));
+        }
+    }
+    let sum = value.as_f64()? + increment.as_f64()?;
+    if sum.is_finite() {
+        Some(db::Json::Float(sum))
+    } else {
+        None
+    }
+}
+
+#[async_trait]
+impl super::Command for JsonNumIncrBy {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path_bytes = super::next_bytes!(cmd);
+        let path = super::json_path(&path_bytes)?;
+        let increment = super::json_value(&super::next_bytes!(cmd))?;
+        super::check_end_of_param!(cmd);
+        if increment.as_f64().is_none() {
+            return Ok(Data::error("value is not a number"));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => return Ok(Data::error(super::NO_JSON_KEY)),
+        };
+        let mut overflow = false;
+        let results = super::json_apply(json, &path, |value| {
+            if value.as_f64().is_none() {
+                return Err(value.type_name());
+            }
+            match add(value, &increment) {
+                Some(sum) => {
+                    *value = sum;
+                    Ok(Data::Bulk(value.to_bytes()))
+                }
+                None => {
+                    overflow = true;
+                    Ok(Data::NullBulk)
+                }
+            }
+        });
+        if overflow {
+            return Ok(Data::error("result is not a number"));
+        }
+        if path.is_legacy() {
+            return Ok(super::json_response(&path_bytes, true, results, "number"));
+        }
+
+        // JSONPath responds the serialized array of the new values.
+        let values = results
+            .into_iter()
+            .map(|result| match result {
+                Ok(Data::Bulk(value)) => value,
+                _ => b"null".to_vec(),
+            })
+            .collect::<Vec<_>>();
+        let mut response = b"[".to_vec();
+        response.extend_from_slice(&values.join(&b","[..]));
+        response.push(b']');
+        Ok(Data::Bulk(response))
+    }
+}
@@ -0,0 +1,82 @@
+//! JSON.SET command
+//!
+//! # command syntax
+//! JSON.SET key path value [NX | XX]
+//!
+//! <https://redis.io/commands/json.set>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonSet commnad empty struct
+pub(super) struct JsonSet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.SET"), Box::new(JsonSet))
+}
+
+#[async_trait]
+impl super::Command for JsonSet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path = super::json_path(&super::next_bytes!(cmd))?;
+        let value = super::json_value(&super::next_bytes!(cmd))?;
+        let condition = match cmd.next_string()? {
+            Some(option) => match option.as_str() {
+                "NX" => db::SetCondition::NX,
+                "XX" => db::SetCondition::XX,
+                _ => return Ok(Data::error("syntax error")),
+            },
+            None => db::SetCondition::NONE,
+        };
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => {
+                if condition == db::SetCondition::XX {
+                    return Ok(Data::NullBulk);
+                }
+                if !path.is_root() {
+                    return Ok(Data::error("new objects must be created at the root"));
+                }
+                db.insert(key, db::Value::Json(value));
+                return Ok(Data::ok());
+            }
+        };
+
+        let locations = path.locate(json);
+        if !locations.is_empty() {
+            if condition == db::SetCondition::NX {
+                return Ok(Data::NullBulk);
+            }
+            for location in locations {
+                if let Some(target) = json.get_mut(&location) {
+                    *target = value.clone();
+                }
+            }
+            return Ok(Data::ok());
+        }
+
+        // Add the new member to the objects.
+        if condition == db::SetCondition::XX {
+            return Ok(Data::NullBulk);
+        }
+        match path.locate_parents(json) {
+            Some((parents, name)) if !parents.is_empty() => {
+                for location in parents {
+                    if let Some(parent) = json.get_mut(&location) {
+                        parent.insert(name.clone(), value.clone());
+                    }
+                }
+                Ok(Data::ok())
+            }
+            _ => Ok(Data::NullBulk),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! JSON.STRAPPEND command
+//!
+//! # command syntax
+//! JSON.STRAPPEND key [path] value
+//!
+//! <https://redis.io/commands/json.strappend>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonStrAppend commnad empty struct
+pub(super) struct JsonStrAppend;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.STRAPPEND"), Box::new(JsonStrAppend))
+}
+
+#[async_trait]
+impl super::Command for JsonStrAppend {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let first = super::next_bytes!(cmd);
+        let (path_bytes, value) = match cmd.next_bytes()? {
+            Some(value) => (first, value),
+            None => (b".".to_vec(), first),
+        };
+        super::check_end_of_param!(cmd);
+        let path = super::json_path(&path_bytes)?;
+        let suffix = match super::json_value(&value)? {
+            db::Json::String(suffix) => suffix,
+            _ => return Ok(Data::error("value is not a string")),
+        };
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => return Ok(Data::error(super::NO_JSON_KEY)),
+        };
+        let results = super::json_apply(json, &path, |value| match value {
+            db::Json::String(string) => {
+                string.push_str(&suffix);
+                Ok(Data::Integer(string.len() as i64))
+            }
+            value => Err(value.type_name()),
+        });
+        Ok(super::json_response(
+            &path_bytes,
+            path.is_legacy(),
+            results,
+            "string",
+        ))
+    }
+}
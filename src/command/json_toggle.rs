@@ -0,0 +1,55 @@
+//! JSON.TOGGLE command
+//!
+//! # command syntax
+//! JSON.TOGGLE key path
+//!
+//! <https://redis.io/commands/json.toggle>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonToggle commnad empty struct
+pub(super) struct JsonToggle;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.TOGGLE"), Box::new(JsonToggle))
+}
+
+#[async_trait]
+impl super::Command for JsonToggle {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path_bytes = super::next_bytes!(cmd);
+        let path = super::json_path(&path_bytes)?;
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let json = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_json_mut()?,
+            None => return Ok(Data::error(super::NO_JSON_KEY)),
+        };
+        let legacy = path.is_legacy();
+        let results = super::json_apply(json, &path, |value| match value {
+            db::Json::Bool(bool) => {
+                *bool = !*bool;
+                // The legacy path responds "true" or "false".
+                if legacy {
+                    Ok(Data::Bulk(value.to_bytes()))
+                } else {
+                    Ok(Data::Integer(*bool as i64))
+                }
+            }
+            value => Err(value.type_name()),
+        });
+        Ok(super::json_response(
+            &path_bytes,
+            legacy,
+            results,
+            "boolean",
+        ))
+    }
+}
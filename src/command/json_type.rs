@@ -0,0 +1,47 @@
+//! JSON.TYPE command
+//!
+//! # command syntax
+//! JSON.TYPE key [path]
+//!
+//! <https://redis.io/commands/json.type>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// JsonType commnad empty struct
+pub(super) struct JsonType;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("JSON.TYPE"), Box::new(JsonType))
+}
+
+#[async_trait]
+impl super::Command for JsonType {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let path = match cmd.next_bytes()? {
+            Some(path) => super::json_path(&path)?,
+            None => super::json_path(b".")?,
+        };
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let json = match db.get(&key) {
+            Some(entry) => entry.value.as_json()?,
+            None => return Ok(Data::NullBulk),
+        };
+        let mut types = path.locate(json).into_iter().filter_map(|location| {
+            json.get(&location)
+                .map(|value| Data::SimpleString(value.type_name().as_bytes().to_vec()))
+        });
+        if path.is_legacy() {
+            Ok(types.next().unwrap_or(Data::NullBulk))
+        } else {
+            Ok(Data::Array(types.collect()))
+        }
+    }
+}
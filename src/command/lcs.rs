@@ -0,0 +1,170 @@
+//! LCS command
+//!
+//! # command syntax
+//! LCS key1 key2 \[LEN\] \[IDX\] [MINMATCHLEN min-match-len] \[WITHMATCHLEN\]
+//!
+//! <https://redis.io/commands/lcs>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// Maximum size of the table of the subsequence lengths in bytes.
+const MAX_TABLE_BYTES: usize = 128 * 1024 * 1024;
+
+/// Lcs commnad empty struct
+pub(super) struct Lcs;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LCS"), Box::new(Lcs))
+}
+
+/// Matched ranges in both strings, inclusive.
+struct Match {
+    a: (usize, usize),
+    b: (usize, usize),
+}
+
+impl Match {
+    fn len(&self) -> usize {
+        self.a.1 - self.a.0 + 1
+    }
+}
+
+/// Find the longest common subsequence.
+/// Return the subsequence and the matched ranges from the end of the strings.
+fn lcs(a: &[u8], b: &[u8]) -> (Vec<u8>, Vec<Match>) {
+    // table[i * width + j] is the length of the LCS of a[..i] and b[..j].
+    let width = b.len() + 1;
+    let mut table = vec![0u32; (a.len() + 1) * width];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i * width + j] = if a[i - 1] == b[j - 1] {
+                table[(i - 1) * width + j - 1] + 1
+            } else {
+                table[(i - 1) * width + j].max(table[i * width + j - 1])
+            };
+        }
+    }
+
+    // Walk back from the end of the strings.
+    let mut subsequence = Vec::with_capacity(table[a.len() * width + b.len()] as usize);
+    let mut matches = Vec::new();
+    let mut current: Option<Match> = None;
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            subsequence.push(a[i - 1]);
+            match current.as_mut() {
+                // Extend the match to the left.
+                Some(range) if range.a.0 == i && range.b.0 == j => {
+                    range.a.0 -= 1;
+                    range.b.0 -= 1;
+                }
+                _ => {
+                    matches.extend(current.take());
+                    current = Some(Match {
+                        a: (i - 1, i - 1),
+                        b: (j - 1, j - 1),
+                    });
+                }
+            }
+            i -= 1;
+            j -= 1;
+        } else {
+            if table[(i - 1) * width + j] > table[i * width + j - 1] {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+            matches.extend(current.take());
+        }
+    }
+    matches.extend(current);
+    subsequence.reverse();
+    (subsequence, matches)
+}
+
+#[async_trait]
+impl super::Command for Lcs {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key1 = super::next_bytes!(cmd);
+        let key2 = super::next_bytes!(cmd);
+
+        let (mut len, mut idx, mut with_match_len) = (false, false, false);
+        let mut min_match_len = 0;
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "LEN" => len = true,
+                "IDX" => idx = true,
+                "MINMATCHLEN" => min_match_len = super::next_i64!(cmd).max(0) as usize,
+                "WITHMATCHLEN" => with_match_len = true,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+        if len && idx {
+            return Ok(Data::error(
+                "If you want both the length and indexes, please just use IDX.",
+            ));
+        }
+
+        // Copy the strings to release the lock during the computation.
+        let (a, b) = {
+            let db = db::DB.read().await;
+
+            let mut strings = Vec::with_capacity(2);
+            for key in [&key1, &key2] {
+                match db.get(key) {
+                    Some(entry) => strings.push(entry.value.as_string()?.into_owned()),
+                    None => strings.push(Vec::new()),
+                }
+            }
+            let b = strings.pop().unwrap();
+            (strings.pop().unwrap(), b)
+        };
+        let too_long = (a.len() + 1)
+            .checked_mul(b.len() + 1)
+            .and_then(|cells| cells.checked_mul(std::mem::size_of::<u32>()))
+            .is_none_or(|bytes| bytes > MAX_TABLE_BYTES);
+        if too_long {
+            return Ok(Data::error("String too long for LCS"));
+        }
+
+        let (subsequence, matches) = lcs(&a, &b);
+        if len {
+            return Ok(Data::Integer(subsequence.len() as i64));
+        }
+        if !idx {
+            return Ok(Data::Bulk(subsequence));
+        }
+
+        let matches = matches
+            .iter()
+            .filter(|range| range.len() >= min_match_len)
+            .map(|range| {
+                let mut item = vec![
+                    Data::Array(vec![
+                        Data::Integer(range.a.0 as i64),
+                        Data::Integer(range.a.1 as i64),
+                    ]),
+                    Data::Array(vec![
+                        Data::Integer(range.b.0 as i64),
+                        Data::Integer(range.b.1 as i64),
+                    ]),
+                ];
+                if with_match_len {
+                    item.push(Data::Integer(range.len() as i64));
+                }
+                Data::Array(item)
+            })
+            .collect();
+        Ok(Data::Array(vec![
+            Data::Bulk(b"matches".to_vec()),
+            Data::Array(matches),
+            Data::Bulk(b"len".to_vec()),
+            Data::Integer(subsequence.len() as i64),
+        ]))
+    }
+}
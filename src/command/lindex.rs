@@ -0,0 +1,39 @@
+//! LINDEX command
+//!
+//! # command syntax
+//! LINDEX key index
+//!
+//! <https://redis.io/commands/lindex>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LIndex commnad empty struct
+pub(super) struct LIndex;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LINDEX"), Box::new(LIndex))
+}
+
+#[async_trait]
+impl super::Command for LIndex {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let index = super::next_i64!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let list = match db.get(&key) {
+            Some(entry) => entry.value.as_list()?,
+            None => return Ok(Data::NullBulk),
+        };
+        match super::position(index, list.len()) {
+            Some(index) => Ok(Data::Bulk(list[index].clone())),
+            None => Ok(Data::NullBulk),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! LINSERT command
+//!
+//! # command syntax
+//! LINSERT key BEFORE|AFTER pivot element
+//!
+//! <https://redis.io/commands/linsert>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LInsert commnad empty struct
+pub(super) struct LInsert;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LINSERT"), Box::new(LInsert))
+}
+
+#[async_trait]
+impl super::Command for LInsert {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let after = match super::next_string!(cmd).as_str() {
+            "BEFORE" => false,
+            "AFTER" => true,
+            _ => return Ok(Data::error("syntax error")),
+        };
+        let pivot = super::next_bytes!(cmd);
+        let element = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let list = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_list_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        match list.iter().position(|e| *e == pivot) {
+            Some(index) => {
+                if after {
+                    list.insert(index + 1, element);
+                } else {
+                    list.insert(index, element);
+                }
+                Ok(Data::Integer(list.len() as i64))
+            }
+            None => Ok(Data::Integer(-1)),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! LLEN command
+//!
+//! # command syntax
+//! LLEN key
+//!
+//! <https://redis.io/commands/llen>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LLen commnad empty struct
+pub(super) struct LLen;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LLEN"), Box::new(LLen))
+}
+
+#[async_trait]
+impl super::Command for LLen {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_list()?.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! LMOVE command
+//!
+//! # command syntax
+//! LMOVE source destination LEFT|RIGHT LEFT|RIGHT
+//!
+//! <https://redis.io/commands/lmove>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LMove commnad empty struct
+pub(super) struct LMove;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LMOVE"), Box::new(LMove))
+}
+
+#[async_trait]
+impl super::Command for LMove {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let source = super::next_bytes!(cmd);
+        let destination = super::next_bytes!(cmd);
+        let wherefrom = match super::ListEnd::parse(&super::next_string!(cmd)) {
+            Some(end) => end,
+            None => return Ok(Data::error("syntax error")),
+        };
+        let whereto = match super::ListEnd::parse(&super::next_string!(cmd)) {
+            Some(end) => end,
+            None => return Ok(Data::error("syntax error")),
+        };
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let element = lmove(&mut db, source, destination, wherefrom, whereto)?;
+        db.serve_blocked();
+
+        match element {
+            Some(element) => Ok(Data::Bulk(element)),
+            None => Ok(Data::NullBulk),
+        }
+    }
+}
+
+/// Move the element from the source list to the destination list.
+pub(super) fn lmove(
+    db: &mut db::DBManager,
+    source: Vec<u8>,
+    destination: Vec<u8>,
+    wherefrom: super::ListEnd,
+    whereto: super::ListEnd,
+) -> crate::Result<Option<Vec<u8>>> {
+    match db.get(&source) {
+        Some(entry) => entry.value.as_list()?,
+        None => return Ok(None),
+    };
+    // Check the destination type before popping the element.
+    if let Some(entry) = db.get(&destination) {
+        entry.value.as_list()?;
+    }
+    let element = match db.get_mut(&source) {
+        Some(entry) => wherefrom.pop(entry.value.as_list_mut()?),
+        None => None,
+    };
+    db.remove_if_empty(&source);
+
+    if let Some(element) = &element {
+        let list = db
+            .get_or_insert_with(destination, || db::Value::List(Default::default()))
+            .value
+            .as_list_mut()?;
+        whereto.push(list, element.clone());
+    }
+    Ok(element)
+}
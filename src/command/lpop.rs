@@ -0,0 +1,76 @@
+//! LPOP, RPOP command
+//!
+//! # command syntax
+//! LPOP key \[count\]
+//!
+//! <https://redis.io/commands/lpop>
+//!
+//! RPOP key \[count\]
+//!
+//! <https://redis.io/commands/rpop>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// Pop commnad struct
+pub(super) struct Pop {
+    end: super::ListEnd,
+}
+
+/// command register function
+pub(super) fn command(end: super::ListEnd) -> (String, super::Cmd) {
+    match end {
+        super::ListEnd::Left => (String::from("LPOP"), Box::new(Pop { end })),
+        super::ListEnd::Right => (String::from("RPOP"), Box::new(Pop { end })),
+    }
+}
+
+#[async_trait]
+impl super::Command for Pop {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let count = match cmd.next_i64()? {
+            Some(count) => {
+                if count < 0 {
+                    return Ok(Data::error("value is out of range, must be positive"));
+                }
+                super::check_end_of_param!(cmd);
+                Some(count as usize)
+            }
+            None => None,
+        };
+
+        let mut db = db::DB.write().await;
+
+        let list = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_list_mut()?,
+            None => {
+                return match count {
+                    Some(_) => Ok(Data::NullArray),
+                    None => Ok(Data::NullBulk),
+                }
+            }
+        };
+        let response = match count {
+            Some(count) => {
+                let mut array = Vec::with_capacity(count.min(list.len()));
+                while array.len() < count {
+                    match self.end.pop(list) {
+                        Some(element) => array.push(Data::Bulk(element)),
+                        None => break,
+                    }
+                }
+                Data::Array(array)
+            }
+            None => match self.end.pop(list) {
+                Some(element) => Data::Bulk(element),
+                None => Data::NullBulk,
+            },
+        };
+        db.remove_if_empty(&key);
+
+        Ok(response)
+    }
+}
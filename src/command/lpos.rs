@@ -0,0 +1,109 @@
+//! LPOS command
+//!
+//! # command syntax
+//! LPOS key element [RANK rank] [COUNT num-matches] [MAXLEN len]
+//!
+//! <https://redis.io/commands/lpos>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LPos commnad empty struct
+pub(super) struct LPos;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LPOS"), Box::new(LPos))
+}
+
+#[async_trait]
+impl super::Command for LPos {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let element = super::next_bytes!(cmd);
+
+        let mut rank = 1;
+        let mut count = None;
+        let mut maxlen = 0;
+
+        while let Some(param) = cmd.next_string()? {
+            match param.as_str() {
+                "RANK" => {
+                    rank = super::next_i64!(cmd);
+                    if rank == 0 {
+                        return Ok(Data::error(
+                            "RANK can't be zero: use 1 to start from the first match, \
+                            2 from the second ... or use negative to start from the end of the list",
+                        ));
+                    }
+                }
+                "COUNT" => {
+                    let num = super::next_i64!(cmd);
+                    if num < 0 {
+                        return Ok(Data::error("COUNT can't be negative"));
+                    }
+                    count = Some(num as usize);
+                }
+                "MAXLEN" => {
+                    maxlen = super::next_i64!(cmd);
+                    if maxlen < 0 {
+                        return Ok(Data::error("MAXLEN can't be negative"));
+                    }
+                }
+                _ => {
+                    return Ok(Data::error("syntax error"));
+                }
+            }
+        }
+
+        let db = db::DB.read().await;
+
+        let list = match db.get(&key) {
+            Some(entry) => entry.value.as_list()?,
+            None => {
+                return match count {
+                    Some(_) => Ok(Data::Array(Vec::new())),
+                    None => Ok(Data::NullBulk),
+                }
+            }
+        };
+        // Zero means no limit.
+        let limit = match count {
+            Some(0) | None => usize::MAX,
+            Some(count) => count,
+        };
+        let maxlen = match maxlen {
+            0 => list.len(),
+            _ => maxlen as usize,
+        };
+        // Skip the first (rank - 1) matches.
+        let skip = rank.unsigned_abs() as usize - 1;
+        let matches: Vec<Data> = if rank > 0 {
+            list.iter()
+                .enumerate()
+                .take(maxlen)
+                .filter(|(_, e)| **e == element)
+                .skip(skip)
+                .take(limit)
+                .map(|(index, _)| Data::Integer(index as i64))
+                .collect()
+        } else {
+            list.iter()
+                .enumerate()
+                .rev()
+                .take(maxlen)
+                .filter(|(_, e)| **e == element)
+                .skip(skip)
+                .take(limit)
+                .map(|(index, _)| Data::Integer(index as i64))
+                .collect()
+        };
+
+        match count {
+            Some(_) => Ok(Data::Array(matches)),
+            None => Ok(matches.into_iter().next().unwrap_or(Data::NullBulk)),
+        }
+    }
+}
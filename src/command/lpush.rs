@@ -0,0 +1,73 @@
+//! LPUSH, RPUSH, LPUSHX, RPUSHX command
+//!
+//! # command syntax
+//! LPUSH key element [element ...]
+//!
+//! <https://redis.io/commands/lpush>
+//!
+//! RPUSH key element [element ...]
+//!
+//! <https://redis.io/commands/rpush>
+//!
+//! LPUSHX key element [element ...]
+//!
+//! <https://redis.io/commands/lpushx>
+//!
+//! RPUSHX key element [element ...]
+//!
+//! <https://redis.io/commands/rpushx>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::collections::VecDeque;
+
+/// Push commnad struct
+pub(super) struct Push {
+    end: super::ListEnd,
+    /// Push only if the list already exists.
+    exists_only: bool,
+}
+
+/// command register function
+pub(super) fn command(end: super::ListEnd, exists_only: bool) -> (String, super::Cmd) {
+    let name = match (end, exists_only) {
+        (super::ListEnd::Left, false) => "LPUSH",
+        (super::ListEnd::Right, false) => "RPUSH",
+        (super::ListEnd::Left, true) => "LPUSHX",
+        (super::ListEnd::Right, true) => "RPUSHX",
+    };
+    (String::from(name), Box::new(Push { end, exists_only }))
+}
+
+#[async_trait]
+impl super::Command for Push {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut elements = vec![super::next_bytes!(cmd)];
+        while let Some(element) = cmd.next_bytes()? {
+            elements.push(element);
+        }
+
+        let mut db = db::DB.write().await;
+
+        let list = if self.exists_only {
+            match db.get_mut(&key) {
+                Some(entry) => entry.value.as_list_mut()?,
+                None => return Ok(Data::Integer(0)),
+            }
+        } else {
+            db.get_or_insert_with(key, || db::Value::List(VecDeque::new()))
+                .value
+                .as_list_mut()?
+        };
+        for element in elements {
+            self.end.push(list, element);
+        }
+        let len = list.len();
+        db.serve_blocked();
+
+        Ok(Data::Integer(len as i64))
+    }
+}
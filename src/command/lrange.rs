@@ -0,0 +1,44 @@
+//! LRANGE command
+//!
+//! # command syntax
+//! LRANGE key start stop
+//!
+//! <https://redis.io/commands/lrange>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LRange commnad empty struct
+pub(super) struct LRange;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LRANGE"), Box::new(LRange))
+}
+
+#[async_trait]
+impl super::Command for LRange {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let start = super::next_i64!(cmd);
+        let stop = super::next_i64!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let list = match db.get(&key) {
+            Some(entry) => entry.value.as_list()?,
+            None => return Ok(Data::Array(Vec::new())),
+        };
+        match super::range_index(start, stop, list.len()) {
+            Some((start, stop)) => Ok(Data::Array(
+                list.range(start..=stop)
+                    .map(|element| Data::Bulk(element.clone()))
+                    .collect(),
+            )),
+            None => Ok(Data::Array(Vec::new())),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! LREM command
+//!
+//! # command syntax
+//! LREM key count element
+//!
+//! <https://redis.io/commands/lrem>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LRem commnad empty struct
+pub(super) struct LRem;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LREM"), Box::new(LRem))
+}
+
+#[async_trait]
+impl super::Command for LRem {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let count = super::next_i64!(cmd);
+        let element = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let list = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_list_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        // Zero means all elements equal to the element.
+        let limit = match count {
+            0 => usize::MAX,
+            _ => count.unsigned_abs() as usize,
+        };
+        let mut removed = 0;
+        let mut remove = |e: &Vec<u8>| {
+            if removed < limit && *e == element {
+                removed += 1;
+                false
+            } else {
+                true
+            }
+        };
+        if count < 0 {
+            // Remove elements moving from tail to head.
+            list.make_contiguous().reverse();
+            list.retain(|e| remove(e));
+            list.make_contiguous().reverse();
+        } else {
+            list.retain(|e| remove(e));
+        }
+        db.remove_if_empty(&key);
+
+        Ok(Data::Integer(removed as i64))
+    }
+}
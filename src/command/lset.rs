@@ -0,0 +1,43 @@
+//! LSET command
+//!
+//! # command syntax
+//! LSET key index element
+//!
+//! <https://redis.io/commands/lset>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LSet commnad empty struct
+pub(super) struct LSet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LSET"), Box::new(LSet))
+}
+
+#[async_trait]
+impl super::Command for LSet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let index = super::next_i64!(cmd);
+        let element = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let list = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_list_mut()?,
+            None => return Ok(Data::error("no such key")),
+        };
+        match super::position(index, list.len()) {
+            Some(index) => {
+                list[index] = element;
+                Ok(Data::ok())
+            }
+            None => Ok(Data::error("index out of range")),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! LTRIM command
+//!
+//! # command syntax
+//! LTRIM key start stop
+//!
+//! <https://redis.io/commands/ltrim>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// LTrim commnad empty struct
+pub(super) struct LTrim;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("LTRIM"), Box::new(LTrim))
+}
+
+#[async_trait]
+impl super::Command for LTrim {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let start = super::next_i64!(cmd);
+        let stop = super::next_i64!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let list = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_list_mut()?,
+            None => return Ok(Data::ok()),
+        };
+        match super::range_index(start, stop, list.len()) {
+            Some((start, stop)) => {
+                list.truncate(stop + 1);
+                list.drain(..start);
+            }
+            None => list.clear(),
+        }
+        db.remove_if_empty(&key);
+
+        Ok(Data::ok())
+    }
+}
@@ -0,0 +1,43 @@
+//! MGET command
+//!
+//! # command syntax
+//! MGET key [key ...]
+//!
+//! <https://redis.io/commands/mget>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// MGet commnad empty struct
+pub(super) struct MGet;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("MGET"), Box::new(MGet))
+}
+
+#[async_trait]
+impl super::Command for MGet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let mut keys = vec![super::next_bytes!(cmd)];
+        while let Some(key) = cmd.next_bytes()? {
+            keys.push(key);
+        }
+
+        let db = db::DB.read().await;
+
+        // Missing keys and values of the other kinds are nil.
+        Ok(Data::Array(
+            keys.iter()
+                .map(
+                    |key| match db.get(key).map(|entry| entry.value.as_string()) {
+                        Some(Ok(value)) => Data::Bulk(value.into_owned()),
+                        _ => Data::NullBulk,
+                    },
+                )
+                .collect(),
+        ))
+    }
+}
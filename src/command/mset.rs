@@ -0,0 +1,58 @@
+//! MSET, MSETNX command
+//!
+//! # command syntax
+//! MSET key value [key value ...]
+//!
+//! <https://redis.io/commands/mset>
+//!
+//! MSETNX key value [key value ...]
+//!
+//! <https://redis.io/commands/msetnx>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// MSet commnad struct
+pub(super) struct MSet {
+    /// Set only if none of the keys exist.
+    not_exists: bool,
+}
+
+/// command register function
+pub(super) fn command(not_exists: bool) -> (String, super::Cmd) {
+    match not_exists {
+        false => (String::from("MSET"), Box::new(MSet { not_exists })),
+        true => (String::from("MSETNX"), Box::new(MSet { not_exists })),
+    }
+}
+
+#[async_trait]
+impl super::Command for MSet {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let mut pairs = Vec::new();
+        while let Some(key) = cmd.next_bytes()? {
+            pairs.push((key, super::next_bytes!(cmd)));
+        }
+        if pairs.is_empty() {
+            return Ok(Data::error("wrong number of arguments for command"));
+        }
+
+        // All keys are set under the same lock.
+        let mut db = db::DB.write().await;
+
+        if self.not_exists && pairs.iter().any(|(key, _)| db.get(key).is_some()) {
+            return Ok(Data::Integer(0));
+        }
+        for (key, value) in pairs {
+            db.set(key, value, None, db::SetCondition::NONE, false, false)?;
+        }
+
+        if self.not_exists {
+            Ok(Data::Integer(1))
+        } else {
+            Ok(Data::ok())
+        }
+    }
+}
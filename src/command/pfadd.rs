@@ -0,0 +1,50 @@
+//! PFADD command
+//!
+//! # command syntax
+//! PFADD key [element [element ...]]
+//!
+//! <https://redis.io/commands/pfadd>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// PfAdd commnad empty struct
+pub(super) struct PfAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("PFADD"), Box::new(PfAdd))
+}
+
+#[async_trait]
+impl super::Command for PfAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut elements = Vec::new();
+        while let Some(element) = cmd.next_bytes()? {
+            elements.push(element);
+        }
+
+        let mut db = db::DB.write().await;
+
+        let (mut hll, mut updated) = match db.get(&key) {
+            Some(entry) => (db::HyperLogLog::decode(&entry.value.as_string()?)?, false),
+            // A new key is always updated.
+            None => (db::HyperLogLog::new(), true),
+        };
+        for element in &elements {
+            updated |= hll.add(element);
+        }
+        if updated {
+            // Keep the expiration date.
+            *db.get_or_insert_with(key.clone(), || db::Value::String(Vec::new()))
+                .value
+                .as_string_mut()? = hll.encode();
+            db.reindex(&key);
+        }
+
+        Ok(Data::Integer(updated as i64))
+    }
+}
@@ -0,0 +1,63 @@
+//! PFCOUNT command
+//!
+//! # command syntax
+//! PFCOUNT key [key ...]
+//!
+//! <https://redis.io/commands/pfcount>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// PfCount commnad empty struct
+pub(super) struct PfCount;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("PFCOUNT"), Box::new(PfCount))
+}
+
+#[async_trait]
+impl super::Command for PfCount {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut keys = Vec::new();
+        while let Some(key) = cmd.next_bytes()? {
+            keys.push(key);
+        }
+
+        if !keys.is_empty() {
+            // Count the union without caching.
+            let db = db::DB.read().await;
+
+            let mut union = db::HyperLogLog::new();
+            for key in std::iter::once(&key).chain(&keys) {
+                if let Some(entry) = db.get(key) {
+                    union.merge(&db::HyperLogLog::decode(&entry.value.as_string()?)?);
+                }
+            }
+            return Ok(Data::Integer(union.count() as i64));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let string = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_string_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        if !db::HyperLogLog::is_valid(string) {
+            return Err(Box::new(db::Error::InvalidHyperLogLog));
+        }
+        let count = match db::HyperLogLog::cached_count(string) {
+            Some(count) => count,
+            None => {
+                let count = db::HyperLogLog::decode(string)?.count();
+                db::HyperLogLog::set_cached_count(string, count);
+                count
+            }
+        };
+
+        Ok(Data::Integer(count as i64))
+    }
+}
@@ -0,0 +1,50 @@
+//! PFMERGE command
+//!
+//! # command syntax
+//! PFMERGE destkey [sourcekey [sourcekey ...]]
+//!
+//! <https://redis.io/commands/pfmerge>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// PfMerge commnad empty struct
+pub(super) struct PfMerge;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("PFMERGE"), Box::new(PfMerge))
+}
+
+#[async_trait]
+impl super::Command for PfMerge {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let destination = super::next_bytes!(cmd);
+        let mut keys = Vec::new();
+        while let Some(key) = cmd.next_bytes()? {
+            keys.push(key);
+        }
+
+        let mut db = db::DB.write().await;
+
+        // The destination is also merged.
+        let mut union = match db.get(&destination) {
+            Some(entry) => db::HyperLogLog::decode(&entry.value.as_string()?)?,
+            None => db::HyperLogLog::new(),
+        };
+        for key in &keys {
+            if let Some(entry) = db.get(key) {
+                union.merge(&db::HyperLogLog::decode(&entry.value.as_string()?)?);
+            }
+        }
+        // Keep the expiration date.
+        *db.get_or_insert_with(destination.clone(), || db::Value::String(Vec::new()))
+            .value
+            .as_string_mut()? = union.encode();
+        db.reindex(&destination);
+
+        Ok(Data::ok())
+    }
+}
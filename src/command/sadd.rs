@@ -0,0 +1,46 @@
+//! SADD command
+//!
+//! # command syntax
+//! SADD key member [member ...]
+//!
+//! <https://redis.io/commands/sadd>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::collections::HashSet;
+
+/// SAdd commnad empty struct
+pub(super) struct SAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SADD"), Box::new(SAdd))
+}
+
+#[async_trait]
+impl super::Command for SAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut members = vec![super::next_bytes!(cmd)];
+        while let Some(member) = cmd.next_bytes()? {
+            members.push(member);
+        }
+
+        let mut db = db::DB.write().await;
+
+        let set = db
+            .get_or_insert_with(key, || db::Value::Set(HashSet::new()))
+            .value
+            .as_set_mut()?;
+        let mut added = 0;
+        for member in members {
+            if set.insert(member) {
+                added += 1;
+            }
+        }
+
+        Ok(Data::Integer(added))
+    }
+}
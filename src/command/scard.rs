@@ -0,0 +1,32 @@
+//! SCARD command
+//!
+//! # command syntax
+//! SCARD key
+//!
+//! <https://redis.io/commands/scard>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SCard commnad empty struct
+pub(super) struct SCard;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SCARD"), Box::new(SCard))
+}
+
+#[async_trait]
+impl super::Command for SCard {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_set()?.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -1,15 +1,13 @@
 //! SET command
 //! 
 //! # command syntax
-//! SET key value [EX seconds|PX milliseconds|KEEPTTL] [NX|XX] \[GET\]
+//! SET key value [EX seconds|PX milliseconds|EXAT unix-time-seconds|PXAT unix-time-milliseconds|KEEPTTL] [NX|XX] \[GET\]
 //! 
 //! <https://redis.io/commands/set>
 //! 
 use crate::db;
 use crate::protocol::resp::{Data, Parser};
 use async_trait::async_trait;
-use std::ops::Add;
-use std::time::{Duration, Instant};
 
 /// Set commnad empty struct
 pub(super) struct Set;
@@ -26,14 +24,14 @@ impl super::Command for Set {
         let key = super::next_bytes!(cmd);
         let value = super::next_bytes!(cmd);
 
-        let mut expiration: Option<Instant> = None;
+        let mut expiration: Option<u64> = None;
         let mut set_condition = db::SetCondition::NONE;
         let mut get = false;
         let mut keep_ttl = false;
 
         while let Some(param) = cmd.next_string()? {
             match param.as_str() {
-                "EX" => match expiration {
+                "EX" | "PX" | "EXAT" | "PXAT" => match expiration {
                     Some(_) => {
                         return Ok(Data::error("syntax error"));
                     }
@@ -41,20 +39,19 @@ impl super::Command for Set {
                         if keep_ttl {
                             return Ok(Data::error("syntax error"));
                         }
-                        let duration = super::next_u64!(cmd);
-                        expiration = Some(Instant::now().add(Duration::from_secs(duration)));
-                    }
-                },
-                "PX" => match expiration {
-                    Some(_) => {
-                        return Ok(Data::error("syntax error"));
-                    }
-                    None => {
-                        if keep_ttl {
-                            return Ok(Data::error("syntax error"));
+                        let time = super::next_i64!(cmd);
+                        let (time_unit, absolute) = match param.as_str() {
+                            "EX" => (super::TimeUnit::Second, false),
+                            "PX" => (super::TimeUnit::Millisecond, false),
+                            "EXAT" => (super::TimeUnit::Second, true),
+                            _ => (super::TimeUnit::Millisecond, true),
+                        };
+                        match super::expiration(time, &time_unit, absolute) {
+                            Some(when) if time > 0 => expiration = Some(when as u64),
+                            _ => {
+                                return Ok(Data::error("invalid expire time in 'set' command"));
+                            }
                         }
-                        let duration = super::next_u64!(cmd);
-                        expiration = Some(Instant::now().add(Duration::from_millis(duration)));
                     }
                 },
                 "KEEPTTL" => match expiration {
@@ -95,7 +92,7 @@ impl super::Command for Set {
         match db::DB
             .write()
             .await
-            .set(key, value, expiration, set_condition, keep_ttl, get)
+            .set(key, value, expiration, set_condition, keep_ttl, get)?
         {
             Some(value) => Ok(Data::checked_bulk(value)),
             None => {
@@ -0,0 +1,52 @@
+//! SETBIT command
+//!
+//! # command syntax
+//! SETBIT key offset value
+//!
+//! <https://redis.io/commands/setbit>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SetBit commnad empty struct
+pub(super) struct SetBit;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SETBIT"), Box::new(SetBit))
+}
+
+/// Parse the bit offset.
+/// Return None if it is out of the maximum string size.
+pub(super) fn bit_offset(bytes: &[u8]) -> Option<usize> {
+    match super::parse_integer(bytes) {
+        Some(offset) if (0..Data::MAX_BULK_BYTE * 8).contains(&offset) => Some(offset as usize),
+        _ => None,
+    }
+}
+
+#[async_trait]
+impl super::Command for SetBit {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let offset = super::next_bytes!(cmd);
+        let bit = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let offset = match bit_offset(&offset) {
+            Some(offset) => offset,
+            None => return Ok(Data::error("bit offset is not an integer or out of range")),
+        };
+        let bit = match &bit[..] {
+            b"0" => false,
+            b"1" => true,
+            _ => return Ok(Data::error("bit is not an integer or out of range")),
+        };
+
+        let original = db::DB.write().await.setbit(key, offset, bit)?;
+
+        Ok(Data::Integer(original as i64))
+    }
+}
@@ -0,0 +1,62 @@
+//! SETEX, PSETEX command
+//!
+//! # command syntax
+//! SETEX key seconds value
+//!
+//! <https://redis.io/commands/setex>
+//!
+//! PSETEX key milliseconds value
+//!
+//! <https://redis.io/commands/psetex>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SetEx commnad struct
+pub(super) struct SetEx {
+    time_unit: super::TimeUnit,
+}
+
+/// command register function
+pub(super) fn command(time_unit: super::TimeUnit) -> (String, super::Cmd) {
+    match time_unit {
+        super::TimeUnit::Second => (String::from("SETEX"), Box::new(SetEx { time_unit })),
+        super::TimeUnit::Millisecond => (String::from("PSETEX"), Box::new(SetEx { time_unit })),
+    }
+}
+
+#[async_trait]
+impl super::Command for SetEx {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let time = super::next_i64!(cmd);
+        let value = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+        let expiration = match super::expiration(time, &self.time_unit, false) {
+            Some(expiration) if time > 0 => expiration as u64,
+            _ => {
+                let name = match self.time_unit {
+                    super::TimeUnit::Second => "setex",
+                    super::TimeUnit::Millisecond => "psetex",
+                };
+                return Ok(Data::error(&format!(
+                    "invalid expire time in '{}' command",
+                    name
+                )));
+            }
+        };
+
+        db::DB.write().await.set(
+            key,
+            value,
+            Some(expiration),
+            db::SetCondition::NONE,
+            false,
+            false,
+        )?;
+
+        Ok(Data::ok())
+    }
+}
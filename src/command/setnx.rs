@@ -0,0 +1,37 @@
+//! SETNX command
+//!
+//! # command syntax
+//! SETNX key value
+//!
+//! <https://redis.io/commands/setnx>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SetNx commnad empty struct
+pub(super) struct SetNx;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SETNX"), Box::new(SetNx))
+}
+
+#[async_trait]
+impl super::Command for SetNx {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let value = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        if db.get(&key).is_some() {
+            return Ok(Data::Integer(0));
+        }
+        db.set(key, value, None, db::SetCondition::NX, false, false)?;
+
+        Ok(Data::Integer(1))
+    }
+}
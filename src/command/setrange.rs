@@ -0,0 +1,54 @@
+//! SETRANGE command
+//!
+//! # command syntax
+//! SETRANGE key offset value
+//!
+//! <https://redis.io/commands/setrange>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SetRange commnad empty struct
+pub(super) struct SetRange;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SETRANGE"), Box::new(SetRange))
+}
+
+#[async_trait]
+impl super::Command for SetRange {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let offset = super::next_i64!(cmd);
+        let value = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+        if offset < 0 {
+            return Ok(Data::error("offset is out of range"));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let len = match db.get(&key) {
+            Some(entry) => entry.value.as_string()?.len(),
+            None => 0,
+        };
+        if value.is_empty() {
+            // Nothing is created or modified.
+            return Ok(Data::Integer(len as i64));
+        }
+        match offset.checked_add(value.len() as i64) {
+            Some(end) if end <= Data::MAX_BULK_BYTE => {}
+            _ => {
+                return Ok(Data::error(
+                    "string exceeds maximum allowed size (proto-max-bulk-len)",
+                ))
+            }
+        }
+        let len = db.setrange(key, offset as usize, &value)?;
+
+        Ok(Data::Integer(len as i64))
+    }
+}
@@ -0,0 +1,130 @@
+//! SINTER, SUNION, SDIFF, SINTERSTORE, SUNIONSTORE, SDIFFSTORE command
+//!
+//! # command syntax
+//! SINTER key [key ...]
+//!
+//! <https://redis.io/commands/sinter>
+//!
+//! SUNION key [key ...]
+//!
+//! <https://redis.io/commands/sunion>
+//!
+//! SDIFF key [key ...]
+//!
+//! <https://redis.io/commands/sdiff>
+//!
+//! SINTERSTORE destination key [key ...]
+//!
+//! <https://redis.io/commands/sinterstore>
+//!
+//! SUNIONSTORE destination key [key ...]
+//!
+//! <https://redis.io/commands/sunionstore>
+//!
+//! SDIFFSTORE destination key [key ...]
+//!
+//! <https://redis.io/commands/sdiffstore>
+//!
+use super::SetOperation;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::collections::HashSet;
+
+/// SetOp commnad struct
+pub(super) struct SetOp {
+    operation: SetOperation,
+    /// Store the result in the destination.
+    store: bool,
+}
+
+/// command register function
+pub(super) fn command(operation: SetOperation, store: bool) -> (String, super::Cmd) {
+    let name = match (operation, store) {
+        (SetOperation::Inter, false) => "SINTER",
+        (SetOperation::Union, false) => "SUNION",
+        (SetOperation::Diff, false) => "SDIFF",
+        (SetOperation::Inter, true) => "SINTERSTORE",
+        (SetOperation::Union, true) => "SUNIONSTORE",
+        (SetOperation::Diff, true) => "SDIFFSTORE",
+    };
+    (String::from(name), Box::new(SetOp { operation, store }))
+}
+
+#[async_trait]
+impl super::Command for SetOp {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let destination = if self.store {
+            Some(super::next_bytes!(cmd))
+        } else {
+            None
+        };
+        let mut keys = vec![super::next_bytes!(cmd)];
+        while let Some(key) = cmd.next_bytes()? {
+            keys.push(key);
+        }
+
+        match destination {
+            Some(destination) => {
+                let mut db = db::DB.write().await;
+
+                let result = compute(&db, &keys, self.operation)?;
+                let len = result.len();
+                db.insert(destination, db::Value::Set(result));
+
+                Ok(Data::Integer(len as i64))
+            }
+            None => {
+                let db = db::DB.read().await;
+
+                let result = compute(&db, &keys, self.operation)?;
+                Ok(Data::Array(result.into_iter().map(Data::Bulk).collect()))
+            }
+        }
+    }
+}
+
+/// Compute the operation between the sets.
+/// Missing keys are empty sets.
+fn compute(
+    db: &db::DBManager,
+    keys: &[Vec<u8>],
+    operation: SetOperation,
+) -> crate::Result<HashSet<Vec<u8>>> {
+    let mut sets = Vec::with_capacity(keys.len());
+    for key in keys {
+        sets.push(match db.get(key) {
+            Some(entry) => Some(entry.value.as_set()?),
+            None => None,
+        });
+    }
+
+    match operation {
+        SetOperation::Inter => {
+            let mut sets = match sets.into_iter().collect::<Option<Vec<_>>>() {
+                Some(sets) => sets,
+                None => return Ok(HashSet::new()),
+            };
+            // Iterate over the smallest set.
+            sets.sort_by_key(|set| set.len());
+            Ok(sets[0]
+                .iter()
+                .filter(|member| sets[1..].iter().all(|set| set.contains(*member)))
+                .cloned()
+                .collect())
+        }
+        SetOperation::Union => Ok(sets.into_iter().flatten().flatten().cloned().collect()),
+        SetOperation::Diff => {
+            let (first, others) = sets.split_first().unwrap();
+            match first {
+                Some(first) => Ok(first
+                    .iter()
+                    .filter(|member| others.iter().flatten().all(|set| !set.contains(*member)))
+                    .cloned()
+                    .collect()),
+                None => Ok(HashSet::new()),
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! SINTERCARD command
+//!
+//! # command syntax
+//! SINTERCARD numkeys key [key ...] [LIMIT limit]
+//!
+//! <https://redis.io/commands/sintercard>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SInterCard commnad empty struct
+pub(super) struct SInterCard;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SINTERCARD"), Box::new(SInterCard))
+}
+
+#[async_trait]
+impl super::Command for SInterCard {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let numkeys = super::next_i64!(cmd);
+        if numkeys < 1 {
+            return Ok(Data::error("numkeys should be greater than 0"));
+        }
+        let mut keys = Vec::new();
+        for _ in 0..numkeys {
+            match cmd.next_bytes()? {
+                Some(key) => keys.push(key),
+                None => {
+                    return Ok(Data::error(
+                        "Number of keys can't be greater than number of args",
+                    ))
+                }
+            }
+        }
+        // Zero means no limit.
+        let mut limit = usize::MAX;
+        while let Some(param) = cmd.next_string()? {
+            match param.as_str() {
+                "LIMIT" => {
+                    let value = super::next_i64!(cmd);
+                    if value < 0 {
+                        return Ok(Data::error("LIMIT can't be negative"));
+                    }
+                    if value > 0 {
+                        limit = value as usize;
+                    }
+                }
+                _ => {
+                    return Ok(Data::error("syntax error"));
+                }
+            }
+        }
+
+        let db = db::DB.read().await;
+
+        let mut sets = Vec::with_capacity(keys.len());
+        for key in &keys {
+            sets.push(match db.get(key) {
+                Some(entry) => Some(entry.value.as_set()?),
+                None => None,
+            });
+        }
+        let mut sets = match sets.into_iter().collect::<Option<Vec<_>>>() {
+            Some(sets) => sets,
+            None => return Ok(Data::Integer(0)),
+        };
+        // Iterate over the smallest set.
+        sets.sort_by_key(|set| set.len());
+        let cardinality = sets[0]
+            .iter()
+            .filter(|member| sets[1..].iter().all(|set| set.contains(*member)))
+            .take(limit)
+            .count();
+
+        Ok(Data::Integer(cardinality as i64))
+    }
+}
@@ -0,0 +1,33 @@
+//! SISMEMBER command
+//!
+//! # command syntax
+//! SISMEMBER key member
+//!
+//! <https://redis.io/commands/sismember>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SIsMember commnad empty struct
+pub(super) struct SIsMember;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SISMEMBER"), Box::new(SIsMember))
+}
+
+#[async_trait]
+impl super::Command for SIsMember {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let member = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_set()?.contains(&member) as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
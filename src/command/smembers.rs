@@ -0,0 +1,39 @@
+//! SMEMBERS command
+//!
+//! # command syntax
+//! SMEMBERS key
+//!
+//! <https://redis.io/commands/smembers>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SMembers commnad empty struct
+pub(super) struct SMembers;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SMEMBERS"), Box::new(SMembers))
+}
+
+#[async_trait]
+impl super::Command for SMembers {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Array(
+                entry
+                    .value
+                    .as_set()?
+                    .iter()
+                    .map(|member| Data::Bulk(member.clone()))
+                    .collect(),
+            )),
+            None => Ok(Data::Array(Vec::new())),
+        }
+    }
+}
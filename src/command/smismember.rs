@@ -0,0 +1,43 @@
+//! SMISMEMBER command
+//!
+//! # command syntax
+//! SMISMEMBER key member [member ...]
+//!
+//! <https://redis.io/commands/smismember>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SMIsMember commnad empty struct
+pub(super) struct SMIsMember;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SMISMEMBER"), Box::new(SMIsMember))
+}
+
+#[async_trait]
+impl super::Command for SMIsMember {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut members = vec![super::next_bytes!(cmd)];
+        while let Some(member) = cmd.next_bytes()? {
+            members.push(member);
+        }
+
+        let db = db::DB.read().await;
+
+        let set = match db.get(&key) {
+            Some(entry) => Some(entry.value.as_set()?),
+            None => None,
+        };
+        Ok(Data::Array(
+            members
+                .iter()
+                .map(|member| Data::Integer(set.is_some_and(|set| set.contains(member)) as i64))
+                .collect(),
+        ))
+    }
+}
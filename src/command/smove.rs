@@ -0,0 +1,54 @@
+//! SMOVE command
+//!
+//! # command syntax
+//! SMOVE source destination member
+//!
+//! <https://redis.io/commands/smove>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::collections::HashSet;
+
+/// SMove commnad empty struct
+pub(super) struct SMove;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SMOVE"), Box::new(SMove))
+}
+
+#[async_trait]
+impl super::Command for SMove {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let source = super::next_bytes!(cmd);
+        let destination = super::next_bytes!(cmd);
+        let member = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        // Check the destination type before removing the member.
+        if let Some(entry) = db.get(&destination) {
+            entry.value.as_set()?;
+        }
+        let set = match db.get_mut(&source) {
+            Some(entry) => entry.value.as_set_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        if source == destination {
+            return Ok(Data::Integer(set.contains(&member) as i64));
+        }
+        if !set.remove(&member) {
+            return Ok(Data::Integer(0));
+        }
+        db.remove_if_empty(&source);
+        db.get_or_insert_with(destination, || db::Value::Set(HashSet::new()))
+            .value
+            .as_set_mut()?
+            .insert(member);
+
+        Ok(Data::Integer(1))
+    }
+}
@@ -0,0 +1,61 @@
+//! SPOP command
+//!
+//! # command syntax
+//! SPOP key \[count\]
+//!
+//! <https://redis.io/commands/spop>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SPop commnad empty struct
+pub(super) struct SPop;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SPOP"), Box::new(SPop))
+}
+
+#[async_trait]
+impl super::Command for SPop {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let count = match cmd.next_i64()? {
+            Some(count) => {
+                if count < 0 {
+                    return Ok(Data::error("value is out of range, must be positive"));
+                }
+                super::check_end_of_param!(cmd);
+                Some(count)
+            }
+            None => None,
+        };
+
+        let mut db = db::DB.write().await;
+
+        let set = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_set_mut()?,
+            None => {
+                return match count {
+                    Some(_) => Ok(Data::Array(Vec::new())),
+                    None => Ok(Data::NullBulk),
+                }
+            }
+        };
+        let picked: Vec<Vec<u8>> = super::random_elements(set.iter(), count.unwrap_or(1))?
+            .into_iter()
+            .cloned()
+            .collect();
+        for member in &picked {
+            set.remove(member);
+        }
+        db.remove_if_empty(&key);
+
+        match count {
+            Some(_) => Ok(Data::Array(picked.into_iter().map(Data::Bulk).collect())),
+            None => Ok(picked.into_iter().next().map_or(Data::NullBulk, Data::Bulk)),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! SRANDMEMBER command
+//!
+//! # command syntax
+//! SRANDMEMBER key \[count\]
+//!
+//! <https://redis.io/commands/srandmember>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SRandMember commnad empty struct
+pub(super) struct SRandMember;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SRANDMEMBER"), Box::new(SRandMember))
+}
+
+#[async_trait]
+impl super::Command for SRandMember {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let count = cmd.next_i64()?;
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let set = match db.get(&key) {
+            Some(entry) => entry.value.as_set()?,
+            None => {
+                return match count {
+                    Some(_) => Ok(Data::Array(Vec::new())),
+                    None => Ok(Data::NullBulk),
+                }
+            }
+        };
+        // Negative count may return the same member multiple times.
+        let mut picked = super::random_elements(set.iter(), count.unwrap_or(1))?
+            .into_iter()
+            .map(|member| Data::Bulk(member.clone()));
+
+        match count {
+            Some(_) => Ok(Data::Array(picked.collect())),
+            None => Ok(picked.next().unwrap_or(Data::NullBulk)),
+        }
+    }
+}
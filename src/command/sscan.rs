@@ -0,0 +1,75 @@
+//! SSCAN command
+//!
+//! # command syntax
+//! SSCAN key cursor [MATCH pattern] [COUNT count]
+//!
+//! <https://redis.io/commands/sscan>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// SScan commnad empty struct
+pub(super) struct SScan;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("SSCAN"), Box::new(SScan))
+}
+
+#[async_trait]
+impl super::Command for SScan {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let cursor = super::cursor(&super::next_bytes!(cmd))?;
+
+        let mut pattern = None;
+        let mut count = 10;
+
+        while let Some(param) = cmd.next_string()? {
+            match param.as_str() {
+                "MATCH" => pattern = Some(super::next_bytes!(cmd)),
+                "COUNT" => {
+                    count = super::next_i64!(cmd);
+                    if count < 1 {
+                        return Ok(Data::error("syntax error"));
+                    }
+                }
+                _ => {
+                    return Ok(Data::error("syntax error"));
+                }
+            }
+        }
+
+        let db = db::DB.read().await;
+
+        let set = match db.get(&key) {
+            Some(entry) => entry.value.as_set()?,
+            None => {
+                return Ok(Data::Array(vec![
+                    Data::Bulk(b"0".to_vec()),
+                    Data::Array(Vec::new()),
+                ]))
+            }
+        };
+        let (cursor, members) = db::scan(
+            set.iter().map(|member| (&member[..], member)),
+            cursor,
+            count as usize,
+        );
+
+        let array = members
+            .into_iter()
+            .filter(|member| match &pattern {
+                Some(pattern) => db::glob_match(pattern, member),
+                None => true,
+            })
+            .map(|member| Data::Bulk(member.clone()))
+            .collect();
+        Ok(Data::Array(vec![
+            Data::Bulk(cursor.to_string().into_bytes()),
+            Data::Array(array),
+        ]))
+    }
+}
@@ -0,0 +1,32 @@
+//! STRLEN command
+//!
+//! # command syntax
+//! STRLEN key
+//!
+//! <https://redis.io/commands/strlen>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// StrLen commnad empty struct
+pub(super) struct StrLen;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("STRLEN"), Box::new(StrLen))
+}
+
+#[async_trait]
+impl super::Command for StrLen {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_string()?.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
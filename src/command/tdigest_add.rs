@@ -0,0 +1,46 @@
+//! TDIGEST.ADD command
+//!
+//! # command syntax
+//! TDIGEST.ADD key value [value ...]
+//!
+//! <https://redis.io/commands/tdigest.add>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestAdd commnad empty struct
+pub(super) struct TDigestAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TDIGEST.ADD"), Box::new(TDigestAdd))
+}
+
+#[async_trait]
+impl super::Command for TDigestAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut values = vec![super::next_bytes!(cmd)];
+        while let Some(value) = cmd.next_bytes()? {
+            values.push(value);
+        }
+        let values: Option<Vec<f64>> = values
+            .iter()
+            .map(|value| super::parse_float(value).filter(|value| value.is_finite()))
+            .collect();
+        let values = match values {
+            Some(values) => values,
+            None => return Ok(Data::error("TDIGEST: error parsing val parameter")),
+        };
+
+        let mut db = db::DB.write().await;
+
+        match db.get_mut(&key) {
+            Some(entry) => entry.value.as_tdigest_mut()?.add(&values),
+            None => return Ok(Data::error("TDIGEST: key does not exist")),
+        }
+        Ok(Data::ok())
+    }
+}
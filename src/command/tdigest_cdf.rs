@@ -0,0 +1,41 @@
+//! TDIGEST.CDF command
+//!
+//! # command syntax
+//! TDIGEST.CDF key value [value ...]
+//!
+//! <https://redis.io/commands/tdigest.cdf>
+//!
+use super::tdigest_quantile::{estimation, numbers};
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestCdf commnad empty struct
+pub(super) struct TDigestCdf;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TDIGEST.CDF"), Box::new(TDigestCdf))
+}
+
+#[async_trait]
+impl super::Command for TDigestCdf {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let values = numbers(cmd)?;
+
+        let db = db::DB.read().await;
+
+        let tdigest = match db.get(&key) {
+            Some(entry) => entry.value.as_tdigest()?,
+            None => return Ok(Data::error("TDIGEST: key does not exist")),
+        };
+        Ok(Data::Array(
+            values
+                .iter()
+                .map(|value| estimation(tdigest.cdf(*value)))
+                .collect(),
+        ))
+    }
+}
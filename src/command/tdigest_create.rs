@@ -0,0 +1,52 @@
+//! TDIGEST.CREATE command
+//!
+//! # command syntax
+//! TDIGEST.CREATE key [COMPRESSION compression]
+//!
+//! <https://redis.io/commands/tdigest.create>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestCreate commnad empty struct
+pub(super) struct TDigestCreate;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TDIGEST.CREATE"), Box::new(TDigestCreate))
+}
+
+/// Parse the compression.
+pub(super) fn compression(cmd: &mut Parser) -> crate::Result<Option<u64>> {
+    match cmd.next_i64()? {
+        Some(compression) if compression > 0 => Ok(Some(compression as u64)),
+        Some(_) => Err("TDIGEST: compression parameter needs to be a positive integer".into()),
+        None => Ok(None),
+    }
+}
+
+#[async_trait]
+impl super::Command for TDigestCreate {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let compression = match cmd.next_string()? {
+            Some(option) if option == "COMPRESSION" => match compression(cmd)? {
+                Some(compression) => compression,
+                None => return Ok(Data::error("wrong number of arguments for command")),
+            },
+            Some(_) => return Ok(Data::error("syntax error")),
+            None => db::TDigest::DEFAULT_COMPRESSION,
+        };
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        if db.get(&key).is_some() {
+            return Ok(Data::error("TDIGEST: key already exists"));
+        }
+        db.insert(key, db::Value::TDigest(db::TDigest::new(compression)));
+        Ok(Data::ok())
+    }
+}
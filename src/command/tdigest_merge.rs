@@ -0,0 +1,81 @@
+//! TDIGEST.MERGE command
+//!
+//! # command syntax
+//! TDIGEST.MERGE destination-key numkeys source-key [source-key ...]
+//!   [COMPRESSION compression] \[OVERRIDE\]
+//!
+//! <https://redis.io/commands/tdigest.merge>
+//!
+use super::tdigest_create::compression;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestMerge commnad empty struct
+pub(super) struct TDigestMerge;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TDIGEST.MERGE"), Box::new(TDigestMerge))
+}
+
+#[async_trait]
+impl super::Command for TDigestMerge {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let destination = super::next_bytes!(cmd);
+        let num_keys = super::next_i64!(cmd);
+        if num_keys < 1 {
+            return Ok(Data::error(
+                "TDIGEST: numkeys needs to be a positive integer",
+            ));
+        }
+        let mut keys = Vec::new();
+        for _ in 0..num_keys {
+            keys.push(super::next_bytes!(cmd));
+        }
+        let mut compression_option = None;
+        let mut override_destination = false;
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "COMPRESSION" => match compression(cmd)? {
+                    Some(compression) => compression_option = Some(compression),
+                    None => return Ok(Data::error("wrong number of arguments for command")),
+                },
+                "OVERRIDE" => override_destination = true,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        let mut sources = Vec::new();
+        if !override_destination {
+            if let Some(entry) = db.get(&destination) {
+                sources.push(entry.value.as_tdigest()?);
+            }
+        }
+        for key in &keys {
+            match db.get(key) {
+                Some(entry) => sources.push(entry.value.as_tdigest()?),
+                None => return Ok(Data::error("TDIGEST: key does not exist")),
+            }
+        }
+        let compression = compression_option.unwrap_or_else(|| {
+            sources
+                .iter()
+                .map(|source| source.compression())
+                .max()
+                .unwrap_or(db::TDigest::DEFAULT_COMPRESSION)
+        });
+        let mut merged = db::TDigest::new(compression);
+        for source in sources {
+            merged.merge(source);
+        }
+        match db.get_mut(&destination) {
+            Some(entry) => *entry.value.as_tdigest_mut()? = merged,
+            None => db.insert(destination, db::Value::TDigest(merged)),
+        }
+        Ok(Data::ok())
+    }
+}
@@ -0,0 +1,49 @@
+//! TDIGEST.MIN, TDIGEST.MAX command
+//!
+//! # command syntax
+//! TDIGEST.MIN key
+//!
+//! <https://redis.io/commands/tdigest.min>
+//!
+//! TDIGEST.MAX key
+//!
+//! <https://redis.io/commands/tdigest.max>
+//!
+use super::tdigest_quantile::estimation;
+use super::ScoreEnd;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestMin commnad struct
+pub(super) struct TDigestMin {
+    end: ScoreEnd,
+}
+
+/// command register function
+pub(super) fn command(end: ScoreEnd) -> (String, super::Cmd) {
+    match end {
+        ScoreEnd::Min => (String::from("TDIGEST.MIN"), Box::new(TDigestMin { end })),
+        ScoreEnd::Max => (String::from("TDIGEST.MAX"), Box::new(TDigestMin { end })),
+    }
+}
+
+#[async_trait]
+impl super::Command for TDigestMin {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let tdigest = match db.get(&key) {
+            Some(entry) => entry.value.as_tdigest()?,
+            None => return Ok(Data::error("TDIGEST: key does not exist")),
+        };
+        match self.end {
+            ScoreEnd::Min => Ok(estimation(tdigest.min())),
+            ScoreEnd::Max => Ok(estimation(tdigest.max())),
+        }
+    }
+}
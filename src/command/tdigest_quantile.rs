@@ -0,0 +1,68 @@
+//! TDIGEST.QUANTILE command
+//!
+//! # command syntax
+//! TDIGEST.QUANTILE key quantile [quantile ...]
+//!
+//! <https://redis.io/commands/tdigest.quantile>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestQuantile commnad empty struct
+pub(super) struct TDigestQuantile;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TDIGEST.QUANTILE"), Box::new(TDigestQuantile))
+}
+
+/// Estimated value response.
+/// NaN is the estimation of the empty digest.
+pub(super) fn estimation(value: f64) -> Data {
+    if value.is_nan() {
+        Data::Bulk(b"nan".to_vec())
+    } else {
+        Data::Bulk(super::format_float(value))
+    }
+}
+
+/// Parse the numbers of the rest of the command.
+pub(super) fn numbers(cmd: &mut Parser) -> crate::Result<Vec<f64>> {
+    let mut numbers = Vec::new();
+    while let Some(number) = cmd.next_bytes()? {
+        match super::parse_float(&number) {
+            Some(number) => numbers.push(number),
+            None => return Err("TDIGEST: error parsing number".into()),
+        }
+    }
+    if numbers.is_empty() {
+        return Err("wrong number of arguments for command".into());
+    }
+    Ok(numbers)
+}
+
+#[async_trait]
+impl super::Command for TDigestQuantile {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let quantiles = numbers(cmd)?;
+        if quantiles.iter().any(|q| !(0.0..=1.0).contains(q)) {
+            return Ok(Data::error("TDIGEST: quantile should be in [0,1]"));
+        }
+
+        let db = db::DB.read().await;
+
+        let tdigest = match db.get(&key) {
+            Some(entry) => entry.value.as_tdigest()?,
+            None => return Ok(Data::error("TDIGEST: key does not exist")),
+        };
+        Ok(Data::Array(
+            quantiles
+                .iter()
+                .map(|q| estimation(tdigest.quantile(*q)))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,83 @@
+//! TDIGEST.RANK, TDIGEST.REVRANK command
+//!
+//! # command syntax
+//! TDIGEST.RANK key value [value ...]
+//!
+//! <https://redis.io/commands/tdigest.rank>
+//!
+//! TDIGEST.REVRANK key value [value ...]
+//!
+//! <https://redis.io/commands/tdigest.revrank>
+//!
+use super::tdigest_quantile::numbers;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestRank commnad struct
+pub(super) struct TDigestRank {
+    /// Rank from the largest value.
+    reverse: bool,
+}
+
+/// command register function
+pub(super) fn command(reverse: bool) -> (String, super::Cmd) {
+    let name = if reverse {
+        "TDIGEST.REVRANK"
+    } else {
+        "TDIGEST.RANK"
+    };
+    (String::from(name), Box::new(TDigestRank { reverse }))
+}
+
+/// Round half toward zero.
+fn round_half_down(value: f64) -> i64 {
+    let integer = value.trunc();
+    if value - integer <= 0.5 {
+        integer as i64
+    } else {
+        integer as i64 + 1
+    }
+}
+
+#[async_trait]
+impl super::Command for TDigestRank {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let values = numbers(cmd)?;
+
+        let db = db::DB.read().await;
+
+        let tdigest = match db.get(&key) {
+            Some(entry) => entry.value.as_tdigest()?,
+            None => return Ok(Data::error("TDIGEST: key does not exist")),
+        };
+        let total = tdigest.total();
+        let rank = |value: f64| {
+            if total == 0.0 {
+                return -2;
+            }
+            let (below, above) = if self.reverse {
+                (total as i64, -1)
+            } else {
+                (-1, total as i64)
+            };
+            if value < tdigest.min() {
+                below
+            } else if value > tdigest.max() {
+                above
+            } else if self.reverse {
+                round_half_down(total - tdigest.rank(value))
+            } else {
+                round_half_down(tdigest.rank(value))
+            }
+        };
+        Ok(Data::Array(
+            values
+                .iter()
+                .map(|value| Data::Integer(rank(*value)))
+                .collect(),
+        ))
+    }
+}
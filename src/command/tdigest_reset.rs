@@ -0,0 +1,35 @@
+//! TDIGEST.RESET command
+//!
+//! # command syntax
+//! TDIGEST.RESET key
+//!
+//! <https://redis.io/commands/tdigest.reset>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestReset commnad empty struct
+pub(super) struct TDigestReset;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TDIGEST.RESET"), Box::new(TDigestReset))
+}
+
+#[async_trait]
+impl super::Command for TDigestReset {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        match db.get_mut(&key) {
+            Some(entry) => entry.value.as_tdigest_mut()?.reset(),
+            None => return Ok(Data::error("TDIGEST: key does not exist")),
+        }
+        Ok(Data::ok())
+    }
+}
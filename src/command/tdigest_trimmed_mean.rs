@@ -0,0 +1,59 @@
+//! TDIGEST.TRIMMED_MEAN command
+//!
+//! # command syntax
+//! TDIGEST.TRIMMED_MEAN key low_cut_quantile high_cut_quantile
+//!
+//! <https://redis.io/commands/tdigest.trimmed_mean>
+//!
+use super::tdigest_quantile::estimation;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TDigestTrimmedMean commnad empty struct
+pub(super) struct TDigestTrimmedMean;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (
+        String::from("TDIGEST.TRIMMED_MEAN"),
+        Box::new(TDigestTrimmedMean),
+    )
+}
+
+#[async_trait]
+impl super::Command for TDigestTrimmedMean {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let low = super::parse_float(&super::next_bytes!(cmd));
+        let high = super::parse_float(&super::next_bytes!(cmd));
+        super::check_end_of_param!(cmd);
+        let (low, high) = match (low, high) {
+            (Some(low), Some(high))
+                if (0.0..=1.0).contains(&low) && (0.0..=1.0).contains(&high) =>
+            {
+                (low, high)
+            }
+            _ => {
+                return Ok(Data::error(
+                    "TDIGEST: low_cut_percentile and high_cut_percentile should be in [0,1]",
+                ))
+            }
+        };
+        if low >= high {
+            return Ok(Data::error(
+                "TDIGEST: low_cut_percentile should be lower than high_cut_percentile",
+            ));
+        }
+
+        let db = db::DB.read().await;
+
+        match db.get(&key) {
+            Some(entry) => Ok(estimation(
+                entry.value.as_tdigest()?.trimmed_mean(low, high),
+            )),
+            None => Ok(Data::error("TDIGEST: key does not exist")),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! TOPK.ADD, TOPK.INCRBY command
+//!
+//! # command syntax
+//! TOPK.ADD key item [item ...]
+//!
+//! <https://redis.io/commands/topk.add>
+//!
+//! TOPK.INCRBY key item increment [item increment ...]
+//!
+//! <https://redis.io/commands/topk.incrby>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TopkAdd commnad struct
+pub(super) struct TopkAdd {
+    /// Each item has the increment.
+    increment: bool,
+}
+
+/// command register function
+pub(super) fn command(increment: bool) -> (String, super::Cmd) {
+    let name = if increment { "TOPK.INCRBY" } else { "TOPK.ADD" };
+    (String::from(name), Box::new(TopkAdd { increment }))
+}
+
+/// Parse the increment of the item.
+fn increment(cmd: &mut Parser) -> crate::Result<Option<u64>> {
+    match cmd.next_i64()? {
+        Some(increment) if (1..=db::TopK::MAX_INCREMENT as i64).contains(&increment) => {
+            Ok(Some(increment as u64))
+        }
+        Some(_) => Err(format!(
+            "TopK: increment must be an integer between 1 and {}",
+            db::TopK::MAX_INCREMENT
+        )
+        .into()),
+        None => Ok(None),
+    }
+}
+
+#[async_trait]
+impl super::Command for TopkAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut items = Vec::new();
+        while let Some(item) = cmd.next_bytes()? {
+            let increment = if self.increment {
+                match increment(cmd)? {
+                    Some(increment) => increment,
+                    None => return Ok(Data::error("wrong number of arguments for command")),
+                }
+            } else {
+                1
+            };
+            items.push((item, increment));
+        }
+        if items.is_empty() {
+            return Ok(Data::error("wrong number of arguments for command"));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let top_k = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_top_k_mut()?,
+            None => return Ok(Data::error("TopK: key does not exist")),
+        };
+        Ok(Data::Array(
+            items
+                .iter()
+                .map(|(item, increment)| match top_k.incr_by(item, *increment) {
+                    Some(expelled) => Data::Bulk(expelled),
+                    None => Data::NullBulk,
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,44 @@
+//! TOPK.INFO command
+//!
+//! # command syntax
+//! TOPK.INFO key
+//!
+//! <https://redis.io/commands/topk.info>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TopkInfo commnad empty struct
+pub(super) struct TopkInfo;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TOPK.INFO"), Box::new(TopkInfo))
+}
+
+#[async_trait]
+impl super::Command for TopkInfo {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let top_k = match db.get(&key) {
+            Some(entry) => entry.value.as_top_k()?,
+            None => return Ok(Data::error("TopK: key does not exist")),
+        };
+        Ok(Data::Array(vec![
+            Data::Bulk(b"k".to_vec()),
+            Data::Integer(top_k.k() as i64),
+            Data::Bulk(b"width".to_vec()),
+            Data::Integer(top_k.width() as i64),
+            Data::Bulk(b"depth".to_vec()),
+            Data::Integer(top_k.depth() as i64),
+            Data::Bulk(b"decay".to_vec()),
+            Data::Bulk(super::format_float(top_k.decay())),
+        ]))
+    }
+}
@@ -0,0 +1,47 @@
+//! TOPK.LIST command
+//!
+//! # command syntax
+//! TOPK.LIST key \[WITHCOUNT\]
+//!
+//! <https://redis.io/commands/topk.list>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TopkList commnad empty struct
+pub(super) struct TopkList;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TOPK.LIST"), Box::new(TopkList))
+}
+
+#[async_trait]
+impl super::Command for TopkList {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let with_count = match cmd.next_string()? {
+            Some(option) if option == "WITHCOUNT" => true,
+            Some(_) => return Ok(Data::error("syntax error")),
+            None => false,
+        };
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let top_k = match db.get(&key) {
+            Some(entry) => entry.value.as_top_k()?,
+            None => return Ok(Data::error("TopK: key does not exist")),
+        };
+        let mut response = Vec::new();
+        for (item, count) in top_k.list() {
+            response.push(Data::Bulk(item.to_vec()));
+            if with_count {
+                response.push(Data::Integer(count as i64));
+            }
+        }
+        Ok(Data::Array(response))
+    }
+}
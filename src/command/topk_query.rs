@@ -0,0 +1,43 @@
+//! TOPK.QUERY command
+//!
+//! # command syntax
+//! TOPK.QUERY key item [item ...]
+//!
+//! <https://redis.io/commands/topk.query>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TopkQuery commnad empty struct
+pub(super) struct TopkQuery;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TOPK.QUERY"), Box::new(TopkQuery))
+}
+
+#[async_trait]
+impl super::Command for TopkQuery {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut items = vec![super::next_bytes!(cmd)];
+        while let Some(item) = cmd.next_bytes()? {
+            items.push(item);
+        }
+
+        let db = db::DB.read().await;
+
+        let top_k = match db.get(&key) {
+            Some(entry) => entry.value.as_top_k()?,
+            None => return Ok(Data::error("TopK: key does not exist")),
+        };
+        Ok(Data::Array(
+            items
+                .iter()
+                .map(|item| Data::Integer(top_k.contains(item) as i64))
+                .collect(),
+        ))
+    }
+}
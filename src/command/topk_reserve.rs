@@ -0,0 +1,65 @@
+//! TOPK.RESERVE command
+//!
+//! # command syntax
+//! TOPK.RESERVE key topk [width depth decay]
+//!
+//! <https://redis.io/commands/topk.reserve>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TopkReserve commnad empty struct
+pub(super) struct TopkReserve;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TOPK.RESERVE"), Box::new(TopkReserve))
+}
+
+#[async_trait]
+impl super::Command for TopkReserve {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let k = super::next_i64!(cmd);
+        if k < 1 {
+            return Ok(Data::error("TopK: invalid k"));
+        }
+        let (width, depth, decay) = match cmd.next_i64()? {
+            Some(width) => (width, super::next_i64!(cmd), super::next_bytes!(cmd)),
+            None => (
+                db::TopK::DEFAULT_WIDTH as i64,
+                db::TopK::DEFAULT_DEPTH as i64,
+                super::format_float(db::TopK::DEFAULT_DECAY),
+            ),
+        };
+        super::check_end_of_param!(cmd);
+        if width < 1 {
+            return Ok(Data::error("TopK: invalid width"));
+        }
+        if depth < 1 {
+            return Ok(Data::error("TopK: invalid depth"));
+        }
+        let decay = match super::parse_float(&decay) {
+            Some(decay) if 0.0 < decay && decay <= 1.0 => decay,
+            _ => {
+                return Ok(Data::error(
+                    "TopK: invalid decay value. must be '<= 1' & '> 0'",
+                ))
+            }
+        };
+        let top_k = match db::TopK::new(k as u64, width as u64, depth as u64, decay) {
+            Some(top_k) => top_k,
+            None => return Ok(Data::error("TopK: Top-K is too large")),
+        };
+
+        let mut db = db::DB.write().await;
+
+        if db.get(&key).is_some() {
+            return Ok(Data::error("TopK: key already exists"));
+        }
+        db.insert(key, db::Value::TopK(top_k));
+        Ok(Data::ok())
+    }
+}
@@ -0,0 +1,104 @@
+//! TS.ADD command
+//!
+//! # command syntax
+//! TS.ADD key timestamp value [RETENTION retentionPeriod] [CHUNK_SIZE size]
+//!   [DUPLICATE_POLICY policy] [ON_DUPLICATE policy] [LABELS label value ...]
+//!
+//! <https://redis.io/commands/ts.add>
+//!
+use super::ts_create::{duplicate_policy, insert, CreateOptions};
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TsAdd commnad empty struct
+pub(super) struct TsAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TS.ADD"), Box::new(TsAdd))
+}
+
+/// Parse the timestamp. `*` is the current time.
+pub(super) fn timestamp(bytes: &[u8]) -> crate::Result<i64> {
+    if bytes == b"*" {
+        return Ok(db::unix_time_ms() as i64);
+    }
+    match super::parse_integer(bytes) {
+        Some(timestamp) if timestamp >= 0 => Ok(timestamp),
+        _ => Err("TSDB: invalid timestamp, must be a nonnegative integer".into()),
+    }
+}
+
+/// Parse the value of the sample.
+pub(super) fn value(bytes: &[u8]) -> crate::Result<f64> {
+    match super::parse_float(bytes) {
+        Some(value) => Ok(value),
+        None => Err("TSDB: invalid value".into()),
+    }
+}
+
+/// Add the sample to the time series, and the closed buckets to the destinations of the rules.
+/// Return the timestamp or the error response.
+pub(super) fn add_sample(
+    db: &mut db::DBManager,
+    key: &[u8],
+    timestamp: i64,
+    value: f64,
+    policy: Option<db::DuplicatePolicy>,
+) -> crate::Result<Data> {
+    let series = match db.get_mut(key) {
+        Some(entry) => entry.value.as_time_series_mut()?,
+        None => return Ok(Data::error("TSDB: the key does not exist")),
+    };
+    let mut compactions = match series.add(timestamp, value, policy) {
+        Ok(compactions) => compactions,
+        Err(db::SampleError::TooOld) => {
+            return Ok(Data::error("TSDB: Timestamp is older than retention"))
+        }
+        Err(db::SampleError::Blocked) => {
+            return Ok(Data::error(
+                "TSDB: Error at upsert, update is not supported when DUPLICATE_POLICY is set to BLOCK mode",
+            ))
+        }
+    };
+    // The destinations may also be the sources of the other rules.
+    while let Some((destination, timestamp, value)) = compactions.pop() {
+        if let Some(db::BDEntry {
+            value: db::Value::TimeSeries(series),
+            ..
+        }) = db.get_mut(&destination)
+        {
+            if let Ok(closed) = series.add(timestamp, value, Some(db::DuplicatePolicy::Last)) {
+                compactions.extend(closed);
+            }
+        }
+    }
+    Ok(Data::Integer(timestamp))
+}
+
+#[async_trait]
+impl super::Command for TsAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let timestamp = timestamp(&super::next_bytes!(cmd))?;
+        let value = value(&super::next_bytes!(cmd))?;
+        let mut options = CreateOptions::default();
+        let mut on_duplicate = None;
+        while let Some(option) = cmd.next_string()? {
+            if option == "ON_DUPLICATE" {
+                on_duplicate = Some(duplicate_policy(cmd)?);
+            } else if !options.parse(&option, cmd)? {
+                return Ok(Data::error("syntax error"));
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        if db.get(&key).is_none() {
+            insert(&mut db, key.clone(), options.create());
+        }
+        add_sample(&mut db, &key, timestamp, value, on_duplicate)
+    }
+}
@@ -0,0 +1,114 @@
+//! TS.CREATE command
+//!
+//! # command syntax
+//! TS.CREATE key [RETENTION retentionPeriod] [CHUNK_SIZE size]
+//!   [DUPLICATE_POLICY policy] [LABELS label value ...]
+//!
+//! <https://redis.io/commands/ts.create>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TsCreate commnad empty struct
+pub(super) struct TsCreate;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TS.CREATE"), Box::new(TsCreate))
+}
+
+/// Options of creating the time series.
+#[derive(Default)]
+pub(super) struct CreateOptions {
+    retention: Option<i64>,
+    chunk_size: Option<usize>,
+    duplicate_policy: Option<db::DuplicatePolicy>,
+    labels: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl CreateOptions {
+    /// Parse the option of creating the time series.
+    /// Return false if the option is not for creating.
+    /// LABELS takes all the rest of the command.
+    pub(super) fn parse(&mut self, option: &str, cmd: &mut Parser) -> crate::Result<bool> {
+        match option {
+            "RETENTION" => match cmd.next_i64()? {
+                Some(retention) if retention >= 0 => self.retention = Some(retention),
+                _ => return Err("TSDB: invalid retention".into()),
+            },
+            "CHUNK_SIZE" => match cmd.next_i64()? {
+                Some(size) if (48..=1048576).contains(&size) && size % 8 == 0 => {
+                    self.chunk_size = Some(size as usize)
+                }
+                _ => return Err(
+                    "TSDB: CHUNK_SIZE value must be a multiple of 8 in the range [48 .. 1048576]"
+                        .into(),
+                ),
+            },
+            "DUPLICATE_POLICY" => self.duplicate_policy = Some(duplicate_policy(cmd)?),
+            "LABELS" => {
+                while let Some(label) = cmd.next_bytes()? {
+                    match cmd.next_bytes()? {
+                        Some(value) => self.labels.push((label, value)),
+                        None => return Err("TSDB: invalid labels".into()),
+                    }
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+    /// Create an empty time series.
+    pub(super) fn create(self) -> db::TimeSeries {
+        db::TimeSeries::new(
+            self.retention.unwrap_or(0),
+            self.duplicate_policy.unwrap_or(db::DuplicatePolicy::Block),
+            self.chunk_size
+                .unwrap_or(db::TimeSeries::DEFAULT_CHUNK_SIZE),
+            self.labels,
+        )
+    }
+}
+
+/// Parse the duplicate policy.
+pub(super) fn duplicate_policy(cmd: &mut Parser) -> crate::Result<db::DuplicatePolicy> {
+    match cmd
+        .next_string()?
+        .as_deref()
+        .and_then(db::DuplicatePolicy::parse)
+    {
+        Some(policy) => Ok(policy),
+        None => Err("TSDB: Unknown DUPLICATE_POLICY".into()),
+    }
+}
+
+/// Insert the time series.
+pub(super) fn insert(db: &mut db::DBManager, key: Vec<u8>, series: db::TimeSeries) {
+    if series.retention() > 0 {
+        db.register_retention(&key);
+    }
+    db.insert(key, db::Value::TimeSeries(series));
+}
+
+#[async_trait]
+impl super::Command for TsCreate {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut options = CreateOptions::default();
+        while let Some(option) = cmd.next_string()? {
+            if !options.parse(&option, cmd)? {
+                return Ok(Data::error("syntax error"));
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        if db.get(&key).is_some() {
+            return Ok(Data::error("TSDB: key already exists"));
+        }
+        insert(&mut db, key, options.create());
+        Ok(Data::ok())
+    }
+}
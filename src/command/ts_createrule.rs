@@ -0,0 +1,73 @@
+//! TS.CREATERULE command
+//!
+//! # command syntax
+//! TS.CREATERULE sourceKey destKey AGGREGATION aggregator bucketDuration [alignTimestamp]
+//!
+//! <https://redis.io/commands/ts.createrule>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TsCreateRule commnad empty struct
+pub(super) struct TsCreateRule;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TS.CREATERULE"), Box::new(TsCreateRule))
+}
+
+#[async_trait]
+impl super::Command for TsCreateRule {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let source = super::next_bytes!(cmd);
+        let destination = super::next_bytes!(cmd);
+        if super::next_string!(cmd) != "AGGREGATION" {
+            return Ok(Data::error("syntax error"));
+        }
+        let aggregator = match db::Aggregator::parse(&super::next_string!(cmd)) {
+            Some(aggregator) => aggregator,
+            None => return Ok(Data::error("TSDB: Unknown aggregation type")),
+        };
+        let bucket_duration = super::next_i64!(cmd);
+        if bucket_duration <= 0 {
+            return Ok(Data::error(
+                "TSDB: bucketDuration must be greater than zero",
+            ));
+        }
+        let align = cmd.next_i64()?.unwrap_or(0);
+        super::check_end_of_param!(cmd);
+        if source == destination {
+            return Ok(Data::error(
+                "TSDB: the source key and destination key should be different",
+            ));
+        }
+
+        let mut db = db::DB.write().await;
+
+        match db.get(&destination) {
+            Some(entry) => {
+                let series = entry.value.as_time_series()?;
+                // A destination without rules makes no cycles.
+                if series.source().is_some() || !series.rules().is_empty() {
+                    return Ok(Data::error("TSDB: the destination key already has a rule"));
+                }
+            }
+            None => return Ok(Data::error("TSDB: the key does not exist")),
+        }
+        match db.get_mut(&source) {
+            Some(entry) => entry.value.as_time_series_mut()?.add_rule(db::Rule::new(
+                destination.clone(),
+                aggregator,
+                bucket_duration,
+                align,
+            )),
+            None => return Ok(Data::error("TSDB: the key does not exist")),
+        }
+        if let Some(entry) = db.get_mut(&destination) {
+            entry.value.as_time_series_mut()?.set_source(source);
+        }
+        Ok(Data::ok())
+    }
+}
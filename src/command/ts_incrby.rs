@@ -0,0 +1,64 @@
+//! TS.INCRBY command
+//!
+//! # command syntax
+//! TS.INCRBY key addend [TIMESTAMP timestamp] [RETENTION retentionPeriod]
+//!   [CHUNK_SIZE size] [DUPLICATE_POLICY policy] [LABELS label value ...]
+//!
+//! <https://redis.io/commands/ts.incrby>
+//!
+use super::ts_add::{add_sample, timestamp, value};
+use super::ts_create::{insert, CreateOptions};
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TsIncrBy commnad empty struct
+pub(super) struct TsIncrBy;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TS.INCRBY"), Box::new(TsIncrBy))
+}
+
+#[async_trait]
+impl super::Command for TsIncrBy {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let addend = value(&super::next_bytes!(cmd))?;
+        let mut options = CreateOptions::default();
+        let mut timestamp_option = None;
+        while let Some(option) = cmd.next_string()? {
+            if option == "TIMESTAMP" {
+                timestamp_option = Some(timestamp(&super::next_bytes!(cmd))?);
+            } else if !options.parse(&option, cmd)? {
+                return Ok(Data::error("syntax error"));
+            }
+        }
+        let timestamp = timestamp_option.unwrap_or_else(|| db::unix_time_ms() as i64);
+
+        let mut db = db::DB.write().await;
+
+        let last = match db.get(&key) {
+            Some(entry) => entry.value.as_time_series()?.last(),
+            None => {
+                insert(&mut db, key.clone(), options.create());
+                None
+            }
+        };
+        let value = match last {
+            Some((last, _)) if timestamp < last => return Ok(Data::error(
+                "TSDB: timestamp must be equal to or higher than the maximum existing timestamp",
+            )),
+            Some((_, value)) => value + addend,
+            None => addend,
+        };
+        add_sample(
+            &mut db,
+            &key,
+            timestamp,
+            value,
+            Some(db::DuplicatePolicy::Last),
+        )
+    }
+}
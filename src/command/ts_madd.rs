@@ -0,0 +1,46 @@
+//! TS.MADD command
+//!
+//! # command syntax
+//! TS.MADD key timestamp value [key timestamp value ...]
+//!
+//! <https://redis.io/commands/ts.madd>
+//!
+use super::ts_add::{add_sample, timestamp, value};
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// TsMAdd commnad empty struct
+pub(super) struct TsMAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TS.MADD"), Box::new(TsMAdd))
+}
+
+#[async_trait]
+impl super::Command for TsMAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let mut samples = Vec::new();
+        while let Some(key) = cmd.next_bytes()? {
+            let timestamp = timestamp(&super::next_bytes!(cmd))?;
+            let value = value(&super::next_bytes!(cmd))?;
+            samples.push((key, timestamp, value));
+        }
+        if samples.is_empty() {
+            return Ok(Data::error("wrong number of arguments for command"));
+        }
+
+        let mut db = db::DB.write().await;
+
+        let mut responses = Vec::new();
+        for (key, timestamp, value) in samples {
+            responses.push(match add_sample(&mut db, &key, timestamp, value, None) {
+                Ok(response) => response,
+                Err(e) => super::error_response(&e),
+            });
+        }
+        Ok(Data::Array(responses))
+    }
+}
@@ -0,0 +1,249 @@
+//! TS.MRANGE command
+//!
+//! # command syntax
+//! TS.MRANGE fromTimestamp toTimestamp [FILTER_BY_TS ts ...] [FILTER_BY_VALUE min max]
+//!   [WITHLABELS | SELECTED_LABELS label ...] [COUNT count]
+//!   [[ALIGN align] AGGREGATION aggregator bucketDuration]
+//!   FILTER filterExpr ... [GROUPBY label REDUCE reducer]
+//!
+//! <https://redis.io/commands/ts.mrange>
+//!
+use super::ts_range::{args, keyword, next_arg, range_timestamp, samples_response, RangeOptions};
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+/// TsMRange commnad empty struct
+pub(super) struct TsMRange;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("TS.MRANGE"), Box::new(TsMRange))
+}
+
+/// Options taking the variable number of the arguments end at these.
+const OPTIONS: [&str; 9] = [
+    "FILTER_BY_TS",
+    "FILTER_BY_VALUE",
+    "WITHLABELS",
+    "SELECTED_LABELS",
+    "COUNT",
+    "ALIGN",
+    "AGGREGATION",
+    "FILTER",
+    "GROUPBY",
+];
+
+/// Label matcher of the filter expression.
+struct Matcher {
+    label: Vec<u8>,
+    /// Empty means the label does not exist.
+    values: Vec<Vec<u8>>,
+    /// `=` or `!=`.
+    equal: bool,
+}
+
+impl Matcher {
+    /// Parse `label=value`, `label!=value`, `label=(value,...)` or `label!=(value,...)`.
+    fn parse(expression: &[u8]) -> Option<Self> {
+        let position = expression.iter().position(|&c| c == b'=')?;
+        let (label, equal) = match expression[..position].strip_suffix(b"!") {
+            Some(label) => (label, false),
+            None => (&expression[..position], true),
+        };
+        if label.is_empty() {
+            return None;
+        }
+        let value = &expression[position + 1..];
+        let values = match value.strip_prefix(b"(").and_then(|v| v.strip_suffix(b")")) {
+            Some(list) => list.split(|&c| c == b',').map(<[u8]>::to_vec).collect(),
+            None if value.is_empty() => Vec::new(),
+            None => vec![value.to_vec()],
+        };
+        Some(Matcher {
+            label: label.to_vec(),
+            values,
+            equal,
+        })
+    }
+    /// The matcher selects the series by the existing label.
+    fn is_positive(&self) -> bool {
+        self.equal && !self.values.is_empty()
+    }
+    fn matches(&self, series: &db::TimeSeries) -> bool {
+        let value = series.label(&self.label);
+        match (self.values.is_empty(), value) {
+            (true, value) => value.is_none() == self.equal,
+            (false, None) => !self.equal,
+            (false, Some(value)) => self.values.iter().any(|v| v == value) == self.equal,
+        }
+    }
+}
+
+/// Labels in the response.
+enum Labels {
+    None,
+    All,
+    Selected(Vec<Vec<u8>>),
+}
+
+/// Response of the labels.
+fn labels_response(labels: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Data {
+    Data::Array(
+        labels
+            .into_iter()
+            .map(|(label, value)| {
+                Data::Array(vec![
+                    Data::Bulk(label),
+                    value.map_or(Data::NullBulk, Data::Bulk),
+                ])
+            })
+            .collect(),
+    )
+}
+
+#[async_trait]
+impl super::Command for TsMRange {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let from = range_timestamp(&super::next_bytes!(cmd))?;
+        let to = range_timestamp(&super::next_bytes!(cmd))?;
+        let mut args = args(cmd)?;
+        let mut options = RangeOptions::new();
+        let mut labels = Labels::None;
+        let mut matchers = Vec::new();
+        let mut group_by = None;
+        while let Some(option) = args.next() {
+            let option = keyword(&option);
+            match option.as_str() {
+                "WITHLABELS" => labels = Labels::All,
+                "SELECTED_LABELS" => {
+                    let mut selected = Vec::new();
+                    while let Some(label) =
+                        args.next_if(|arg| !OPTIONS.contains(&keyword(arg).as_str()))
+                    {
+                        selected.push(label);
+                    }
+                    if selected.is_empty() {
+                        return Ok(Data::error("wrong number of arguments for command"));
+                    }
+                    labels = Labels::Selected(selected);
+                }
+                "FILTER" => {
+                    while let Some(expression) = args.next_if(|arg| keyword(arg) != "GROUPBY") {
+                        match Matcher::parse(&expression) {
+                            Some(matcher) => matchers.push(matcher),
+                            None => return Ok(Data::error("TSDB: failed parsing labels")),
+                        }
+                    }
+                }
+                "GROUPBY" => {
+                    let label = next_arg(&mut args)?;
+                    if keyword(&next_arg(&mut args)?) != "REDUCE" {
+                        return Ok(Data::error("syntax error"));
+                    }
+                    match db::Aggregator::parse(&keyword(&next_arg(&mut args)?)) {
+                        Some(reducer) => group_by = Some((label, reducer)),
+                        None => return Ok(Data::error("TSDB: Unknown reducer type")),
+                    }
+                }
+                _ => {
+                    if !options.parse(&option, &mut args)? {
+                        return Ok(Data::error("syntax error"));
+                    }
+                }
+            }
+        }
+        options.validate()?;
+        if !matchers.iter().any(Matcher::is_positive) {
+            return Ok(Data::error("TSDB: please provide at least one matcher"));
+        }
+
+        let db = db::DB.read().await;
+
+        let mut series: Vec<(&[u8], &db::TimeSeries)> = db
+            .time_series()
+            .filter(|(_, series)| matchers.iter().all(|matcher| matcher.matches(series)))
+            .collect();
+        series.sort_by(|a, b| a.0.cmp(b.0));
+
+        let (label, reducer) = match group_by {
+            Some(group_by) => group_by,
+            None => {
+                return Ok(Data::Array(
+                    series
+                        .into_iter()
+                        .map(|(key, series)| {
+                            let labels = match &labels {
+                                Labels::None => Vec::new(),
+                                Labels::All => series
+                                    .labels()
+                                    .iter()
+                                    .map(|(label, value)| (label.clone(), Some(value.clone())))
+                                    .collect(),
+                                Labels::Selected(selected) => selected
+                                    .iter()
+                                    .map(|label| {
+                                        (label.clone(), series.label(label).map(<[u8]>::to_vec))
+                                    })
+                                    .collect(),
+                            };
+                            Data::Array(vec![
+                                Data::Bulk(key.to_vec()),
+                                labels_response(labels),
+                                samples_response(&options.query(series, from, to, false)),
+                            ])
+                        })
+                        .collect(),
+                ))
+            }
+        };
+
+        // Reduce the samples with the same timestamp in each group.
+        let mut groups: BTreeMap<&[u8], (Vec<&[u8]>, BTreeMap<i64, db::Aggregation>)> =
+            BTreeMap::new();
+        for (key, series) in series {
+            let value = match series.label(&label) {
+                Some(value) => value,
+                None => continue,
+            };
+            let (keys, samples) = groups.entry(value).or_default();
+            keys.push(key);
+            for (timestamp, value) in options.query(series, from, to, false) {
+                samples
+                    .entry(timestamp)
+                    .and_modify(|aggregation| aggregation.add(value))
+                    .or_insert_with(|| db::Aggregation::new(value));
+            }
+        }
+        Ok(Data::Array(
+            groups
+                .into_iter()
+                .map(|(value, (keys, samples))| {
+                    let name = [label.as_slice(), b"=", value].concat();
+                    let labels = match labels {
+                        Labels::None => Vec::new(),
+                        _ => vec![
+                            (label.clone(), Some(value.to_vec())),
+                            (
+                                b"__reducer__".to_vec(),
+                                Some(reducer.name().as_bytes().to_vec()),
+                            ),
+                            (b"__source__".to_vec(), Some(keys.join(&b","[..]))),
+                        ],
+                    };
+                    let samples: Vec<(i64, f64)> = samples
+                        .into_iter()
+                        .map(|(timestamp, aggregation)| (timestamp, aggregation.value(reducer)))
+                        .collect();
+                    Data::Array(vec![
+                        Data::Bulk(name),
+                        labels_response(labels),
+                        samples_response(&samples),
+                    ])
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,257 @@
+//! TS.RANGE, TS.REVRANGE command
+//!
+//! # command syntax
+//! TS.RANGE key fromTimestamp toTimestamp [FILTER_BY_TS ts ...] [FILTER_BY_VALUE min max]
+//!   [COUNT count] [[ALIGN align] AGGREGATION aggregator bucketDuration]
+//!
+//! <https://redis.io/commands/ts.range>
+//!
+//! TS.REVRANGE key fromTimestamp toTimestamp [FILTER_BY_TS ts ...] [FILTER_BY_VALUE min max]
+//!   [COUNT count] [[ALIGN align] AGGREGATION aggregator bucketDuration]
+//!
+//! <https://redis.io/commands/ts.revrange>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+/// TsRange commnad struct
+pub(super) struct TsRange {
+    /// In descending order of the timestamps.
+    reverse: bool,
+}
+
+/// command register function
+pub(super) fn command(reverse: bool) -> (String, super::Cmd) {
+    match reverse {
+        false => (String::from("TS.RANGE"), Box::new(TsRange { reverse })),
+        true => (String::from("TS.REVRANGE"), Box::new(TsRange { reverse })),
+    }
+}
+
+/// The rest of the command.
+/// The options taking the variable number of the arguments end at the next option.
+pub(super) type Args = Peekable<IntoIter<Vec<u8>>>;
+
+/// Collect the rest of the command.
+pub(super) fn args(cmd: &mut Parser) -> crate::Result<Args> {
+    let mut args = Vec::new();
+    while let Some(arg) = cmd.next_bytes()? {
+        args.push(arg);
+    }
+    Ok(args.into_iter().peekable())
+}
+
+/// Next argument of the option.
+pub(super) fn next_arg(args: &mut Args) -> crate::Result<Vec<u8>> {
+    args.next()
+        .ok_or_else(|| "wrong number of arguments for command".into())
+}
+
+/// Uppercase option name.
+pub(super) fn keyword(arg: &[u8]) -> String {
+    String::from_utf8_lossy(arg).to_uppercase()
+}
+
+/// Parse the timestamp of the range. `-` and `+` are the oldest and the newest.
+pub(super) fn range_timestamp(bytes: &[u8]) -> crate::Result<i64> {
+    match bytes {
+        b"-" => Ok(0),
+        b"+" => Ok(i64::MAX),
+        _ => match super::parse_integer(bytes) {
+            Some(timestamp) if timestamp >= 0 => Ok(timestamp),
+            _ => Err("TSDB: invalid timestamp".into()),
+        },
+    }
+}
+
+/// Alignment of the buckets.
+#[derive(Clone, Copy)]
+enum Align {
+    Start,
+    End,
+    Timestamp(i64),
+}
+
+/// Options of querying the samples.
+pub(super) struct RangeOptions {
+    filter_by_ts: Option<Vec<i64>>,
+    filter_by_value: Option<(f64, f64)>,
+    count: Option<usize>,
+    align: Option<Align>,
+    aggregation: Option<(db::Aggregator, i64)>,
+}
+
+impl RangeOptions {
+    pub(super) fn new() -> Self {
+        RangeOptions {
+            filter_by_ts: None,
+            filter_by_value: None,
+            count: None,
+            align: None,
+            aggregation: None,
+        }
+    }
+    /// Parse the option of querying the samples.
+    /// Return false if the option is not for querying.
+    pub(super) fn parse(&mut self, option: &str, args: &mut Args) -> crate::Result<bool> {
+        match option {
+            "FILTER_BY_TS" => {
+                let mut timestamps = Vec::new();
+                while let Some(timestamp) = args.peek().and_then(|arg| super::parse_integer(arg)) {
+                    timestamps.push(timestamp);
+                    args.next();
+                }
+                if timestamps.is_empty() {
+                    return Err("TSDB: FILTER_BY_TS one or more arguments are missing".into());
+                }
+                timestamps.sort_unstable();
+                self.filter_by_ts = Some(timestamps);
+            }
+            "FILTER_BY_VALUE" => {
+                let min = super::parse_float(&next_arg(args)?);
+                let max = super::parse_float(&next_arg(args)?);
+                match (min, max) {
+                    (Some(min), Some(max)) => self.filter_by_value = Some((min, max)),
+                    _ => return Err("TSDB: Couldn't parse MIN or MAX".into()),
+                }
+            }
+            "COUNT" => match super::parse_integer(&next_arg(args)?) {
+                Some(count) if count > 0 => self.count = Some(count as usize),
+                _ => return Err("TSDB: Invalid COUNT value".into()),
+            },
+            "ALIGN" => {
+                let align = next_arg(args)?;
+                self.align = Some(match align.as_slice() {
+                    b"-" => Align::Start,
+                    b"+" => Align::End,
+                    _ => match keyword(&align).as_str() {
+                        "START" => Align::Start,
+                        "END" => Align::End,
+                        _ => match super::parse_integer(&align) {
+                            Some(timestamp) => Align::Timestamp(timestamp),
+                            None => return Err("TSDB: unknown ALIGN parameter".into()),
+                        },
+                    },
+                });
+            }
+            "AGGREGATION" => {
+                let aggregator = match db::Aggregator::parse(&keyword(&next_arg(args)?)) {
+                    Some(aggregator) => aggregator,
+                    None => return Err("TSDB: Unknown aggregation type".into()),
+                };
+                match super::parse_integer(&next_arg(args)?) {
+                    Some(bucket_duration) if bucket_duration > 0 => {
+                        self.aggregation = Some((aggregator, bucket_duration))
+                    }
+                    _ => return Err("TSDB: bucketDuration must be greater than zero".into()),
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+    /// Check the combination of the options.
+    pub(super) fn validate(&self) -> crate::Result<()> {
+        if self.align.is_some() && self.aggregation.is_none() {
+            return Err("TSDB: ALIGN parameter can only be used with AGGREGATION".into());
+        }
+        Ok(())
+    }
+    /// Query the samples between the timestamps, aggregated if requested.
+    pub(super) fn query(
+        &self,
+        series: &db::TimeSeries,
+        from: i64,
+        to: i64,
+        reverse: bool,
+    ) -> Vec<(i64, f64)> {
+        let mut samples: Vec<(i64, f64)> = series
+            .range(from, to)
+            .into_iter()
+            .filter(|(timestamp, value)| {
+                self.filter_by_ts
+                    .as_ref()
+                    .is_none_or(|timestamps| timestamps.binary_search(timestamp).is_ok())
+                    && self
+                        .filter_by_value
+                        .is_none_or(|(min, max)| min <= *value && *value <= max)
+            })
+            .collect();
+        if let Some((aggregator, bucket_duration)) = self.aggregation {
+            let align = match self.align {
+                None => 0,
+                Some(Align::Start) => from,
+                Some(Align::End) => to,
+                Some(Align::Timestamp(timestamp)) => timestamp,
+            };
+            let mut buckets: Vec<(i64, db::Aggregation)> = Vec::new();
+            for (timestamp, value) in samples {
+                let start = db::bucket_start(timestamp, bucket_duration, align);
+                match buckets.last_mut() {
+                    Some((current, aggregation)) if *current == start => aggregation.add(value),
+                    _ => buckets.push((start, db::Aggregation::new(value))),
+                }
+            }
+            samples = buckets
+                .into_iter()
+                .map(|(start, aggregation)| (start, aggregation.value(aggregator)))
+                .collect();
+        }
+        if reverse {
+            samples.reverse();
+        }
+        if let Some(count) = self.count {
+            samples.truncate(count);
+        }
+        samples
+    }
+}
+
+/// Response of the samples.
+pub(super) fn samples_response(samples: &[(i64, f64)]) -> Data {
+    Data::Array(
+        samples
+            .iter()
+            .map(|&(timestamp, value)| {
+                Data::Array(vec![
+                    Data::Integer(timestamp),
+                    Data::Bulk(super::format_float(value)),
+                ])
+            })
+            .collect(),
+    )
+}
+
+#[async_trait]
+impl super::Command for TsRange {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let from = range_timestamp(&super::next_bytes!(cmd))?;
+        let to = range_timestamp(&super::next_bytes!(cmd))?;
+        let mut args = args(cmd)?;
+        let mut options = RangeOptions::new();
+        while let Some(option) = args.next() {
+            if !options.parse(&keyword(&option), &mut args)? {
+                return Ok(Data::error("syntax error"));
+            }
+        }
+        options.validate()?;
+
+        let db = db::DB.read().await;
+
+        let series = match db.get(&key) {
+            Some(entry) => entry.value.as_time_series()?,
+            None => return Ok(Data::error("TSDB: the key does not exist")),
+        };
+        Ok(samples_response(&options.query(
+            series,
+            from,
+            to,
+            self.reverse,
+        )))
+    }
+}
@@ -1,4 +1,4 @@
-//! TTL, PTTL command
+//! TTL, PTTL, EXPIRETIME, PEXPIRETIME command
 //! 
 //! # command syntax
 //! TTL key
@@ -9,26 +9,37 @@
 //! 
 //! <https://redis.io/commands/pttl>
 //! 
+//! EXPIRETIME key
+//! 
+//! <https://redis.io/commands/expiretime>
+//! 
+//! PEXPIRETIME key
+//! 
+//! <https://redis.io/commands/pexpiretime>
+//! 
 use crate::db;
 use crate::protocol::resp::{Data, Parser};
 use async_trait::async_trait;
-use std::time::Instant;
 
 /// TTL commnad struct
-pub(crate) struct TTL {
+pub(crate) struct Ttl {
     time_unit: super::TimeUnit,
+    absolute: bool,
 }
 
 /// command register function
-pub(crate) fn command(time_unit: super::TimeUnit) -> (String, super::Cmd) {
-    match time_unit {
-        super::TimeUnit::Second =>(String::from("PTTL"), Box::new(TTL { time_unit })),
-        super::TimeUnit::Millisecond =>(String::from("TTL"), Box::new(TTL { time_unit }))   
-    }
+pub(crate) fn command(time_unit: super::TimeUnit, absolute: bool) -> (String, super::Cmd) {
+    let name = match (&time_unit, absolute) {
+        (super::TimeUnit::Second, false) => "TTL",
+        (super::TimeUnit::Millisecond, false) => "PTTL",
+        (super::TimeUnit::Second, true) => "EXPIRETIME",
+        (super::TimeUnit::Millisecond, true) => "PEXPIRETIME",
+    };
+    (String::from(name), Box::new(Ttl { time_unit, absolute }))
 }
 
 #[async_trait]
-impl super::Command for TTL {
+impl super::Command for Ttl {
     /// Get command body       
     async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
         let key = super::next_bytes!(cmd);
@@ -36,18 +47,24 @@ impl super::Command for TTL {
 
         match db::DB.read().await.get(&key) {
             Some(entry) => match entry.expiration {
-                Some(expiration) => match expiration.checked_duration_since(Instant::now()) {
-                    Some(ttl) => {
-                        match self.time_unit {
-                            super::TimeUnit::Second => {return Ok(Data::Integer(ttl.as_millis() as i64));}
-                            super::TimeUnit::Millisecond => {return Ok(Data::Integer(ttl.as_secs() as i64));} 
-                        }
-                    },
-                    None => return Ok(Data::Integer(-2)),
-                },
-                None => return Ok(Data::Integer(-1)),
+                Some(expiration) => {
+                    let now = db::unix_time_ms();
+                    if expiration < now {
+                        return Ok(Data::Integer(-2));
+                    }
+                    let time = if self.absolute {
+                        expiration
+                    } else {
+                        expiration - now
+                    };
+                    match self.time_unit {
+                        super::TimeUnit::Second => Ok(Data::Integer(((time + 500) / 1000) as i64)),
+                        super::TimeUnit::Millisecond => Ok(Data::Integer(time as i64)),
+                    }
+                }
+                None => Ok(Data::Integer(-1)),
             },
-            None => return Ok(Data::Integer(-2)),
+            None => Ok(Data::Integer(-2)),
         }
     }
 }
@@ -0,0 +1,131 @@
+//! VADD command
+//!
+//! # command syntax
+//! VADD key (FP32 vector | VALUES num value [value ...]) element [NOQUANT | Q8] [EF ef]
+//!   [SETATTR attributes] [M numlinks]
+//!
+//! <https://redis.io/commands/vadd>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// VAdd commnad empty struct
+pub(super) struct VAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("VADD"), Box::new(VAdd))
+}
+
+/// Parse the vector given as the little endian float32 blob after FP32,
+/// or as the number of the values and the values after VALUES.
+pub(super) fn vector(format: &str, cmd: &mut Parser) -> crate::Result<Vec<f32>> {
+    let vector: Vec<f32> = match format {
+        "FP32" => {
+            let blob = match cmd.next_bytes()? {
+                Some(blob) if !blob.is_empty() && blob.len().is_multiple_of(4) => blob,
+                Some(_) => return Err("invalid vector specification".into()),
+                None => return Err("wrong number of arguments for command".into()),
+            };
+            blob.chunks_exact(4)
+                .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+                .collect()
+        }
+        "VALUES" => {
+            let count = match cmd.next_i64()? {
+                Some(count) if count > 0 => count,
+                _ => return Err("invalid vector specification".into()),
+            };
+            let mut vector = Vec::new();
+            for _ in 0..count {
+                match cmd.next_bytes()? {
+                    Some(value) => match super::parse_float(&value) {
+                        Some(value) => vector.push(value as f32),
+                        None => return Err("invalid vector specification".into()),
+                    },
+                    None => return Err("wrong number of arguments for command".into()),
+                }
+            }
+            vector
+        }
+        _ => return Err("syntax error".into()),
+    };
+    if !vector.iter().all(|value| value.is_finite()) {
+        return Err("invalid vector specification".into());
+    }
+    Ok(vector)
+}
+
+#[async_trait]
+impl super::Command for VAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let vector = vector(&super::next_string!(cmd), cmd)?;
+        let element = super::next_bytes!(cmd);
+        let mut quantization = None;
+        let mut ef = db::VectorSet::DEFAULT_EF;
+        let mut attributes = None;
+        let mut m = db::VectorSet::DEFAULT_M;
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "NOQUANT" => quantization = Some(db::Quantization::NoQuant),
+                "Q8" => quantization = Some(db::Quantization::Q8),
+                "EF" => match cmd.next_i64()? {
+                    Some(value) if (1..=1_000_000).contains(&value) => ef = value as usize,
+                    _ => return Ok(Data::error("invalid EF")),
+                },
+                "SETATTR" => {
+                    let json = super::next_bytes!(cmd);
+                    // An empty string removes the attributes.
+                    attributes = match json.is_empty() {
+                        true => Some(None),
+                        false => match db::Json::parse(&json) {
+                            Ok(json) => Some(Some(json)),
+                            Err(_) => return Ok(Data::error("invalid JSON in SETATTR")),
+                        },
+                    };
+                }
+                "M" => match cmd.next_i64()? {
+                    Some(value) if (2..=4096).contains(&value) => m = value as usize,
+                    _ => return Ok(Data::error("invalid M")),
+                },
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        if let Some(entry) = db.get(&key) {
+            let set = entry.value.as_vector_set()?;
+            if set.dimension() != vector.len() {
+                return Ok(Data::error(&format!(
+                    "Vector dimension mismatch - got {} but set has {}",
+                    vector.len(),
+                    set.dimension()
+                )));
+            }
+            if quantization.is_some_and(|quantization| quantization != set.quantization()) {
+                return Ok(Data::error(
+                    "asked quantization mismatch with existing vector set",
+                ));
+            }
+        }
+        let set = db
+            .get_or_insert_with(key, || {
+                db::Value::VectorSet(db::VectorSet::new(
+                    vector.len(),
+                    quantization.unwrap_or(db::Quantization::NoQuant),
+                    m,
+                ))
+            })
+            .value
+            .as_vector_set_mut()?;
+        let added = set.add(element.clone(), &vector, ef);
+        if let Some(attributes) = attributes {
+            set.set_attributes(&element, attributes);
+        }
+        Ok(Data::Integer(added as i64))
+    }
+}
@@ -0,0 +1,34 @@
+//! VCARD command
+//!
+//! # command syntax
+//! VCARD key
+//!
+//! <https://redis.io/commands/vcard>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// VCard commnad empty struct
+pub(super) struct VCard;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("VCARD"), Box::new(VCard))
+}
+
+#[async_trait]
+impl super::Command for VCard {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        match db.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_vector_set()?.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! VDIM command
+//!
+//! # command syntax
+//! VDIM key
+//!
+//! <https://redis.io/commands/vdim>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// VDim commnad empty struct
+pub(super) struct VDim;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("VDIM"), Box::new(VDim))
+}
+
+#[async_trait]
+impl super::Command for VDim {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        match db.get(&key) {
+            Some(entry) => Ok(Data::Integer(
+                entry.value.as_vector_set()?.dimension() as i64
+            )),
+            None => Ok(Data::error("key does not exist")),
+        }
+    }
+}
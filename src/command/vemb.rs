@@ -0,0 +1,44 @@
+//! VEMB command
+//!
+//! # command syntax
+//! VEMB key element
+//!
+//! <https://redis.io/commands/vemb>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// VEmb commnad empty struct
+pub(super) struct VEmb;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("VEMB"), Box::new(VEmb))
+}
+
+#[async_trait]
+impl super::Command for VEmb {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let element = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let vector = match db.get(&key) {
+            Some(entry) => entry.value.as_vector_set()?.embedding(&element),
+            None => None,
+        };
+        match vector {
+            Some(vector) => Ok(Data::Array(
+                vector
+                    .into_iter()
+                    .map(|value| Data::Bulk(value.to_string().into_bytes()))
+                    .collect(),
+            )),
+            None => Ok(Data::NullBulk),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! VREM command
+//!
+//! # command syntax
+//! VREM key element
+//!
+//! <https://redis.io/commands/vrem>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// VRem commnad empty struct
+pub(super) struct VRem;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("VREM"), Box::new(VRem))
+}
+
+#[async_trait]
+impl super::Command for VRem {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let element = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let removed = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_vector_set_mut()?.remove(&element),
+            None => false,
+        };
+        db.remove_if_empty(&key);
+        Ok(Data::Integer(removed as i64))
+    }
+}
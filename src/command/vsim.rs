@@ -0,0 +1,104 @@
+//! VSIM command
+//!
+//! # command syntax
+//! VSIM key (ELE element | FP32 vector | VALUES num value [value ...]) [WITHSCORES]
+//!   [COUNT num] [EF ef] [FILTER expression] [FILTER-EF max-filtering-effort] [TRUTH]
+//!
+//! <https://redis.io/commands/vsim>
+//!
+use super::vadd::vector;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// VSim commnad empty struct
+pub(super) struct VSim;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("VSIM"), Box::new(VSim))
+}
+
+/// Query of the search.
+enum Query {
+    Element(Vec<u8>),
+    Vector(Vec<f32>),
+}
+
+#[async_trait]
+impl super::Command for VSim {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let query = match super::next_string!(cmd).as_str() {
+            "ELE" => Query::Element(super::next_bytes!(cmd)),
+            format => Query::Vector(vector(format, cmd)?),
+        };
+        let mut with_scores = false;
+        let mut count = 10;
+        let mut ef = db::VectorSet::DEFAULT_EF;
+        let mut filter = None;
+        let mut filter_ef = None;
+        let mut truth = false;
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "WITHSCORES" => with_scores = true,
+                "COUNT" => match cmd.next_i64()? {
+                    Some(value) if value > 0 => count = value as usize,
+                    _ => return Ok(Data::error("invalid COUNT")),
+                },
+                "EF" => match cmd.next_i64()? {
+                    Some(value) if (1..=1_000_000).contains(&value) => ef = value as usize,
+                    _ => return Ok(Data::error("invalid EF")),
+                },
+                "FILTER" => match db::Filter::parse(&super::next_bytes!(cmd)) {
+                    Some(expression) => filter = Some(expression),
+                    None => return Ok(Data::error("syntax error in FILTER expression")),
+                },
+                "FILTER-EF" => match cmd.next_i64()? {
+                    Some(value) if value > 0 => filter_ef = Some(value as usize),
+                    _ => return Ok(Data::error("invalid FILTER-EF")),
+                },
+                "TRUTH" => truth = true,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        let db = db::DB.read().await;
+
+        let set = match db.get(&key) {
+            Some(entry) => entry.value.as_vector_set()?,
+            None => return Ok(Data::Array(vec![])),
+        };
+        let query = match query {
+            Query::Element(element) => match set.embedding(&element) {
+                Some(vector) => vector,
+                None => return Ok(Data::error("element not found in set")),
+            },
+            Query::Vector(vector) if vector.len() != set.dimension() => {
+                return Ok(Data::error(&format!(
+                    "Vector dimension mismatch - got {} but set has {}",
+                    vector.len(),
+                    set.dimension()
+                )))
+            }
+            Query::Vector(vector) => vector,
+        };
+        let results = set.search(
+            &query,
+            count,
+            ef,
+            filter.as_ref(),
+            filter_ef.unwrap_or(count.saturating_mul(100)),
+            truth,
+        );
+        let mut response = Vec::new();
+        for (element, score) in results {
+            response.push(Data::Bulk(element.to_vec()));
+            if with_scores {
+                response.push(Data::Bulk(score.to_string().into_bytes()));
+            }
+        }
+        Ok(Data::Array(response))
+    }
+}
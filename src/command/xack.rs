@@ -0,0 +1,52 @@
+//! XACK command
+//!
+//! # command syntax
+//! XACK key group id [id ...]
+//!
+//! <https://redis.io/commands/xack>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XAck commnad empty struct
+pub(super) struct XAck;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XACK"), Box::new(XAck))
+}
+
+#[async_trait]
+impl super::Command for XAck {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let group = super::next_bytes!(cmd);
+        let mut ids = vec![super::next_bytes!(cmd)];
+        while let Some(id) = cmd.next_bytes()? {
+            ids.push(id);
+        }
+        let mut parsed = Vec::with_capacity(ids.len());
+        for id in ids {
+            match db::StreamId::parse(&id, 0) {
+                Some(id) => parsed.push(id),
+                None => return Ok(Data::error(super::INVALID_STREAM_ID)),
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        let stream = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_stream_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        let group = match stream.groups.get_mut(&group) {
+            Some(group) => group,
+            None => return Ok(Data::Integer(0)),
+        };
+        let acknowledged = parsed.into_iter().filter(|id| group.ack(*id)).count();
+
+        Ok(Data::Integer(acknowledged as i64))
+    }
+}
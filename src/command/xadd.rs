@@ -0,0 +1,111 @@
+//! XADD command
+//!
+//! # command syntax
+//! XADD key \[NOMKSTREAM\] [MAXLEN|MINID [=|~] threshold [LIMIT count]] *|id field value [field value ...]
+//!
+//! <https://redis.io/commands/xadd>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XAdd commnad empty struct
+pub(super) struct XAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XADD"), Box::new(XAdd))
+}
+
+#[async_trait]
+impl super::Command for XAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut args = Vec::new();
+        while let Some(arg) = cmd.next_bytes()? {
+            args.push(arg);
+        }
+
+        let mut no_mkstream = false;
+        let mut trim = None;
+        let mut index = 0;
+        while index < args.len() {
+            match &args[index].to_ascii_uppercase()[..] {
+                b"NOMKSTREAM" => {
+                    no_mkstream = true;
+                    index += 1;
+                }
+                b"MAXLEN" | b"MINID" => match super::xtrim::parse_trim(&args, &mut index) {
+                    Ok(options) => trim = Some(options),
+                    Err(message) => return Ok(Data::error(message)),
+                },
+                _ => break,
+            }
+        }
+        let args = &args[index..];
+        if args.len() < 3 || args.len() % 2 == 0 {
+            return Ok(Data::error("wrong number of arguments for command"));
+        }
+        // "*" or "ms-*" generates the ID.
+        let (explicit, ms) = if args[0] == b"*" {
+            (None, None)
+        } else if let Some(ms) = args[0].strip_suffix(b"-*") {
+            match db::StreamId::parse(ms, 0) {
+                Some(id) if !ms.contains(&b'-') => (None, Some(id.ms)),
+                _ => return Ok(Data::error(super::INVALID_STREAM_ID)),
+            }
+        } else {
+            match db::StreamId::parse(&args[0], 0) {
+                Some(id) if id == db::StreamId::MIN => {
+                    return Ok(Data::error(
+                        "The ID specified in XADD must be greater than 0-0",
+                    ))
+                }
+                Some(id) => (Some(id), None),
+                None => return Ok(Data::error(super::INVALID_STREAM_ID)),
+            }
+        };
+        let fields: db::Fields = args[1..]
+            .chunks(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect();
+
+        let mut db = db::DB.write().await;
+
+        if no_mkstream && db.get(&key).is_none() {
+            return Ok(Data::NullBulk);
+        }
+        let stream = db
+            .get_or_insert_with(key.clone(), || db::Value::Stream(db::Stream::new()))
+            .value
+            .as_stream_mut()?;
+        let id = match explicit {
+            Some(id) if id > stream.last_id() => Some(id),
+            Some(_) => None,
+            None => {
+                if stream.last_id() == db::StreamId::MAX {
+                    return Ok(Data::error(
+                        "The stream has exhausted the last possible ID, unable to add more items",
+                    ));
+                }
+                stream.next_id(ms, db::unix_time_ms())
+            }
+        };
+        let id =
+            match id {
+                Some(id) => id,
+                None => return Ok(Data::error(
+                    "The ID specified in XADD is equal or smaller than the target stream top item",
+                )),
+            };
+        stream.add(id, fields);
+        if let Some(options) = trim {
+            stream.trim(&options.trim, options.approx, options.limit);
+        }
+        db.signal_ready(&key);
+        db.serve_blocked();
+
+        Ok(Data::Bulk(id.to_bytes()))
+    }
+}
@@ -0,0 +1,119 @@
+//! XAUTOCLAIM command
+//!
+//! # command syntax
+//! XAUTOCLAIM key group consumer min-idle-time start [COUNT count] \[JUSTID\]
+//!
+//! <https://redis.io/commands/xautoclaim>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XAutoClaim commnad empty struct
+pub(super) struct XAutoClaim;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XAUTOCLAIM"), Box::new(XAutoClaim))
+}
+
+/// Number of the pending entries scanned for each entry to claim.
+const ATTEMPTS_FACTOR: usize = 10;
+
+#[async_trait]
+impl super::Command for XAutoClaim {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let group = super::next_bytes!(cmd);
+        let consumer = super::next_bytes!(cmd);
+        let min_idle = match super::parse_integer(&super::next_bytes!(cmd)) {
+            Some(min_idle) => min_idle.max(0) as u64,
+            None => return Ok(Data::error("Invalid min-idle-time argument for XAUTOCLAIM")),
+        };
+        let start = match super::xrange::range_id(&super::next_bytes!(cmd), false) {
+            Ok(start) => start,
+            Err(message) => return Ok(Data::error(message)),
+        };
+        let mut count = 100;
+        let mut just_id = false;
+        while let Some(option) = cmd.next_string()? {
+            match &option.to_uppercase()[..] {
+                "COUNT" => {
+                    count = match super::next_i64!(cmd) {
+                        count if 0 < count && count <= i64::MAX / ATTEMPTS_FACTOR as i64 => {
+                            count as usize
+                        }
+                        _ => return Ok(Data::error("COUNT must be > 0")),
+                    }
+                }
+                "JUSTID" => just_id = true,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        let stream = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_stream_mut()?,
+            None => return Ok(super::no_group(&key, &group)),
+        };
+        let now = db::unix_time_ms();
+        let mut attempts = count * ATTEMPTS_FACTOR;
+        let mut candidates = Vec::new();
+        let mut next = db::StreamId::MIN;
+        match stream.groups.get(&group) {
+            Some(stream_group) => {
+                for (id, entry) in stream_group.pending.range(start..) {
+                    if attempts == 0 || candidates.len() == count {
+                        next = *id;
+                        break;
+                    }
+                    attempts -= 1;
+                    if now.saturating_sub(entry.delivery_time) >= min_idle {
+                        candidates.push((*id, entry.delivery_count));
+                    }
+                }
+            }
+            None => return Ok(super::no_group(&key, &group)),
+        }
+        let fields: Vec<Option<db::Fields>> =
+            candidates.iter().map(|(id, _)| stream.entry(*id)).collect();
+        let stream_group = stream.groups.get_mut(&group).unwrap();
+        stream_group.consumer(&consumer, now);
+
+        let mut claimed = Vec::new();
+        let mut deleted = Vec::new();
+        for ((id, delivery_count), fields) in candidates.into_iter().zip(fields) {
+            let fields = match fields {
+                Some(fields) => fields,
+                None => {
+                    // The entry has been deleted from the stream.
+                    stream_group.ack(id);
+                    deleted.push(Data::Bulk(id.to_bytes()));
+                    continue;
+                }
+            };
+            let delivery_count = if just_id {
+                delivery_count
+            } else {
+                delivery_count + 1
+            };
+            stream_group.assign(id, &consumer, now, delivery_count);
+            claimed.push(if just_id {
+                Data::Bulk(id.to_bytes())
+            } else {
+                super::stream_entry(id, fields)
+            });
+        }
+        if !claimed.is_empty() {
+            stream_group.consumer(&consumer, now).active_time = Some(now);
+        }
+
+        Ok(Data::Array(vec![
+            Data::Bulk(next.to_bytes()),
+            Data::Array(claimed),
+            Data::Array(deleted),
+        ]))
+    }
+}
@@ -0,0 +1,129 @@
+//! XCLAIM command
+//!
+//! # command syntax
+//! XCLAIM key group consumer min-idle-time id [id ...] [IDLE ms] [TIME unix-time-milliseconds] [RETRYCOUNT count] \[FORCE\] \[JUSTID\] [LASTID lastid]
+//!
+//! <https://redis.io/commands/xclaim>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XClaim commnad empty struct
+pub(super) struct XClaim;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XCLAIM"), Box::new(XClaim))
+}
+
+#[async_trait]
+impl super::Command for XClaim {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let group = super::next_bytes!(cmd);
+        let consumer = super::next_bytes!(cmd);
+        let min_idle = match super::parse_integer(&super::next_bytes!(cmd)) {
+            Some(min_idle) => min_idle.max(0) as u64,
+            None => return Ok(Data::error("Invalid min-idle-time argument for XCLAIM")),
+        };
+        // IDs continue until an option appears.
+        let mut ids = Vec::new();
+        let mut option = None;
+        while let Some(arg) = cmd.next_bytes()? {
+            match db::StreamId::parse(&arg, 0) {
+                Some(id) => ids.push(id),
+                None => {
+                    option = Some(arg);
+                    break;
+                }
+            }
+        }
+        if ids.is_empty() {
+            return Ok(Data::error(super::INVALID_STREAM_ID));
+        }
+        let now = db::unix_time_ms();
+        let mut delivery_time = now;
+        let mut retry_count = None;
+        let mut force = false;
+        let mut just_id = false;
+        let mut last_id = None;
+        while let Some(arg) = option {
+            match &arg.to_ascii_uppercase()[..] {
+                b"IDLE" => delivery_time = now.saturating_sub(super::next_i64!(cmd).max(0) as u64),
+                b"TIME" => delivery_time = super::next_i64!(cmd).max(0) as u64,
+                b"RETRYCOUNT" => retry_count = Some(super::next_i64!(cmd).max(0) as u64),
+                b"FORCE" => force = true,
+                b"JUSTID" => just_id = true,
+                b"LASTID" => match db::StreamId::parse(&super::next_bytes!(cmd), 0) {
+                    Some(id) => last_id = Some(id),
+                    None => return Ok(Data::error(super::INVALID_STREAM_ID)),
+                },
+                _ => {
+                    return Ok(Data::error(&format!(
+                        "Unrecognized XCLAIM option '{}'",
+                        String::from_utf8_lossy(&arg)
+                    )))
+                }
+            }
+            option = cmd.next_bytes()?;
+        }
+        // Delivery time in the future is the current time.
+        let delivery_time = delivery_time.min(now);
+
+        let mut db = db::DB.write().await;
+
+        let stream = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_stream_mut()?,
+            None => return Ok(super::no_group(&key, &group)),
+        };
+        let fields: Vec<Option<db::Fields>> = ids.iter().map(|id| stream.entry(*id)).collect();
+        let stream_group = match stream.groups.get_mut(&group) {
+            Some(group) => group,
+            None => return Ok(super::no_group(&key, &group)),
+        };
+        if let Some(last_id) = last_id {
+            stream_group.last_id = stream_group.last_id.max(last_id);
+        }
+        stream_group.consumer(&consumer, now);
+
+        let mut claimed = Vec::new();
+        for (id, fields) in ids.into_iter().zip(fields) {
+            let delivery_count = match stream_group.pending.get(&id) {
+                Some(entry) => {
+                    if now.saturating_sub(entry.delivery_time) < min_idle {
+                        continue;
+                    }
+                    entry.delivery_count
+                }
+                None if force && fields.is_some() => 0,
+                None => continue,
+            };
+            let fields = match fields {
+                Some(fields) => fields,
+                None => {
+                    // The entry has been deleted from the stream.
+                    stream_group.ack(id);
+                    continue;
+                }
+            };
+            let delivery_count = match retry_count {
+                Some(retry_count) => retry_count,
+                None if just_id => delivery_count,
+                None => delivery_count + 1,
+            };
+            stream_group.assign(id, &consumer, delivery_time, delivery_count);
+            claimed.push(if just_id {
+                Data::Bulk(id.to_bytes())
+            } else {
+                super::stream_entry(id, fields)
+            });
+        }
+        if !claimed.is_empty() {
+            stream_group.consumer(&consumer, now).active_time = Some(now);
+        }
+
+        Ok(Data::Array(claimed))
+    }
+}
@@ -0,0 +1,47 @@
+//! XDEL command
+//!
+//! # command syntax
+//! XDEL key id [id ...]
+//!
+//! <https://redis.io/commands/xdel>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XDel commnad empty struct
+pub(super) struct XDel;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XDEL"), Box::new(XDel))
+}
+
+#[async_trait]
+impl super::Command for XDel {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut ids = vec![super::next_bytes!(cmd)];
+        while let Some(id) = cmd.next_bytes()? {
+            ids.push(id);
+        }
+        let mut parsed = Vec::with_capacity(ids.len());
+        for id in ids {
+            match db::StreamId::parse(&id, 0) {
+                Some(id) => parsed.push(id),
+                None => return Ok(Data::error(super::INVALID_STREAM_ID)),
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        let stream = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_stream_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        let deleted = parsed.into_iter().filter(|id| stream.delete(*id)).count();
+
+        Ok(Data::Integer(deleted as i64))
+    }
+}
@@ -0,0 +1,159 @@
+//! XGROUP command
+//!
+//! # command syntax
+//! XGROUP CREATE key group id|$ \[MKSTREAM\] [ENTRIESREAD entries-read]
+//!
+//! <https://redis.io/commands/xgroup-create>
+//!
+//! XGROUP SETID key group id|$ [ENTRIESREAD entries-read]
+//!
+//! <https://redis.io/commands/xgroup-setid>
+//!
+//! XGROUP DESTROY key group
+//!
+//! <https://redis.io/commands/xgroup-destroy>
+//!
+//! XGROUP CREATECONSUMER key group consumer
+//!
+//! <https://redis.io/commands/xgroup-createconsumer>
+//!
+//! XGROUP DELCONSUMER key group consumer
+//!
+//! <https://redis.io/commands/xgroup-delconsumer>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XGroup commnad empty struct
+pub(super) struct XGroup;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XGROUP"), Box::new(XGroup))
+}
+
+#[async_trait]
+impl super::Command for XGroup {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let subcommand = super::next_string!(cmd).to_uppercase();
+        let key = super::next_bytes!(cmd);
+        let group = super::next_bytes!(cmd);
+
+        match &subcommand[..] {
+            "CREATE" | "SETID" => {
+                let id = super::next_bytes!(cmd);
+                let mut mkstream = false;
+                let mut entries_read = None;
+                while let Some(option) = cmd.next_string()? {
+                    match &option.to_uppercase()[..] {
+                        "MKSTREAM" if subcommand == "CREATE" => mkstream = true,
+                        "ENTRIESREAD" => match super::next_i64!(cmd) {
+                            -1 => entries_read = None,
+                            count if count >= 0 => entries_read = Some(count as u64),
+                            _ => {
+                                return Ok(Data::error(
+                                    "value for ENTRIESREAD must be positive or -1",
+                                ))
+                            }
+                        },
+                        _ => return Ok(Data::error("syntax error")),
+                    }
+                }
+
+                let mut db = db::DB.write().await;
+
+                if mkstream && db.get(&key).is_none() {
+                    db.insert(key.clone(), db::Value::Stream(db::Stream::new()));
+                }
+                let stream = match db.get_mut(&key) {
+                    Some(entry) => entry.value.as_stream_mut()?,
+                    None => return Ok(Data::error(NO_KEY)),
+                };
+                let id = if id == b"$" {
+                    stream.last_id()
+                } else {
+                    match db::StreamId::parse(&id, 0) {
+                        Some(id) => id,
+                        None => return Ok(Data::error(super::INVALID_STREAM_ID)),
+                    }
+                };
+                if subcommand == "CREATE" {
+                    if stream.groups.contains_key(&group) {
+                        return Ok(Data::Error(
+                            b"BUSYGROUP Consumer Group name already exists".to_vec(),
+                        ));
+                    }
+                    stream
+                        .groups
+                        .insert(group, db::Group::new(id, entries_read));
+                } else {
+                    match stream.groups.get_mut(&group) {
+                        Some(group) => {
+                            group.last_id = id;
+                            group.entries_read = entries_read;
+                        }
+                        None => return Ok(no_such_group(&key, &group)),
+                    }
+                }
+                Ok(Data::ok())
+            }
+            "DESTROY" => {
+                super::check_end_of_param!(cmd);
+
+                let mut db = db::DB.write().await;
+
+                match db.get_mut(&key) {
+                    Some(entry) => Ok(Data::Integer(
+                        entry.value.as_stream_mut()?.groups.remove(&group).is_some() as i64,
+                    )),
+                    None => Ok(Data::error(NO_KEY)),
+                }
+            }
+            "CREATECONSUMER" | "DELCONSUMER" => {
+                let consumer = super::next_bytes!(cmd);
+                super::check_end_of_param!(cmd);
+
+                let mut db = db::DB.write().await;
+
+                let stream = match db.get_mut(&key) {
+                    Some(entry) => entry.value.as_stream_mut()?,
+                    None => return Ok(Data::error(NO_KEY)),
+                };
+                let stream_group = match stream.groups.get_mut(&group) {
+                    Some(group) => group,
+                    None => return Ok(no_such_group(&key, &group)),
+                };
+                if subcommand == "CREATECONSUMER" {
+                    Ok(Data::Integer(
+                        stream_group.create_consumer(&consumer, db::unix_time_ms()) as i64,
+                    ))
+                } else {
+                    Ok(Data::Integer(
+                        stream_group.delete_consumer(&consumer).unwrap_or(0) as i64,
+                    ))
+                }
+            }
+            _ => Ok(Data::error(&format!(
+                "unknown subcommand '{}'. Try XGROUP HELP.",
+                subcommand
+            ))),
+        }
+    }
+}
+
+/// Error message of the missing key.
+const NO_KEY: &str = "The XGROUP subcommand requires the key to exist. Note that for CREATE you may want to use the MKSTREAM option to create an empty stream automatically.";
+
+/// Error response of the missing consumer group.
+pub(super) fn no_such_group(key: &[u8], group: &[u8]) -> Data {
+    Data::Error(
+        format!(
+            "NOGROUP No such consumer group '{}' for key name '{}'",
+            String::from_utf8_lossy(group),
+            String::from_utf8_lossy(key)
+        )
+        .into_bytes(),
+    )
+}
@@ -0,0 +1,268 @@
+//! XINFO command
+//!
+//! # command syntax
+//! XINFO STREAM key [FULL [COUNT count]]
+//!
+//! <https://redis.io/commands/xinfo-stream>
+//!
+//! XINFO GROUPS key
+//!
+//! <https://redis.io/commands/xinfo-groups>
+//!
+//! XINFO CONSUMERS key group
+//!
+//! <https://redis.io/commands/xinfo-consumers>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XInfo commnad empty struct
+pub(super) struct XInfo;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XINFO"), Box::new(XInfo))
+}
+
+#[async_trait]
+impl super::Command for XInfo {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let subcommand = super::next_string!(cmd).to_uppercase();
+        match &subcommand[..] {
+            "STREAM" => stream(cmd).await,
+            "GROUPS" => groups(cmd).await,
+            "CONSUMERS" => consumers(cmd).await,
+            _ => Ok(Data::error(&format!(
+                "unknown subcommand '{}'. Try XINFO HELP.",
+                subcommand
+            ))),
+        }
+    }
+}
+
+/// XINFO STREAM
+async fn stream(cmd: &mut Parser) -> crate::Result<Data> {
+    let key = super::next_bytes!(cmd);
+    let full = match cmd.next_string()? {
+        Some(option) if option.to_uppercase() == "FULL" => true,
+        Some(_) => return Ok(Data::error("syntax error")),
+        None => false,
+    };
+    // Zero means all entries.
+    let mut count = 10;
+    if full {
+        match cmd.next_string()? {
+            Some(option) if option.to_uppercase() == "COUNT" => {
+                count = super::next_i64!(cmd).max(0) as usize;
+            }
+            Some(_) => return Ok(Data::error("syntax error")),
+            None => {}
+        }
+    }
+    super::check_end_of_param!(cmd);
+
+    let db = db::DB.read().await;
+
+    let stream = match db.get(&key) {
+        Some(entry) => entry.value.as_stream()?,
+        None => return Ok(Data::error("no such key")),
+    };
+    let entry = |entry: Option<(db::StreamId, db::Fields)>| match entry {
+        Some((id, fields)) => super::stream_entry(id, fields),
+        None => Data::NullBulk,
+    };
+    let mut info = vec![
+        Data::Bulk(b"length".to_vec()),
+        Data::Integer(stream.len() as i64),
+        Data::Bulk(b"radix-tree-keys".to_vec()),
+        Data::Integer(stream.node_count() as i64),
+        Data::Bulk(b"radix-tree-nodes".to_vec()),
+        Data::Integer(stream.node_count() as i64),
+        Data::Bulk(b"last-generated-id".to_vec()),
+        Data::Bulk(stream.last_id().to_bytes()),
+        Data::Bulk(b"max-deleted-entry-id".to_vec()),
+        Data::Bulk(stream.max_deleted_id().to_bytes()),
+        Data::Bulk(b"entries-added".to_vec()),
+        Data::Integer(stream.entries_added() as i64),
+        Data::Bulk(b"recorded-first-entry-id".to_vec()),
+        Data::Bulk(stream.first_id().to_bytes()),
+    ];
+    if full {
+        let count = if count == 0 { None } else { Some(count) };
+        info.push(Data::Bulk(b"entries".to_vec()));
+        info.push(super::stream_entries(stream.range(
+            db::StreamId::MIN,
+            db::StreamId::MAX,
+            count,
+            false,
+        )));
+        info.push(Data::Bulk(b"groups".to_vec()));
+        info.push(Data::Array(
+            stream
+                .groups
+                .iter()
+                .map(|(name, group)| full_group(stream, name, group, count))
+                .collect(),
+        ));
+    } else {
+        info.push(Data::Bulk(b"groups".to_vec()));
+        info.push(Data::Integer(stream.groups.len() as i64));
+        info.push(Data::Bulk(b"first-entry".to_vec()));
+        info.push(entry(stream.first_entry()));
+        info.push(Data::Bulk(b"last-entry".to_vec()));
+        info.push(entry(stream.last_entry()));
+    }
+
+    Ok(Data::Array(info))
+}
+
+/// Group information of XINFO STREAM FULL.
+fn full_group(stream: &db::Stream, name: &[u8], group: &db::Group, count: Option<usize>) -> Data {
+    let count = count.unwrap_or(usize::MAX);
+    let pending = group
+        .pending
+        .iter()
+        .take(count)
+        .map(|(id, entry)| {
+            Data::Array(vec![
+                Data::Bulk(id.to_bytes()),
+                Data::Bulk(entry.consumer.clone()),
+                Data::Integer(entry.delivery_time as i64),
+                Data::Integer(entry.delivery_count as i64),
+            ])
+        })
+        .collect();
+    let consumers = group
+        .consumers
+        .iter()
+        .map(|(name, consumer)| {
+            let pending = consumer
+                .pending
+                .iter()
+                .take(count)
+                .filter_map(|id| {
+                    let entry = group.pending.get(id)?;
+                    Some(Data::Array(vec![
+                        Data::Bulk(id.to_bytes()),
+                        Data::Integer(entry.delivery_time as i64),
+                        Data::Integer(entry.delivery_count as i64),
+                    ]))
+                })
+                .collect();
+            Data::Array(vec![
+                Data::Bulk(b"name".to_vec()),
+                Data::Bulk(name.clone()),
+                Data::Bulk(b"seen-time".to_vec()),
+                Data::Integer(consumer.seen_time as i64),
+                Data::Bulk(b"active-time".to_vec()),
+                Data::Integer(consumer.active_time.map_or(-1, |time| time as i64)),
+                Data::Bulk(b"pel-count".to_vec()),
+                Data::Integer(consumer.pending.len() as i64),
+                Data::Bulk(b"pending".to_vec()),
+                Data::Array(pending),
+            ])
+        })
+        .collect();
+    Data::Array(vec![
+        Data::Bulk(b"name".to_vec()),
+        Data::Bulk(name.to_vec()),
+        Data::Bulk(b"last-delivered-id".to_vec()),
+        Data::Bulk(group.last_id.to_bytes()),
+        Data::Bulk(b"entries-read".to_vec()),
+        optional(group.entries_read),
+        Data::Bulk(b"lag".to_vec()),
+        optional(stream.lag(group)),
+        Data::Bulk(b"pel-count".to_vec()),
+        Data::Integer(group.pending.len() as i64),
+        Data::Bulk(b"pending".to_vec()),
+        Data::Array(pending),
+        Data::Bulk(b"consumers".to_vec()),
+        Data::Array(consumers),
+    ])
+}
+
+/// Integer or null.
+fn optional(value: Option<u64>) -> Data {
+    match value {
+        Some(value) => Data::Integer(value as i64),
+        None => Data::NullBulk,
+    }
+}
+
+/// XINFO GROUPS
+async fn groups(cmd: &mut Parser) -> crate::Result<Data> {
+    let key = super::next_bytes!(cmd);
+    super::check_end_of_param!(cmd);
+
+    let db = db::DB.read().await;
+
+    let stream = match db.get(&key) {
+        Some(entry) => entry.value.as_stream()?,
+        None => return Ok(Data::error("no such key")),
+    };
+    Ok(Data::Array(
+        stream
+            .groups
+            .iter()
+            .map(|(name, group)| {
+                Data::Array(vec![
+                    Data::Bulk(b"name".to_vec()),
+                    Data::Bulk(name.clone()),
+                    Data::Bulk(b"consumers".to_vec()),
+                    Data::Integer(group.consumers.len() as i64),
+                    Data::Bulk(b"pending".to_vec()),
+                    Data::Integer(group.pending.len() as i64),
+                    Data::Bulk(b"last-delivered-id".to_vec()),
+                    Data::Bulk(group.last_id.to_bytes()),
+                    Data::Bulk(b"entries-read".to_vec()),
+                    optional(group.entries_read),
+                    Data::Bulk(b"lag".to_vec()),
+                    optional(stream.lag(group)),
+                ])
+            })
+            .collect(),
+    ))
+}
+
+/// XINFO CONSUMERS
+async fn consumers(cmd: &mut Parser) -> crate::Result<Data> {
+    let key = super::next_bytes!(cmd);
+    let group = super::next_bytes!(cmd);
+    super::check_end_of_param!(cmd);
+
+    let db = db::DB.read().await;
+
+    let stream = match db.get(&key) {
+        Some(entry) => entry.value.as_stream()?,
+        None => return Ok(Data::error("no such key")),
+    };
+    let stream_group = match stream.groups.get(&group) {
+        Some(group) => group,
+        None => return Ok(super::xgroup::no_such_group(&key, &group)),
+    };
+    let now = db::unix_time_ms();
+    Ok(Data::Array(
+        stream_group
+            .consumers
+            .iter()
+            .map(|(name, consumer)| {
+                Data::Array(vec![
+                    Data::Bulk(b"name".to_vec()),
+                    Data::Bulk(name.clone()),
+                    Data::Bulk(b"pending".to_vec()),
+                    Data::Integer(consumer.pending.len() as i64),
+                    Data::Bulk(b"idle".to_vec()),
+                    Data::Integer(now.saturating_sub(consumer.seen_time) as i64),
+                    Data::Bulk(b"inactive".to_vec()),
+                    Data::Integer(
+                        consumer
+                            .active_time
+                            .map_or(-1, |time| now.saturating_sub(time) as i64),
+                    ),
+                ])
+            })
+            .collect(),
+    ))
+}
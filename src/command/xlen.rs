@@ -0,0 +1,32 @@
+//! XLEN command
+//!
+//! # command syntax
+//! XLEN key
+//!
+//! <https://redis.io/commands/xlen>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XLen commnad empty struct
+pub(super) struct XLen;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XLEN"), Box::new(XLen))
+}
+
+#[async_trait]
+impl super::Command for XLen {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_stream()?.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! XPENDING command
+//!
+//! # command syntax
+//! XPENDING key group [[IDLE min-idle-time] start end count \[consumer\]]
+//!
+//! <https://redis.io/commands/xpending>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+/// XPending commnad empty struct
+pub(super) struct XPending;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XPENDING"), Box::new(XPending))
+}
+
+#[async_trait]
+impl super::Command for XPending {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let group = super::next_bytes!(cmd);
+        // The extended form.
+        let mut extended = None;
+        if let Some(mut arg) = cmd.next_bytes()? {
+            let mut min_idle = 0;
+            if arg.eq_ignore_ascii_case(b"IDLE") {
+                min_idle = super::next_i64!(cmd).max(0) as u64;
+                arg = super::next_bytes!(cmd);
+            }
+            let start = match super::xrange::range_id(&arg, false) {
+                Ok(start) => start,
+                Err(message) => return Ok(Data::error(message)),
+            };
+            let end = match super::xrange::range_id(&super::next_bytes!(cmd), true) {
+                Ok(end) => end,
+                Err(message) => return Ok(Data::error(message)),
+            };
+            let count = super::next_i64!(cmd).max(0) as usize;
+            let consumer = cmd.next_bytes()?;
+            super::check_end_of_param!(cmd);
+            extended = Some((min_idle, start, end, count, consumer));
+        }
+
+        let db = db::DB.read().await;
+
+        let stream_group = match db.get(&key) {
+            Some(entry) => entry.value.as_stream()?.groups.get(&group),
+            None => None,
+        };
+        let stream_group = match stream_group {
+            Some(stream_group) => stream_group,
+            None => return Ok(super::no_group(&key, &group)),
+        };
+
+        let (min_idle, start, end, count, consumer) = match extended {
+            Some(extended) => extended,
+            None => return Ok(summary(stream_group)),
+        };
+        if start > end {
+            return Ok(Data::Array(Vec::new()));
+        }
+        let now = db::unix_time_ms();
+        let entries = stream_group
+            .pending
+            .range(start..=end)
+            .filter(|(_, entry)| consumer.as_ref().is_none_or(|c| entry.consumer == *c))
+            .filter(|(_, entry)| now.saturating_sub(entry.delivery_time) >= min_idle)
+            .take(count)
+            .map(|(id, entry)| {
+                Data::Array(vec![
+                    Data::Bulk(id.to_bytes()),
+                    Data::Bulk(entry.consumer.clone()),
+                    Data::Integer(now.saturating_sub(entry.delivery_time) as i64),
+                    Data::Integer(entry.delivery_count as i64),
+                ])
+            })
+            .collect();
+
+        Ok(Data::Array(entries))
+    }
+}
+
+/// The summary form.
+/// Number of the pending entries, the smallest and the greatest IDs,
+/// and the number of the pending entries of each consumer.
+fn summary(group: &db::Group) -> Data {
+    let (first, last) = match (
+        group.pending.keys().next(),
+        group.pending.keys().next_back(),
+    ) {
+        (Some(first), Some(last)) => (first, last),
+        _ => {
+            return Data::Array(vec![
+                Data::Integer(0),
+                Data::NullBulk,
+                Data::NullBulk,
+                Data::NullArray,
+            ])
+        }
+    };
+    let mut consumers: BTreeMap<&[u8], usize> = BTreeMap::new();
+    for entry in group.pending.values() {
+        *consumers.entry(&entry.consumer).or_default() += 1;
+    }
+    Data::Array(vec![
+        Data::Integer(group.pending.len() as i64),
+        Data::Bulk(first.to_bytes()),
+        Data::Bulk(last.to_bytes()),
+        Data::Array(
+            consumers
+                .into_iter()
+                .map(|(consumer, count)| {
+                    Data::Array(vec![
+                        Data::Bulk(consumer.to_vec()),
+                        Data::Bulk(count.to_string().into_bytes()),
+                    ])
+                })
+                .collect(),
+        ),
+    ])
+}
@@ -0,0 +1,92 @@
+//! XRANGE, XREVRANGE command
+//!
+//! # command syntax
+//! XRANGE key start end [COUNT count]
+//!
+//! <https://redis.io/commands/xrange>
+//!
+//! XREVRANGE key end start [COUNT count]
+//!
+//! <https://redis.io/commands/xrevrange>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XRange commnad struct
+pub(super) struct XRange {
+    /// From the end to the start.
+    reverse: bool,
+}
+
+/// command register function
+pub(super) fn command(reverse: bool) -> (String, super::Cmd) {
+    match reverse {
+        false => (String::from("XRANGE"), Box::new(XRange { reverse })),
+        true => (String::from("XREVRANGE"), Box::new(XRange { reverse })),
+    }
+}
+
+#[async_trait]
+impl super::Command for XRange {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut start = super::next_bytes!(cmd);
+        let mut end = super::next_bytes!(cmd);
+        if self.reverse {
+            std::mem::swap(&mut start, &mut end);
+        }
+        let count = match cmd.next_string()? {
+            Some(option) if option.to_uppercase() == "COUNT" => {
+                let count = super::next_i64!(cmd);
+                super::check_end_of_param!(cmd);
+                Some(count.max(0) as usize)
+            }
+            Some(_) => return Ok(Data::error("syntax error")),
+            None => None,
+        };
+        let start = match range_id(&start, false) {
+            Ok(start) => start,
+            Err(message) => return Ok(Data::error(message)),
+        };
+        let end = match range_id(&end, true) {
+            Ok(end) => end,
+            Err(message) => return Ok(Data::error(message)),
+        };
+
+        let db = db::DB.read().await;
+
+        match db.get(&key) {
+            Some(entry) => Ok(super::stream_entries(entry.value.as_stream()?.range(
+                start,
+                end,
+                count,
+                self.reverse,
+            ))),
+            None => Ok(Data::Array(Vec::new())),
+        }
+    }
+}
+
+/// Parse the ID of the range.
+/// "-" and "+" are the smallest and the largest IDs, and "(" makes the ID exclusive.
+/// The omitted sequence number is the smallest for the start and the largest for the end.
+pub(super) fn range_id(bytes: &[u8], end: bool) -> Result<db::StreamId, &'static str> {
+    match bytes {
+        b"-" => return Ok(db::StreamId::MIN),
+        b"+" => return Ok(db::StreamId::MAX),
+        _ => {}
+    }
+    let (exclusive, bytes) = match bytes.strip_prefix(b"(") {
+        Some(bytes) => (true, bytes),
+        None => (false, bytes),
+    };
+    let id = db::StreamId::parse(bytes, if end { u64::MAX } else { 0 })
+        .ok_or(super::INVALID_STREAM_ID)?;
+    match (exclusive, end) {
+        (false, _) => Ok(id),
+        (true, false) => id.next().ok_or("invalid start ID for the interval"),
+        (true, true) => id.prev().ok_or("invalid end ID for the interval"),
+    }
+}
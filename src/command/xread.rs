@@ -0,0 +1,161 @@
+//! XREAD command
+//!
+//! # command syntax
+//! XREAD [COUNT count] [BLOCK milliseconds] STREAMS key [key ...] id [id ...]
+//!
+//! <https://redis.io/commands/xread>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// XRead commnad empty struct
+pub(super) struct XRead;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XREAD"), Box::new(XRead))
+}
+
+#[async_trait]
+impl super::Command for XRead {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let mut count = None;
+        let mut block = None;
+        loop {
+            match &super::next_string!(cmd).to_uppercase()[..] {
+                "COUNT" => match super::next_i64!(cmd) {
+                    // Zero or negative count means no limit.
+                    value if value > 0 => count = Some(value as usize),
+                    _ => count = None,
+                },
+                "BLOCK" => match super::next_i64!(cmd) {
+                    timeout if timeout < 0 => {
+                        return Ok(Data::error("timeout is negative"));
+                    }
+                    // Zero means blocking indefinitely.
+                    0 => block = Some(None),
+                    timeout => block = Some(Some(Duration::from_millis(timeout as u64))),
+                },
+                "STREAMS" => break,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+        let mut args = Vec::new();
+        while let Some(arg) = cmd.next_bytes()? {
+            args.push(arg);
+        }
+        if args.is_empty() || args.len() % 2 != 0 {
+            return Ok(Data::error(
+                "Unbalanced 'xread' list of streams: for each stream key an ID or '$' must be specified.",
+            ));
+        }
+        let ids = args.split_off(args.len() / 2);
+        let keys = args;
+
+        // Without BLOCK, the read lock is enough.
+        if block.is_none() {
+            let db = db::DB.read().await;
+
+            let after = after_ids(&db, &keys, &ids)?;
+            let response = read_all(&db, &keys, &after, count)?;
+            if response.is_empty() {
+                return Ok(Data::NullArray);
+            }
+            return Ok(Data::Array(response));
+        }
+
+        let (id, receiver) = {
+            let mut db = db::DB.write().await;
+
+            let after = after_ids(&db, &keys, &ids)?;
+            let response = read_all(&db, &keys, &after, count)?;
+            if !response.is_empty() {
+                return Ok(Data::Array(response));
+            }
+            db.block(
+                keys.clone(),
+                Box::new(move |db, key| {
+                    let index = keys.iter().position(|k| k == key)?;
+                    read(db, key, after[index], count)
+                        .ok()
+                        .flatten()
+                        .map(|entries| Data::Array(vec![entries]))
+                }),
+            )
+        };
+
+        match db::wait(id, receiver, block.flatten()).await {
+            Some(response) => Ok(response),
+            None => Ok(Data::NullArray),
+        }
+    }
+}
+
+/// Resolve the IDs to read the entries after. "$" means the last ID.
+fn after_ids(
+    db: &db::DBManager,
+    keys: &[Vec<u8>],
+    ids: &[Vec<u8>],
+) -> crate::Result<Vec<db::StreamId>> {
+    let mut after = Vec::with_capacity(ids.len());
+    for (key, id) in keys.iter().zip(ids) {
+        if id == b"$" {
+            after.push(match db.get(key) {
+                Some(entry) => entry.value.as_stream()?.last_id(),
+                None => db::StreamId::MIN,
+            });
+        } else {
+            match db::StreamId::parse(id, 0) {
+                Some(id) => after.push(id),
+                None => return Err(super::INVALID_STREAM_ID.into()),
+            }
+        }
+    }
+    Ok(after)
+}
+
+/// Read the entries of the streams after the IDs.
+/// The streams without entries are not included.
+fn read_all(
+    db: &db::DBManager,
+    keys: &[Vec<u8>],
+    after: &[db::StreamId],
+    count: Option<usize>,
+) -> crate::Result<Vec<Data>> {
+    let mut response = Vec::new();
+    for (key, id) in keys.iter().zip(after) {
+        if let Some(entries) = read(db, key, *id, count)? {
+            response.push(entries);
+        }
+    }
+    Ok(response)
+}
+
+/// Read the entries after the ID.
+/// Return the key and the entries, or None if there are no entries.
+fn read(
+    db: &db::DBManager,
+    key: &[u8],
+    after: db::StreamId,
+    count: Option<usize>,
+) -> crate::Result<Option<Data>> {
+    let stream = match db.get(key) {
+        Some(entry) => entry.value.as_stream()?,
+        None => return Ok(None),
+    };
+    let start = match after.next() {
+        Some(start) => start,
+        None => return Ok(None),
+    };
+    let entries = stream.range(start, db::StreamId::MAX, count, false);
+    if entries.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Data::Array(vec![
+        Data::Bulk(key.to_vec()),
+        super::stream_entries(entries),
+    ])))
+}
@@ -0,0 +1,177 @@
+//! XREADGROUP command
+//!
+//! # command syntax
+//! XREADGROUP GROUP group consumer [COUNT count] [BLOCK milliseconds] \[NOACK\] STREAMS key [key ...] id [id ...]
+//!
+//! <https://redis.io/commands/xreadgroup>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// XReadGroup commnad empty struct
+pub(super) struct XReadGroup;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XREADGROUP"), Box::new(XReadGroup))
+}
+
+#[async_trait]
+impl super::Command for XReadGroup {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        if super::next_string!(cmd).to_uppercase() != "GROUP" {
+            return Ok(Data::error("Missing GROUP option for XREADGROUP"));
+        }
+        let group = super::next_bytes!(cmd);
+        let consumer = super::next_bytes!(cmd);
+        let mut count = None;
+        let mut block = None;
+        let mut no_ack = false;
+        loop {
+            match &super::next_string!(cmd).to_uppercase()[..] {
+                "COUNT" => match super::next_i64!(cmd) {
+                    // Zero or negative count means no limit.
+                    value if value > 0 => count = Some(value as usize),
+                    _ => count = None,
+                },
+                "BLOCK" => match super::next_i64!(cmd) {
+                    timeout if timeout < 0 => {
+                        return Ok(Data::error("timeout is negative"));
+                    }
+                    // Zero means blocking indefinitely.
+                    0 => block = Some(None),
+                    timeout => block = Some(Some(Duration::from_millis(timeout as u64))),
+                },
+                "NOACK" => no_ack = true,
+                "STREAMS" => break,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+        let mut args = Vec::new();
+        while let Some(arg) = cmd.next_bytes()? {
+            args.push(arg);
+        }
+        if args.is_empty() || args.len() % 2 != 0 {
+            return Ok(Data::error(
+                "Unbalanced 'xreadgroup' list of streams: for each stream key an ID or '>' must be specified.",
+            ));
+        }
+        let ids = args.split_off(args.len() / 2);
+        let keys = args;
+
+        // ">" reads the new entries and the others read the pending entries.
+        let mut after = Vec::with_capacity(ids.len());
+        for id in &ids {
+            if id == b">" {
+                after.push(None);
+            } else if id == b"$" {
+                return Ok(Data::error("The $ ID is meaningless in the context of XREADGROUP: you want to read the history of this consumer by specifying a proper ID, or use the > ID to get new messages. The $ ID would just return an empty result set."));
+            } else {
+                match db::StreamId::parse(id, 0) {
+                    Some(id) => after.push(Some(id)),
+                    None => return Ok(Data::error(super::INVALID_STREAM_ID)),
+                }
+            }
+        }
+        let history = after.iter().any(|id| id.is_some());
+
+        let (id, receiver) = {
+            let mut db = db::DB.write().await;
+
+            for key in &keys {
+                let exists = match db.get(key) {
+                    Some(entry) => entry.value.as_stream()?.groups.contains_key(&group),
+                    None => false,
+                };
+                if !exists {
+                    return Ok(Data::Error(
+                        format!(
+                            "NOGROUP No such key '{}' or consumer group '{}' in XREADGROUP with GROUP option",
+                            String::from_utf8_lossy(key),
+                            String::from_utf8_lossy(&group)
+                        )
+                        .into_bytes(),
+                    ));
+                }
+            }
+
+            let now = db::unix_time_ms();
+            let mut response = Vec::new();
+            for (key, after) in keys.iter().zip(&after) {
+                let stream = db.get_mut(key).unwrap().value.as_stream_mut()?;
+                match after {
+                    Some(after) => {
+                        let entries = stream
+                            .read_pending(&group, &consumer, *after, count, now)
+                            .unwrap_or_default();
+                        response.push(Data::Array(vec![
+                            Data::Bulk(key.clone()),
+                            pending_entries(entries),
+                        ]));
+                    }
+                    None => {
+                        if let Some(entries) = read(stream, key, &group, &consumer, count, no_ack) {
+                            response.push(entries);
+                        }
+                    }
+                }
+            }
+            if !response.is_empty() || history {
+                return Ok(Data::Array(response));
+            }
+            match block {
+                Some(_) => db.block(
+                    keys,
+                    Box::new(move |db, key| {
+                        let stream = db.get_mut(key)?.value.as_stream_mut().ok()?;
+                        read(stream, key, &group, &consumer, count, no_ack)
+                            .map(|entries| Data::Array(vec![entries]))
+                    }),
+                ),
+                None => return Ok(Data::NullArray),
+            }
+        };
+
+        match db::wait(id, receiver, block.flatten()).await {
+            Some(response) => Ok(response),
+            None => Ok(Data::NullArray),
+        }
+    }
+}
+
+/// Read the new entries for the consumer of the group.
+/// Return the key and the entries, or None if there are no entries.
+fn read(
+    stream: &mut db::Stream,
+    key: &[u8],
+    group: &[u8],
+    consumer: &[u8],
+    count: Option<usize>,
+    no_ack: bool,
+) -> Option<Data> {
+    let entries = stream.read_group(group, consumer, count, no_ack, db::unix_time_ms())?;
+    if entries.is_empty() {
+        return None;
+    }
+    Some(Data::Array(vec![
+        Data::Bulk(key.to_vec()),
+        super::stream_entries(entries),
+    ]))
+}
+
+/// Response of the pending entries.
+/// Deleted entries have null fields.
+fn pending_entries(entries: Vec<(db::StreamId, Option<db::Fields>)>) -> Data {
+    Data::Array(
+        entries
+            .into_iter()
+            .map(|(id, fields)| match fields {
+                Some(fields) => super::stream_entry(id, fields),
+                None => Data::Array(vec![Data::Bulk(id.to_bytes()), Data::NullArray]),
+            })
+            .collect(),
+    )
+}
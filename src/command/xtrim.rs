@@ -0,0 +1,108 @@
+//! XTRIM command
+//!
+//! # command syntax
+//! XTRIM key MAXLEN|MINID [=|~] threshold [LIMIT count]
+//!
+//! <https://redis.io/commands/xtrim>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// XTrim commnad empty struct
+pub(super) struct XTrim;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("XTRIM"), Box::new(XTrim))
+}
+
+/// Trimming options of XADD and XTRIM.
+pub(super) struct TrimOptions {
+    pub(super) trim: db::Trim,
+    /// Remove only whole nodes.
+    pub(super) approx: bool,
+    pub(super) limit: Option<usize>,
+}
+
+#[async_trait]
+impl super::Command for XTrim {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut args = Vec::new();
+        while let Some(arg) = cmd.next_bytes()? {
+            args.push(arg);
+        }
+        let mut index = 0;
+        let options = match parse_trim(&args, &mut index) {
+            Ok(options) => options,
+            Err(message) => return Ok(Data::error(message)),
+        };
+        if index != args.len() {
+            return Ok(Data::error("syntax error"));
+        }
+
+        let mut db = db::DB.write().await;
+
+        match db.get_mut(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_stream_mut()?.trim(
+                &options.trim,
+                options.approx,
+                options.limit,
+            ) as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
+
+/// Parse the trimming options starting at the strategy (MAXLEN or MINID).
+/// The index is advanced past the options.
+pub(super) fn parse_trim(args: &[Vec<u8>], index: &mut usize) -> Result<TrimOptions, &'static str> {
+    let mut next = || {
+        let arg = args.get(*index).ok_or("syntax error")?;
+        *index += 1;
+        Ok::<_, &'static str>(arg)
+    };
+    let strategy = next()?.to_ascii_uppercase();
+    let mut threshold = next()?;
+    let mut approx = false;
+    if threshold == b"~" || threshold == b"=" {
+        approx = threshold == b"~";
+        threshold = next()?;
+    }
+    let trim = match &strategy[..] {
+        b"MAXLEN" => match super::parse_integer(threshold) {
+            Some(max_len) if max_len < 0 => return Err("The MAXLEN argument must be >= 0."),
+            Some(max_len) => db::Trim::MaxLen(max_len as usize),
+            None => return Err("value is not an integer or out of range"),
+        },
+        b"MINID" => match db::StreamId::parse(threshold, 0) {
+            Some(min_id) => db::Trim::MinId(min_id),
+            None => return Err(super::INVALID_STREAM_ID),
+        },
+        _ => return Err("syntax error"),
+    };
+    let mut limit = None;
+    if args
+        .get(*index)
+        .is_some_and(|arg| arg.eq_ignore_ascii_case(b"LIMIT"))
+    {
+        *index += 1;
+        let count = args.get(*index).ok_or("syntax error")?;
+        *index += 1;
+        limit = match super::parse_integer(count) {
+            Some(count) if count < 0 => return Err("The LIMIT argument must be >= 0."),
+            Some(count) => Some(count as usize),
+            None => return Err("value is not an integer or out of range"),
+        };
+        if !approx {
+            return Err("syntax error, LIMIT cannot be used without the special ~ option");
+        }
+    }
+    Ok(TrimOptions {
+        trim,
+        approx,
+        limit,
+    })
+}
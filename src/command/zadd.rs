@@ -0,0 +1,134 @@
+//! ZADD command
+//!
+//! # command syntax
+//! ZADD key [NX|XX] [GT|LT] \[CH\] \[INCR\] score member [score member ...]
+//!
+//! <https://redis.io/commands/zadd>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZAdd commnad empty struct
+pub(super) struct ZAdd;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZADD"), Box::new(ZAdd))
+}
+
+#[async_trait]
+impl super::Command for ZAdd {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut args = Vec::new();
+        while let Some(arg) = cmd.next_bytes()? {
+            args.push(arg);
+        }
+
+        let (mut nx, mut xx, mut gt, mut lt, mut ch, mut incr) =
+            (false, false, false, false, false, false);
+        let mut index = 0;
+        while index < args.len() {
+            match &args[index].to_ascii_uppercase()[..] {
+                b"NX" => nx = true,
+                b"XX" => xx = true,
+                b"GT" => gt = true,
+                b"LT" => lt = true,
+                b"CH" => ch = true,
+                b"INCR" => incr = true,
+                _ => break,
+            }
+            index += 1;
+        }
+        let args = &args[index..];
+        if args.is_empty() || args.len() % 2 != 0 {
+            return Ok(Data::error("syntax error"));
+        }
+        if nx && xx {
+            return Ok(Data::error(
+                "XX and NX options at the same time are not compatible",
+            ));
+        }
+        if (gt && lt) || (nx && (gt || lt)) {
+            return Ok(Data::error(
+                "GT, LT, and/or NX options at the same time are not compatible",
+            ));
+        }
+        if incr && args.len() > 2 {
+            return Ok(Data::error(
+                "INCR option supports a single increment-element pair",
+            ));
+        }
+        let mut pairs = Vec::with_capacity(args.len() / 2);
+        for pair in args.chunks(2) {
+            match super::parse_float(&pair[0]) {
+                Some(score) => pairs.push((score, pair[1].clone())),
+                None => return Ok(Data::error("value is not a valid float")),
+            }
+        }
+
+        let mut db = db::DB.write().await;
+
+        if let Some(entry) = db.get(&key) {
+            entry.value.as_zset()?;
+        } else if xx {
+            return Ok(if incr {
+                Data::NullBulk
+            } else {
+                Data::Integer(0)
+            });
+        }
+        let zset = db
+            .get_or_insert_with(key.clone(), || db::Value::ZSet(db::ZSet::new()))
+            .value
+            .as_zset_mut()?;
+
+        let mut added = 0;
+        let mut updated = 0;
+        let mut result = None;
+        for (score, member) in pairs {
+            match zset.score(&member) {
+                Some(current) => {
+                    if nx {
+                        continue;
+                    }
+                    let score = if incr { current + score } else { score };
+                    if score.is_nan() {
+                        db.remove_if_empty(&key);
+                        return Ok(Data::error("resulting score is not a number (NaN)"));
+                    }
+                    if (lt && score >= current) || (gt && score <= current) {
+                        continue;
+                    }
+                    if score != current {
+                        zset.insert(member, score);
+                        updated += 1;
+                    }
+                    result = Some(score);
+                }
+                None => {
+                    if xx {
+                        continue;
+                    }
+                    zset.insert(member, score);
+                    added += 1;
+                    result = Some(score);
+                }
+            }
+        }
+        db.remove_if_empty(&key);
+        db.serve_blocked();
+
+        if incr {
+            Ok(result.map_or(Data::NullBulk, |score| {
+                Data::Bulk(super::format_float(score))
+            }))
+        } else if ch {
+            Ok(Data::Integer(added + updated))
+        } else {
+            Ok(Data::Integer(added))
+        }
+    }
+}
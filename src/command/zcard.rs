@@ -0,0 +1,32 @@
+//! ZCARD command
+//!
+//! # command syntax
+//! ZCARD key
+//!
+//! <https://redis.io/commands/zcard>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZCard commnad empty struct
+pub(super) struct ZCard;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZCARD"), Box::new(ZCard))
+}
+
+#[async_trait]
+impl super::Command for ZCard {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(entry.value.as_zset()?.len() as i64)),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
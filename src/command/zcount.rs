@@ -0,0 +1,40 @@
+//! ZCOUNT command
+//!
+//! # command syntax
+//! ZCOUNT key min max
+//!
+//! <https://redis.io/commands/zcount>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZCount commnad empty struct
+pub(super) struct ZCount;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZCOUNT"), Box::new(ZCount))
+}
+
+#[async_trait]
+impl super::Command for ZCount {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let min = super::next_bytes!(cmd);
+        let max = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+        let range = match db::ScoreRange::parse(&min, &max) {
+            Some(range) => range,
+            None => return Ok(Data::error("min or max is not a float")),
+        };
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(
+                entry.value.as_zset()?.count_by_score(&range) as i64
+            )),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
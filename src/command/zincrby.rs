@@ -0,0 +1,47 @@
+//! ZINCRBY command
+//!
+//! # command syntax
+//! ZINCRBY key increment member
+//!
+//! <https://redis.io/commands/zincrby>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZIncrBy commnad empty struct
+pub(super) struct ZIncrBy;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZINCRBY"), Box::new(ZIncrBy))
+}
+
+#[async_trait]
+impl super::Command for ZIncrBy {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let increment = match super::parse_float(&super::next_bytes!(cmd)) {
+            Some(increment) => increment,
+            None => return Ok(Data::error("value is not a valid float")),
+        };
+        let member = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let zset = db
+            .get_or_insert_with(key, || db::Value::ZSet(db::ZSet::new()))
+            .value
+            .as_zset_mut()?;
+        let score = zset.score(&member).unwrap_or(0.0) + increment;
+        if score.is_nan() {
+            return Ok(Data::error("resulting score is not a number (NaN)"));
+        }
+        zset.insert(member, score);
+        db.serve_blocked();
+
+        Ok(Data::Bulk(super::format_float(score)))
+    }
+}
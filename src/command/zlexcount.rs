@@ -0,0 +1,40 @@
+//! ZLEXCOUNT command
+//!
+//! # command syntax
+//! ZLEXCOUNT key min max
+//!
+//! <https://redis.io/commands/zlexcount>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZLexCount commnad empty struct
+pub(super) struct ZLexCount;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZLEXCOUNT"), Box::new(ZLexCount))
+}
+
+#[async_trait]
+impl super::Command for ZLexCount {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let min = super::next_bytes!(cmd);
+        let max = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+        let range = match db::LexRange::parse(&min, &max) {
+            Some(range) => range,
+            None => return Ok(Data::error("min or max not valid string range item")),
+        };
+
+        match db::DB.read().await.get(&key) {
+            Some(entry) => Ok(Data::Integer(
+                entry.value.as_zset()?.count_by_lex(&range) as i64
+            )),
+            None => Ok(Data::Integer(0)),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! ZMSCORE command
+//!
+//! # command syntax
+//! ZMSCORE key member [member ...]
+//!
+//! <https://redis.io/commands/zmscore>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZMScore commnad empty struct
+pub(super) struct ZMScore;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZMSCORE"), Box::new(ZMScore))
+}
+
+#[async_trait]
+impl super::Command for ZMScore {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut members = vec![super::next_bytes!(cmd)];
+        while let Some(member) = cmd.next_bytes()? {
+            members.push(member);
+        }
+
+        let db = db::DB.read().await;
+
+        let zset = match db.get(&key) {
+            Some(entry) => Some(entry.value.as_zset()?),
+            None => None,
+        };
+        Ok(Data::Array(
+            members
+                .iter()
+                .map(|member| match zset.and_then(|zset| zset.score(member)) {
+                    Some(score) => Data::Bulk(super::format_float(score)),
+                    None => Data::NullBulk,
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,63 @@
+//! ZPOPMIN, ZPOPMAX command
+//!
+//! # command syntax
+//! ZPOPMIN key \[count\]
+//!
+//! <https://redis.io/commands/zpopmin>
+//!
+//! ZPOPMAX key \[count\]
+//!
+//! <https://redis.io/commands/zpopmax>
+//!
+use super::ScoreEnd;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZPop commnad struct
+pub(super) struct ZPop {
+    end: ScoreEnd,
+}
+
+/// command register function
+pub(super) fn command(end: ScoreEnd) -> (String, super::Cmd) {
+    match end {
+        ScoreEnd::Min => (String::from("ZPOPMIN"), Box::new(ZPop { end })),
+        ScoreEnd::Max => (String::from("ZPOPMAX"), Box::new(ZPop { end })),
+    }
+}
+
+#[async_trait]
+impl super::Command for ZPop {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let count = match cmd.next_i64()? {
+            Some(count) => {
+                if count < 0 {
+                    return Ok(Data::error("value is out of range, must be positive"));
+                }
+                super::check_end_of_param!(cmd);
+                count as usize
+            }
+            None => 1,
+        };
+
+        let mut db = db::DB.write().await;
+
+        let zset = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_zset_mut()?,
+            None => return Ok(Data::Array(Vec::new())),
+        };
+        let members = zset.pop(matches!(self.end, ScoreEnd::Max), count);
+        db.remove_if_empty(&key);
+
+        Ok(super::scored_members(
+            members
+                .iter()
+                .map(|(member, score)| (&member[..], *score))
+                .collect(),
+            true,
+        ))
+    }
+}
@@ -0,0 +1,57 @@
+//! ZRANDMEMBER command
+//!
+//! # command syntax
+//! ZRANDMEMBER key [count \[WITHSCORES\]]
+//!
+//! <https://redis.io/commands/zrandmember>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZRandMember commnad empty struct
+pub(super) struct ZRandMember;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZRANDMEMBER"), Box::new(ZRandMember))
+}
+
+#[async_trait]
+impl super::Command for ZRandMember {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let count = cmd.next_i64()?;
+        let with_scores = match cmd.next_string()? {
+            Some(option) if option.to_uppercase() == "WITHSCORES" => true,
+            Some(_) => return Ok(Data::error("syntax error")),
+            None => false,
+        };
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let zset = match db.get(&key) {
+            Some(entry) => entry.value.as_zset()?,
+            None => {
+                return match count {
+                    Some(_) => Ok(Data::Array(Vec::new())),
+                    None => Ok(Data::NullBulk),
+                }
+            }
+        };
+        // Negative count may return the same member multiple times.
+        let picked: Vec<(&[u8], f64)> = super::random_indexes(zset.len(), count.unwrap_or(1))?
+            .into_iter()
+            .filter_map(|index| zset.by_rank(index))
+            .collect();
+
+        match count {
+            Some(_) => Ok(super::scored_members(picked, with_scores)),
+            None => Ok(picked
+                .first()
+                .map_or(Data::NullBulk, |(member, _)| Data::Bulk(member.to_vec()))),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! ZRANGE, ZRANGESTORE command
+//!
+//! # command syntax
+//! ZRANGE key start stop \[BYSCORE|BYLEX\] \[REV\] [LIMIT offset count] \[WITHSCORES\]
+//!
+//! <https://redis.io/commands/zrange>
+//!
+//! ZRANGESTORE dst src min max \[BYSCORE|BYLEX\] \[REV\] [LIMIT offset count]
+//!
+//! <https://redis.io/commands/zrangestore>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZRange commnad struct
+pub(super) struct ZRange {
+    /// Store the result in the destination.
+    store: bool,
+}
+
+/// Kind of the range.
+enum By {
+    Rank,
+    Score,
+    Lex,
+}
+
+/// Parsed range.
+enum Range {
+    Rank(i64, i64),
+    Score(db::ScoreRange),
+    Lex(db::LexRange),
+}
+
+impl Range {
+    /// Get the members in the range of the sorted set.
+    fn members<'a>(
+        &self,
+        zset: &'a db::ZSet,
+        reverse: bool,
+        offset: usize,
+        count: Option<usize>,
+    ) -> Vec<(&'a [u8], f64)> {
+        match self {
+            Range::Score(range) => zset.range_by_score(range, reverse, offset, count),
+            Range::Lex(range) => zset.range_by_lex(range, reverse, offset, count),
+            Range::Rank(start, stop) => match super::range_index(*start, *stop, zset.len()) {
+                Some((start, stop)) => zset.range_by_rank(start, stop, reverse),
+                None => Vec::new(),
+            },
+        }
+    }
+}
+
+/// command register function
+pub(super) fn command(store: bool) -> (String, super::Cmd) {
+    match store {
+        false => (String::from("ZRANGE"), Box::new(ZRange { store })),
+        true => (String::from("ZRANGESTORE"), Box::new(ZRange { store })),
+    }
+}
+
+#[async_trait]
+impl super::Command for ZRange {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let destination = if self.store {
+            Some(super::next_bytes!(cmd))
+        } else {
+            None
+        };
+        let key = super::next_bytes!(cmd);
+        let start = super::next_bytes!(cmd);
+        let stop = super::next_bytes!(cmd);
+
+        let mut by = By::Rank;
+        let mut reverse = false;
+        let mut limit = None;
+        let mut with_scores = false;
+        while let Some(option) = cmd.next_string()? {
+            match option.as_str() {
+                "BYSCORE" => by = By::Score,
+                "BYLEX" => by = By::Lex,
+                "REV" => reverse = true,
+                "LIMIT" => limit = Some((super::next_i64!(cmd), super::next_i64!(cmd))),
+                "WITHSCORES" if !self.store => with_scores = true,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+        if limit.is_some() && matches!(by, By::Rank) {
+            return Ok(Data::error(
+                "syntax error, LIMIT is only supported in combination with either BYSCORE or BYLEX",
+            ));
+        }
+        if with_scores && matches!(by, By::Lex) {
+            return Ok(Data::error(
+                "syntax error, WITHSCORES not supported in combination with BYLEX",
+            ));
+        }
+        // Negative offset returns nothing and negative count returns all.
+        let (offset, count) = match limit {
+            Some((offset, _)) if offset < 0 => (usize::MAX, None),
+            Some((offset, count)) => (offset as usize, usize::try_from(count).ok()),
+            None => (0, None),
+        };
+        // The range is given from the max to the min in reverse order.
+        let (min, max) = if reverse {
+            (&stop, &start)
+        } else {
+            (&start, &stop)
+        };
+        let range = match by {
+            By::Score => match db::ScoreRange::parse(min, max) {
+                Some(range) => Range::Score(range),
+                None => return Ok(Data::error("min or max is not a float")),
+            },
+            By::Lex => match db::LexRange::parse(min, max) {
+                Some(range) => Range::Lex(range),
+                None => return Ok(Data::error("min or max not valid string range item")),
+            },
+            By::Rank => match (super::parse_integer(&start), super::parse_integer(&stop)) {
+                (Some(start), Some(stop)) => Range::Rank(start, stop),
+                _ => return Ok(Data::error("value is not an integer or out of range")),
+            },
+        };
+
+        match destination {
+            Some(destination) => {
+                let mut db = db::DB.write().await;
+
+                let mut result = db::ZSet::new();
+                if let Some(entry) = db.get(&key) {
+                    let zset = entry.value.as_zset()?;
+                    for (member, score) in range.members(zset, reverse, offset, count) {
+                        result.insert(member.to_vec(), score);
+                    }
+                }
+                let len = result.len();
+                db.insert(destination, db::Value::ZSet(result));
+                db.serve_blocked();
+
+                Ok(Data::Integer(len as i64))
+            }
+            None => {
+                let db = db::DB.read().await;
+
+                let members = match db.get(&key) {
+                    Some(entry) => range.members(entry.value.as_zset()?, reverse, offset, count),
+                    None => Vec::new(),
+                };
+                Ok(super::scored_members(members, with_scores))
+            }
+        }
+    }
+}
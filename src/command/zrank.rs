@@ -0,0 +1,68 @@
+//! ZRANK, ZREVRANK command
+//!
+//! # command syntax
+//! ZRANK key member \[WITHSCORE\]
+//!
+//! <https://redis.io/commands/zrank>
+//!
+//! ZREVRANK key member \[WITHSCORE\]
+//!
+//! <https://redis.io/commands/zrevrank>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZRank commnad struct
+pub(super) struct ZRank {
+    /// Rank from the highest score.
+    reverse: bool,
+}
+
+/// command register function
+pub(super) fn command(reverse: bool) -> (String, super::Cmd) {
+    match reverse {
+        false => (String::from("ZRANK"), Box::new(ZRank { reverse })),
+        true => (String::from("ZREVRANK"), Box::new(ZRank { reverse })),
+    }
+}
+
+#[async_trait]
+impl super::Command for ZRank {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let member = super::next_bytes!(cmd);
+        let with_score = match cmd.next_string()? {
+            Some(option) if option.to_uppercase() == "WITHSCORE" => true,
+            Some(_) => return Ok(Data::error("syntax error")),
+            None => false,
+        };
+        super::check_end_of_param!(cmd);
+        let missing = if with_score {
+            Data::NullArray
+        } else {
+            Data::NullBulk
+        };
+
+        let db = db::DB.read().await;
+
+        let zset = match db.get(&key) {
+            Some(entry) => entry.value.as_zset()?,
+            None => return Ok(missing),
+        };
+        let rank = match zset.rank(&member, self.reverse) {
+            Some(rank) => rank as i64,
+            None => return Ok(missing),
+        };
+        if with_score {
+            let score = zset.score(&member).unwrap();
+            Ok(Data::Array(vec![
+                Data::Integer(rank),
+                Data::Bulk(super::format_float(score)),
+            ]))
+        } else {
+            Ok(Data::Integer(rank))
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! ZREM command
+//!
+//! # command syntax
+//! ZREM key member [member ...]
+//!
+//! <https://redis.io/commands/zrem>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZRem commnad empty struct
+pub(super) struct ZRem;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZREM"), Box::new(ZRem))
+}
+
+#[async_trait]
+impl super::Command for ZRem {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let mut members = vec![super::next_bytes!(cmd)];
+        while let Some(member) = cmd.next_bytes()? {
+            members.push(member);
+        }
+
+        let mut db = db::DB.write().await;
+
+        let zset = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_zset_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        let mut removed = 0;
+        for member in members {
+            if zset.remove(&member) {
+                removed += 1;
+            }
+        }
+        db.remove_if_empty(&key);
+
+        Ok(Data::Integer(removed))
+    }
+}
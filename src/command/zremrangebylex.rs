@@ -0,0 +1,43 @@
+//! ZREMRANGEBYLEX command
+//!
+//! # command syntax
+//! ZREMRANGEBYLEX key min max
+//!
+//! <https://redis.io/commands/zremrangebylex>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZRemRangeByLex commnad empty struct
+pub(super) struct ZRemRangeByLex;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZREMRANGEBYLEX"), Box::new(ZRemRangeByLex))
+}
+
+#[async_trait]
+impl super::Command for ZRemRangeByLex {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let min = super::next_bytes!(cmd);
+        let max = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+        let range = match db::LexRange::parse(&min, &max) {
+            Some(range) => range,
+            None => return Ok(Data::error("min or max not valid string range item")),
+        };
+
+        let mut db = db::DB.write().await;
+
+        let removed = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_zset_mut()?.remove_range_by_lex(&range),
+            None => return Ok(Data::Integer(0)),
+        };
+        db.remove_if_empty(&key);
+
+        Ok(Data::Integer(removed as i64))
+    }
+}
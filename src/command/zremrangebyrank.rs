@@ -0,0 +1,43 @@
+//! ZREMRANGEBYRANK command
+//!
+//! # command syntax
+//! ZREMRANGEBYRANK key start stop
+//!
+//! <https://redis.io/commands/zremrangebyrank>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZRemRangeByRank commnad empty struct
+pub(super) struct ZRemRangeByRank;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZREMRANGEBYRANK"), Box::new(ZRemRangeByRank))
+}
+
+#[async_trait]
+impl super::Command for ZRemRangeByRank {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let start = super::next_i64!(cmd);
+        let stop = super::next_i64!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let mut db = db::DB.write().await;
+
+        let zset = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_zset_mut()?,
+            None => return Ok(Data::Integer(0)),
+        };
+        let removed = match super::range_index(start, stop, zset.len()) {
+            Some((start, stop)) => zset.remove_range_by_rank(start, stop),
+            None => 0,
+        };
+        db.remove_if_empty(&key);
+
+        Ok(Data::Integer(removed as i64))
+    }
+}
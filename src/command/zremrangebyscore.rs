@@ -0,0 +1,43 @@
+//! ZREMRANGEBYSCORE command
+//!
+//! # command syntax
+//! ZREMRANGEBYSCORE key min max
+//!
+//! <https://redis.io/commands/zremrangebyscore>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZRemRangeByScore commnad empty struct
+pub(super) struct ZRemRangeByScore;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZREMRANGEBYSCORE"), Box::new(ZRemRangeByScore))
+}
+
+#[async_trait]
+impl super::Command for ZRemRangeByScore {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let min = super::next_bytes!(cmd);
+        let max = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+        let range = match db::ScoreRange::parse(&min, &max) {
+            Some(range) => range,
+            None => return Ok(Data::error("min or max is not a float")),
+        };
+
+        let mut db = db::DB.write().await;
+
+        let removed = match db.get_mut(&key) {
+            Some(entry) => entry.value.as_zset_mut()?.remove_range_by_score(&range),
+            None => return Ok(Data::Integer(0)),
+        };
+        db.remove_if_empty(&key);
+
+        Ok(Data::Integer(removed as i64))
+    }
+}
@@ -0,0 +1,39 @@
+//! ZSCORE command
+//!
+//! # command syntax
+//! ZSCORE key member
+//!
+//! <https://redis.io/commands/zscore>
+//!
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+
+/// ZScore commnad empty struct
+pub(super) struct ZScore;
+
+/// command register function
+pub(super) fn command() -> (String, super::Cmd) {
+    (String::from("ZSCORE"), Box::new(ZScore))
+}
+
+#[async_trait]
+impl super::Command for ZScore {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let key = super::next_bytes!(cmd);
+        let member = super::next_bytes!(cmd);
+        super::check_end_of_param!(cmd);
+
+        let db = db::DB.read().await;
+
+        let zset = match db.get(&key) {
+            Some(entry) => entry.value.as_zset()?,
+            None => return Ok(Data::NullBulk),
+        };
+        match zset.score(&member) {
+            Some(score) => Ok(Data::Bulk(super::format_float(score))),
+            None => Ok(Data::NullBulk),
+        }
+    }
+}
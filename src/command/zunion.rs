@@ -0,0 +1,243 @@
+//! ZUNION, ZINTER, ZDIFF, ZUNIONSTORE, ZINTERSTORE, ZDIFFSTORE command
+//!
+//! # command syntax
+//! ZUNION numkeys key [key ...] [WEIGHTS weight [weight ...]] [AGGREGATE SUM|MIN|MAX] \[WITHSCORES\]
+//!
+//! <https://redis.io/commands/zunion>
+//!
+//! ZINTER numkeys key [key ...] [WEIGHTS weight [weight ...]] [AGGREGATE SUM|MIN|MAX] \[WITHSCORES\]
+//!
+//! <https://redis.io/commands/zinter>
+//!
+//! ZDIFF numkeys key [key ...] \[WITHSCORES\]
+//!
+//! <https://redis.io/commands/zdiff>
+//!
+//! ZUNIONSTORE destination numkeys key [key ...] [WEIGHTS weight [weight ...]] [AGGREGATE SUM|MIN|MAX]
+//!
+//! <https://redis.io/commands/zunionstore>
+//!
+//! ZINTERSTORE destination numkeys key [key ...] [WEIGHTS weight [weight ...]] [AGGREGATE SUM|MIN|MAX]
+//!
+//! <https://redis.io/commands/zinterstore>
+//!
+//! ZDIFFSTORE destination numkeys key [key ...]
+//!
+//! <https://redis.io/commands/zdiffstore>
+//!
+use super::SetOperation;
+use crate::db;
+use crate::protocol::resp::{Data, Parser};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// ZSetOp commnad struct
+pub(super) struct ZSetOp {
+    operation: SetOperation,
+    /// Store the result in the destination.
+    store: bool,
+}
+
+/// How to combine the scores of the same member.
+#[derive(Clone, Copy)]
+enum Aggregate {
+    Sum,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    fn apply(self, a: f64, b: f64) -> f64 {
+        match self {
+            // inf + -inf is defined as zero.
+            Aggregate::Sum => zero_if_nan(a + b),
+            Aggregate::Min => a.min(b),
+            Aggregate::Max => a.max(b),
+        }
+    }
+}
+
+/// command register function
+pub(super) fn command(operation: SetOperation, store: bool) -> (String, super::Cmd) {
+    let name = match (operation, store) {
+        (SetOperation::Union, false) => "ZUNION",
+        (SetOperation::Inter, false) => "ZINTER",
+        (SetOperation::Diff, false) => "ZDIFF",
+        (SetOperation::Union, true) => "ZUNIONSTORE",
+        (SetOperation::Inter, true) => "ZINTERSTORE",
+        (SetOperation::Diff, true) => "ZDIFFSTORE",
+    };
+    (String::from(name), Box::new(ZSetOp { operation, store }))
+}
+
+#[async_trait]
+impl super::Command for ZSetOp {
+    /// Get command body
+    async fn execute(&self, cmd: &mut Parser) -> crate::Result<Data> {
+        let destination = if self.store {
+            Some(super::next_bytes!(cmd))
+        } else {
+            None
+        };
+        let numkeys = super::next_i64!(cmd);
+        if numkeys <= 0 {
+            let name = match self.operation {
+                SetOperation::Union => "zunion",
+                SetOperation::Inter => "zinter",
+                SetOperation::Diff => "zdiff",
+            };
+            let name = if self.store {
+                format!("{}store", name)
+            } else {
+                name.to_string()
+            };
+            return Ok(Data::error(&format!(
+                "at least 1 input key is needed for '{}' command",
+                name
+            )));
+        }
+        let mut keys = Vec::new();
+        for _ in 0..numkeys {
+            match cmd.next_bytes()? {
+                Some(key) => keys.push(key),
+                None => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        let mut weights = vec![1.0; keys.len()];
+        let mut aggregate = Aggregate::Sum;
+        let mut with_scores = false;
+        let is_diff = matches!(self.operation, SetOperation::Diff);
+        while let Some(option) = cmd.next_string()? {
+            match &option.to_uppercase()[..] {
+                "WEIGHTS" if !is_diff => {
+                    for weight in weights.iter_mut() {
+                        *weight = match super::parse_float(&super::next_bytes!(cmd)) {
+                            Some(value) => value,
+                            None => return Ok(Data::error("weight value is not a float")),
+                        };
+                    }
+                }
+                "AGGREGATE" if !is_diff => {
+                    aggregate = match &super::next_string!(cmd).to_uppercase()[..] {
+                        "SUM" => Aggregate::Sum,
+                        "MIN" => Aggregate::Min,
+                        "MAX" => Aggregate::Max,
+                        _ => return Ok(Data::error("syntax error")),
+                    }
+                }
+                "WITHSCORES" if !self.store => with_scores = true,
+                _ => return Ok(Data::error("syntax error")),
+            }
+        }
+
+        match destination {
+            Some(destination) => {
+                let mut db = db::DB.write().await;
+
+                let result = compute(&db, &keys, &weights, aggregate, self.operation)?;
+                let mut zset = db::ZSet::new();
+                for (member, score) in result {
+                    zset.insert(member, score);
+                }
+                let len = zset.len();
+                db.insert(destination, db::Value::ZSet(zset));
+                db.serve_blocked();
+
+                Ok(Data::Integer(len as i64))
+            }
+            None => {
+                let db = db::DB.read().await;
+
+                let result = compute(&db, &keys, &weights, aggregate, self.operation)?;
+                let mut members: Vec<(&[u8], f64)> = result
+                    .iter()
+                    .map(|(member, score)| (&member[..], *score))
+                    .collect();
+                members.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+
+                Ok(super::scored_members(members, with_scores))
+            }
+        }
+    }
+}
+
+/// Zero if the score is NaN.
+fn zero_if_nan(score: f64) -> f64 {
+    if score.is_nan() {
+        0.0
+    } else {
+        score
+    }
+}
+
+/// Member-score pairs of the sorted set or the set.
+/// Members of the set have the score 1.
+fn members(entry: &db::BDEntry) -> crate::Result<Vec<(&[u8], f64)>> {
+    match &entry.value {
+        db::Value::Set(set) => Ok(set.iter().map(|member| (&member[..], 1.0)).collect()),
+        value => Ok(value.as_zset()?.iter().collect()),
+    }
+}
+
+/// Compute the operation between the sorted sets.
+/// Missing keys are empty sets.
+fn compute(
+    db: &db::DBManager,
+    keys: &[Vec<u8>],
+    weights: &[f64],
+    aggregate: Aggregate,
+    operation: SetOperation,
+) -> crate::Result<HashMap<Vec<u8>, f64>> {
+    let mut sets = Vec::with_capacity(keys.len());
+    for (key, weight) in keys.iter().zip(weights) {
+        sets.push(match db.get(key) {
+            Some(entry) => members(entry)?
+                .into_iter()
+                .map(|(member, score)| (member, zero_if_nan(score * weight)))
+                .collect::<HashMap<&[u8], f64>>(),
+            None => HashMap::new(),
+        });
+    }
+
+    let (first, others) = sets.split_first().unwrap();
+    let mut result = HashMap::new();
+    match operation {
+        SetOperation::Union => {
+            for set in &sets {
+                for (member, score) in set {
+                    result
+                        .entry(member.to_vec())
+                        .and_modify(|current| *current = aggregate.apply(*current, *score))
+                        .or_insert(*score);
+                }
+            }
+        }
+        SetOperation::Inter => {
+            for (member, score) in first {
+                let mut score = *score;
+                let mut found = true;
+                for set in others {
+                    match set.get(member) {
+                        Some(other) => score = aggregate.apply(score, *other),
+                        None => {
+                            found = false;
+                            break;
+                        }
+                    }
+                }
+                if found {
+                    result.insert(member.to_vec(), score);
+                }
+            }
+        }
+        SetOperation::Diff => {
+            for (member, score) in first {
+                if others.iter().all(|set| !set.contains_key(member)) {
+                    result.insert(member.to_vec(), *score);
+                }
+            }
+        }
+    }
+    Ok(result)
+}
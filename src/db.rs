@@ -1,10 +1,62 @@
 //! The key-value database with an expiration date.
 //!
-use async_std::{channel, prelude::*, stream, sync::RwLock, task};
+use crate::protocol::resp::Data;
+use async_std::{channel, future, prelude::*, sync::RwLock, task};
 use futures::{future::join_all, select, FutureExt};
 use once_cell::sync::Lazy;
-use std::collections::{hash_map::Entry, BTreeMap, HashMap};
-use std::time::{Duration, Instant};
+use std::borrow::Cow;
+use std::collections::{hash_map::Entry, BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+mod attrfilter;
+mod bitmap;
+mod bloom;
+mod countminsketch;
+mod cuckoo;
+mod geo;
+mod gorilla;
+mod hash;
+mod hyperloglog;
+mod json;
+mod jsonpath;
+mod scan;
+mod search;
+mod searchquery;
+mod stream;
+mod tdigest;
+mod timeseries;
+mod topk;
+mod trie;
+mod vectorset;
+mod zset;
+
+pub(crate) use attrfilter::Filter;
+pub(crate) use bitmap::{
+    bitcount, bitop, bitpos, fit_field, get_field, getbit, set_field, BitOperation, Overflow,
+};
+pub(crate) use bloom::BloomFilter;
+pub(crate) use countminsketch::CountMinSketch;
+pub(crate) use cuckoo::CuckooFilter;
+pub(crate) use geo::{
+    geo_decode, geo_distance, geo_encode, geo_hash, geo_search, is_valid_position, GeoShape,
+};
+pub(crate) use hash::Hash;
+pub(crate) use hyperloglog::HyperLogLog;
+pub(crate) use json::{Json, JsonFormat, JsonStep};
+pub(crate) use jsonpath::JsonPath;
+pub(crate) use scan::{glob_match, scan};
+pub(crate) use search::{FieldKind, SearchField, SearchIndex};
+pub(crate) use searchquery::SearchQuery;
+pub(crate) use stream::{Fields, Group, Stream, StreamId, Trim};
+pub(crate) use tdigest::TDigest;
+pub(crate) use timeseries::{
+    bucket_start, Aggregation, Aggregator, DuplicatePolicy, Rule, SampleError, TimeSeries,
+};
+pub(crate) use topk::TopK;
+pub(crate) use trie::Trie;
+pub(crate) use vectorset::{Quantization, VectorSet};
+pub(crate) use zset::{LexRange, ScoreRange, ZSet};
 
 /// The data base singleton.
 pub(crate) static DB: Lazy<RwLock<DBManager>> = Lazy::new(|| {
@@ -12,30 +64,251 @@ pub(crate) static DB: Lazy<RwLock<DBManager>> = Lazy::new(|| {
         entries: HashMap::new(),
         expirations: BTreeMap::new(),
         expiration_id: 1,
+        blocked_keys: HashMap::new(),
+        waiters: HashMap::new(),
+        waiter_id: 1,
+        ready_keys: VecDeque::new(),
+        retention_keys: HashSet::new(),
+        search_indexes: HashMap::new(),
         task_handles: Vec::new(),
     })
 });
 
+/// Current Unix time in milliseconds.
+pub(crate) fn unix_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as u64)
+}
+
 /// Key-value entries.
 pub(crate) struct BDEntry {
     /// Value
-    pub(crate) value: Vec<u8>,
-    /// Expiration date
-    pub(crate) expiration: Option<Instant>,
+    pub(crate) value: Value,
+    /// Expiration date in Unix time milliseconds
+    pub(crate) expiration: Option<u64>,
+}
+/// Typed value of the entry.
+pub(crate) enum Value {
+    /// String value.
+    String(Vec<u8>),
+    /// String value holding an integer.
+    Integer(i64),
+    /// List value.
+    List(VecDeque<Vec<u8>>),
+    /// Hash value.
+    Hash(Hash),
+    /// Set value.
+    Set(HashSet<Vec<u8>>),
+    /// Sorted set value.
+    ZSet(ZSet),
+    /// Stream value.
+    Stream(Stream),
+    /// JSON value.
+    Json(Json),
+    /// Bloom filter value.
+    BloomFilter(BloomFilter),
+    /// Cuckoo filter value.
+    CuckooFilter(CuckooFilter),
+    /// Count-Min Sketch value.
+    CountMinSketch(CountMinSketch),
+    /// Top-K value.
+    TopK(TopK),
+    /// T-digest value.
+    TDigest(TDigest),
+    /// Time series value.
+    TimeSeries(TimeSeries),
+    /// Vector set value.
+    VectorSet(VectorSet),
+    /// Suggestion dictionary value.
+    Trie(Trie),
+}
+/// Database error.
+#[derive(Debug)]
+pub(crate) enum Error {
+    /// Operation against a key holding the wrong kind of value.
+    WrongType,
+    /// The string is not a HyperLogLog.
+    InvalidHyperLogLog,
+    /// The HyperLogLog is corrupted.
+    CorruptedHyperLogLog,
+}
+
+/// Implementation of "Display" for database errors.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::Error::*;
+        match self {
+            WrongType => write!(
+                f,
+                "WRONGTYPE Operation against a key holding the wrong kind of value"
+            ),
+            InvalidHyperLogLog => write!(
+                f,
+                "WRONGTYPE Key is not a valid HyperLogLog string value."
+            ),
+            CorruptedHyperLogLog => write!(f, "INVALIDOBJ Corrupted HLL object detected"),
+        }
+    }
+}
+
+/// Implementation of "Error" for database errors.
+impl std::error::Error for Error {}
+
+/// Generate the typed accessors of the value.
+/// The accessors return WRONGTYPE error for the other kinds of values.
+macro_rules! value_accessors {
+    ($($variant:ident: $type:ty => $as_ref:ident, $as_mut:ident;)*) => {
+        impl Value {
+            $(
+                #[allow(dead_code)]
+                pub(crate) fn $as_ref(&self) -> crate::Result<&$type> {
+                    match self {
+                        Value::$variant(value) => Ok(value),
+                        _ => Err(Box::new(Error::WrongType)),
+                    }
+                }
+                #[allow(dead_code)]
+                pub(crate) fn $as_mut(&mut self) -> crate::Result<&mut $type> {
+                    match self {
+                        Value::$variant(value) => Ok(value),
+                        _ => Err(Box::new(Error::WrongType)),
+                    }
+                }
+            )*
+        }
+    };
+}
+
+value_accessors! {
+    List: VecDeque<Vec<u8>> => as_list, as_list_mut;
+    Hash: Hash => as_hash, as_hash_mut;
+    Set: HashSet<Vec<u8>> => as_set, as_set_mut;
+    ZSet: ZSet => as_zset, as_zset_mut;
+    Stream: Stream => as_stream, as_stream_mut;
+    Json: Json => as_json, as_json_mut;
+    BloomFilter: BloomFilter => as_bloom_filter, as_bloom_filter_mut;
+    CuckooFilter: CuckooFilter => as_cuckoo_filter, as_cuckoo_filter_mut;
+    CountMinSketch: CountMinSketch => as_count_min_sketch, as_count_min_sketch_mut;
+    TopK: TopK => as_top_k, as_top_k_mut;
+    TDigest: TDigest => as_tdigest, as_tdigest_mut;
+    TimeSeries: TimeSeries => as_time_series, as_time_series_mut;
+    VectorSet: VectorSet => as_vector_set, as_vector_set_mut;
+    Trie: Trie => as_trie, as_trie_mut;
+}
+
+impl Value {
+    /// Create the string value.
+    /// The integer encoding is used if the bytes are the decimal form of an integer.
+    pub(crate) fn string(bytes: Vec<u8>) -> Value {
+        match Self::parse_integer(&bytes) {
+            Some(integer) => Value::Integer(integer),
+            None => Value::String(bytes),
+        }
+    }
+    /// Parse the bytes in the same decimal form as the integer encoding.
+    fn parse_integer(bytes: &[u8]) -> Option<i64> {
+        // i64::MIN has 20 characters.
+        if bytes.is_empty() || bytes.len() > 20 {
+            return None;
+        }
+        let integer: i64 = std::str::from_utf8(bytes).ok()?.parse().ok()?;
+        if integer.to_string().as_bytes() == bytes {
+            Some(integer)
+        } else {
+            None
+        }
+    }
+    /// Get the bytes of the string value.
+    pub(crate) fn as_string(&self) -> crate::Result<Cow<'_, [u8]>> {
+        match self {
+            Value::String(bytes) => Ok(Cow::Borrowed(bytes)),
+            Value::Integer(integer) => Ok(Cow::Owned(integer.to_string().into_bytes())),
+            _ => Err(Box::new(Error::WrongType)),
+        }
+    }
+    /// Get the mutable bytes of the string value.
+    /// The integer encoding is converted into bytes.
+    pub(crate) fn as_string_mut(&mut self) -> crate::Result<&mut Vec<u8>> {
+        if let Value::Integer(integer) = self {
+            *self = Value::String(integer.to_string().into_bytes());
+        }
+        match self {
+            Value::String(bytes) => Ok(bytes),
+            _ => Err(Box::new(Error::WrongType)),
+        }
+    }
+    /// Get the integer of the string value.
+    /// Return None if the string is not an integer.
+    pub(crate) fn as_integer(&self) -> crate::Result<Option<i64>> {
+        match self {
+            Value::String(bytes) => Ok(Self::parse_integer(bytes)),
+            Value::Integer(integer) => Ok(Some(*integer)),
+            _ => Err(Box::new(Error::WrongType)),
+        }
+    }
+    /// Empty collections are removed from the database.
+    /// Streams, JSON values, probabilistic values and time series are kept even if they are empty.
+    pub(crate) fn is_empty(&self) -> bool {
+        match self {
+            Value::String(_)
+            | Value::Integer(_)
+            | Value::Stream(_)
+            | Value::Json(_)
+            | Value::BloomFilter(_)
+            | Value::CuckooFilter(_)
+            | Value::CountMinSketch(_)
+            | Value::TopK(_)
+            | Value::TDigest(_)
+            | Value::TimeSeries(_) => false,
+            Value::List(list) => list.is_empty(),
+            Value::Hash(hash) => hash.is_empty(),
+            Value::Set(set) => set.is_empty(),
+            Value::ZSet(zset) => zset.is_empty(),
+            Value::VectorSet(set) => set.is_empty(),
+            Value::Trie(trie) => trie.is_empty(),
+        }
+    }
 }
 /// Database manager
 pub(crate) struct DBManager {
     /// Key-value entries.
     entries: HashMap<Vec<u8>, BDEntry>,
     /// Map of entries with expiration dates.    
-    expirations: BTreeMap<(Instant, u64), Vec<u8>>,
+    expirations: BTreeMap<(u64, u64), Vec<u8>>,
     /// ID to make the key unique.
     expiration_id: u64,
+    /// IDs of the blocked clients for each key in FIFO order.
+    blocked_keys: HashMap<Vec<u8>, VecDeque<u64>>,
+    /// Blocked clients.
+    waiters: HashMap<u64, Waiter>,
+    /// ID of the blocked client.
+    waiter_id: u64,
+    /// Keys that may be able to serve the blocked clients.
+    ready_keys: VecDeque<Vec<u8>>,
+    /// Keys of the time series with the retention period.
+    retention_keys: HashSet<Vec<u8>>,
+    /// Full-text search indexes by the names.
+    search_indexes: HashMap<Vec<u8>, SearchIndex>,
     /// Worker task handles.
     task_handles: Vec<task::JoinHandle<()>>,
 }
+/// Function to serve the blocked client with the ready key.
+/// Return the response if the client has been served.
+pub(crate) type ServeFn = Box<dyn FnMut(&mut DBManager, &[u8]) -> Option<Data> + Send + Sync>;
+/// Client blocked on keys.
+struct Waiter {
+    /// Keys the client is blocked on.
+    keys: Vec<Vec<u8>>,
+    /// Function to serve the client.
+    serve: ServeFn,
+    /// The channel to send the response.
+    /// It is closed when the client has been cancelled.
+    sender: channel::Sender<Data>,
+}
 /// Redis command option.
 #[derive(PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
 pub(crate) enum SetCondition {
     NX,
     XX,
@@ -54,6 +327,30 @@ pub(crate) async fn close() {
     DB.write().await.close().await;
 }
 
+/// Helper function. Wait until the blocked client is served.
+/// Return None if timed out.
+pub(crate) async fn wait(
+    id: u64,
+    receiver: channel::Receiver<Data>,
+    timeout: Option<Duration>,
+) -> Option<Data> {
+    let response = match timeout {
+        Some(timeout) => match future::timeout(timeout, receiver.recv()).await {
+            Ok(response) => response.ok(),
+            Err(_) => None,
+        },
+        None => receiver.recv().await.ok(),
+    };
+    match response {
+        Some(response) => Some(response),
+        None => {
+            DB.write().await.unblock(id);
+            // The client may have been served before it was unblocked.
+            receiver.try_recv().ok()
+        }
+    }
+}
+
 /// Register expiration date to expirations map
 /// If you make it a function, you'll get a borrowing error.
 macro_rules! register_expiration {
@@ -81,7 +378,7 @@ impl DBManager {
     /// run worker.
     async fn run(mut shutdown_event: channel::Receiver<crate::Void>) {
         // The expiration entrys are checked every five seconds.
-        let mut interval = stream::interval(Duration::from_secs(5));
+        let mut interval = async_std::stream::interval(Duration::from_secs(5));
 
         loop {
             select! {
@@ -90,6 +387,12 @@ impl DBManager {
                     if DB.read().await.check_expired() {
                         DB.write().await.remove_expired();
                     }
+                    if DB.read().await.check_cancelled() {
+                        DB.write().await.remove_cancelled();
+                    }
+                    if DB.read().await.check_retention() {
+                        DB.write().await.trim_time_series();
+                    }
                 },
                 // Wait for a shutdown.
                 void = shutdown_event.next().fuse() => match void {
@@ -100,7 +403,7 @@ impl DBManager {
         }
     }
     /// Get the entry.
-    pub(crate) fn get(&self, key: &Vec<u8>) -> Option<&BDEntry> {
+    pub(crate) fn get(&self, key: &[u8]) -> Option<&BDEntry> {
         let entry = self.entries.get(key);
 
         if Self::expierd_opt(entry) {
@@ -109,12 +412,106 @@ impl DBManager {
             entry
         }
     }
+    /// Get the mutable entry.
+    /// The expired entry is removed.
+    pub(crate) fn get_mut(&mut self, key: &[u8]) -> Option<&mut BDEntry> {
+        if Self::expierd_opt(self.entries.get(key)) {
+            self.entries.remove(key);
+            Self::update_search_indexes(&mut self.search_indexes, key, None);
+        }
+        self.entries.get_mut(key)
+    }
+    /// Get the mutable entry, or insert the value if the entry does not exist.
+    pub(crate) fn get_or_insert_with<F>(&mut self, key: Vec<u8>, default: F) -> &mut BDEntry
+    where
+        F: FnOnce() -> Value,
+    {
+        match self.entries.entry(key) {
+            Entry::Occupied(mut entry) => {
+                if Self::expierd(entry.get()) {
+                    // A new entry may serve the blocked clients.
+                    if self.blocked_keys.contains_key(entry.key()) {
+                        self.ready_keys.push_back(entry.key().clone());
+                    }
+                    Self::update_search_indexes(&mut self.search_indexes, entry.key(), None);
+                    *entry.get_mut() = BDEntry {
+                        value: default(),
+                        expiration: None,
+                    };
+                }
+                entry.into_mut()
+            }
+            Entry::Vacant(entry) => {
+                // A new entry may serve the blocked clients.
+                if self.blocked_keys.contains_key(entry.key()) {
+                    self.ready_keys.push_back(entry.key().clone());
+                }
+                entry.insert(BDEntry {
+                    value: default(),
+                    expiration: None,
+                })
+            }
+        }
+    }
+    /// Insert the value, replacing the existing entry of any kind.
+    /// The empty collection is not inserted and removes the existing entry.
+    pub(crate) fn insert(&mut self, key: Vec<u8>, value: Value) {
+        if value.is_empty() {
+            self.entries.remove(&key);
+            Self::update_search_indexes(&mut self.search_indexes, &key, None);
+            return;
+        }
+        // A new entry may serve the blocked clients.
+        if self.blocked_keys.contains_key(&key) {
+            self.ready_keys.push_back(key.clone());
+        }
+        Self::update_search_indexes(&mut self.search_indexes, &key, Some(&value));
+        self.entries.insert(
+            key,
+            BDEntry {
+                value,
+                expiration: None,
+            },
+        );
+    }
+    /// Update the search indexes after the value of the key has been changed in place.
+    pub(crate) fn reindex(&mut self, key: &[u8]) {
+        let value = self
+            .entries
+            .get(key)
+            .filter(|entry| !Self::expierd(entry))
+            .map(|entry| &entry.value);
+        Self::update_search_indexes(&mut self.search_indexes, key, value);
+    }
+    /// Mark the key as ready after its value has grown in place.
+    pub(crate) fn signal_ready(&mut self, key: &[u8]) {
+        if self.blocked_keys.contains_key(key) {
+            self.ready_keys.push_back(key.to_vec());
+        }
+    }
+    /// Remove the entry if its collection has become empty.
+    pub(crate) fn remove_if_empty(&mut self, key: &[u8]) {
+        if let Some(entry) = self.entries.get(key) {
+            if entry.value.is_empty() {
+                self.entries.remove(key);
+            }
+        }
+    }
+    /// All the time series with the keys.
+    pub(crate) fn time_series(&self) -> impl Iterator<Item = (&[u8], &TimeSeries)> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| !Self::expierd(entry))
+            .filter_map(|(key, entry)| match &entry.value {
+                Value::TimeSeries(series) => Some((key.as_slice(), series)),
+                _ => None,
+            })
+    }
     /// Get the value.
-    pub(crate) fn get_value(&self, key: &Vec<u8>) -> Option<Vec<u8>> {
-        let entry = self.get(key);
-        match entry {
-            Some(entry) => Some(entry.value.clone()),
-            None => None,
+    pub(crate) fn get_value(&self, key: &[u8]) -> crate::Result<Option<Vec<u8>>> {
+        match self.get(key) {
+            Some(entry) => Ok(Some(entry.value.as_string()?.into_owned())),
+            None => Ok(None),
         }
     }
     /// Set value with options
@@ -122,39 +519,60 @@ impl DBManager {
         &mut self,
         key: Vec<u8>,
         value: Vec<u8>,
-        expiration: Option<Instant>,
+        expiration: Option<u64>,
         set_condition: SetCondition,
         keep_ttl: bool,
         get_value: bool,
-    ) -> Option<Vec<u8>> {
+    ) -> crate::Result<Option<Vec<u8>>> {
         match self.entries.entry(key) {
             Entry::Occupied(mut entry) => {
                 let expierd = Self::expierd(entry.get());
 
-                let old_value;
-                if get_value && !expierd {
-                    old_value = Some(entry.get().value.clone());
+                let old_value = if get_value && !expierd {
+                    Some(entry.get().value.as_string()?.into_owned())
                 } else {
-                    old_value = None;
-                }
-                if set_condition != SetCondition::NX {
+                    None
+                };
+                // The expired entry does not exist.
+                let apply = match set_condition {
+                    SetCondition::NX => expierd,
+                    SetCondition::XX => !expierd,
+                    _ => true,
+                };
+                if apply {
                     if keep_ttl && !expierd {
-                        entry.get_mut().value = value;
+                        entry.get_mut().value = Value::string(value);
                     } else {
                         //　Register expiration date.
                         register_expiration!(self, entry.key().clone(), expiration);
-                        *entry.get_mut() = BDEntry { value, expiration };
+                        *entry.get_mut() = BDEntry {
+                            value: Value::string(value),
+                            expiration,
+                        };
                     }
+                    Self::update_search_indexes(
+                        &mut self.search_indexes,
+                        entry.key(),
+                        Some(&entry.get().value),
+                    );
                 }
-                return old_value;
+                Ok(old_value)
             }
             Entry::Vacant(entry) => {
                 if set_condition != SetCondition::XX {
                     //　Register expiration date.
                     register_expiration!(self, entry.key().clone(), expiration);
-                    entry.insert(BDEntry { value, expiration });
+                    let entry = entry.insert_entry(BDEntry {
+                        value: Value::string(value),
+                        expiration,
+                    });
+                    Self::update_search_indexes(
+                        &mut self.search_indexes,
+                        entry.key(),
+                        Some(&entry.get().value),
+                    );
                 }
-                None
+                Ok(None)
             }
         }
     }
@@ -163,6 +581,7 @@ impl DBManager {
         match self.entries.entry(key) {
             Entry::Occupied(entry) => {
                 let deleted = !Self::expierd(entry.get());
+                Self::update_search_indexes(&mut self.search_indexes, entry.key(), None);
                 entry.remove();
                 deleted
             }
@@ -184,63 +603,113 @@ impl DBManager {
         }
     }
     /// Append the value to the entry.
-    pub(crate) fn append(&mut self, key: Vec<u8>, mut value: Vec<u8>) -> usize {
+    pub(crate) fn append(&mut self, key: Vec<u8>, mut value: Vec<u8>) -> crate::Result<usize> {
         match self.entries.entry(key) {
             Entry::Occupied(mut entry) => {
                 let expierd = Self::expierd(entry.get());
                 if expierd {
                     *entry.get_mut() = BDEntry {
-                        value,
+                        value: Value::String(value),
                         expiration: None,
                     };
                 } else {
-                    entry.get_mut().value.append(&mut value);
+                    entry.get_mut().value.as_string_mut()?.append(&mut value);
                 }
-                entry.get().value.len()
+                Self::update_search_indexes(
+                    &mut self.search_indexes,
+                    entry.key(),
+                    Some(&entry.get().value),
+                );
+                Ok(entry.get().value.as_string()?.len())
             }
             Entry::Vacant(entry) => {
                 let len = value.len();
-                entry.insert(BDEntry {
-                    value,
+                let entry = entry.insert_entry(BDEntry {
+                    value: Value::String(value),
                     expiration: None,
                 });
-                len
+                Self::update_search_indexes(
+                    &mut self.search_indexes,
+                    entry.key(),
+                    Some(&entry.get().value),
+                );
+                Ok(len)
             }
         }
     }
+    /// Overwrite the value of the entry at the offset.
+    /// The value is padded with zero bytes if it is shorter than the offset.
+    /// The expiration date is kept.
+    pub(crate) fn setrange(
+        &mut self,
+        key: Vec<u8>,
+        offset: usize,
+        value: &[u8],
+    ) -> crate::Result<usize> {
+        let string = self
+            .get_or_insert_with(key.clone(), || Value::String(Vec::new()))
+            .value
+            .as_string_mut()?;
+        if value.is_empty() {
+            return Ok(string.len());
+        }
+        let end = offset + value.len();
+        if string.len() < end {
+            string.resize(end, 0);
+        }
+        string[offset..end].copy_from_slice(value);
+        let len = string.len();
+        self.reindex(&key);
+        Ok(len)
+    }
+    /// Set or clear the bit at the offset, padding the string with zeros.
+    /// Return the original bit.
+    pub(crate) fn setbit(&mut self, key: Vec<u8>, offset: usize, bit: bool) -> crate::Result<bool> {
+        let string = self
+            .get_or_insert_with(key.clone(), || Value::String(Vec::new()))
+            .value
+            .as_string_mut()?;
+        if string.len() <= offset / 8 {
+            string.resize(offset / 8 + 1, 0);
+        }
+        let original = bitmap::setbit(string, offset, bit);
+        self.reindex(&key);
+        Ok(original)
+    }
     /// Get the value with options.
     pub(crate) fn getex(
         &mut self,
         key: Vec<u8>,
-        expiration: Option<Instant>,
+        expiration: Option<u64>,
         persist: bool,
-    ) -> Option<Vec<u8>> {
+    ) -> crate::Result<Option<Vec<u8>>> {
         match self.entries.entry(key) {
             Entry::Occupied(mut entry) => {
                 let expierd = Self::expierd(entry.get());
                 if expierd {
-                    None
+                    Ok(None)
                 } else {
+                    let value = entry.get().value.as_string()?.into_owned();
                     if persist {
                         entry.get_mut().expiration = None;
                     } else {
                         // Register expiration date.
                         register_expiration!(self, entry.key().clone(), expiration);
-                        if let Some(_) = expiration {
+                        if expiration.is_some() {
                             entry.get_mut().expiration = expiration;
                         }
                     }
-                    Some(entry.get().value.clone())
+                    Ok(Some(value))
                 }
             }
-            Entry::Vacant(_) => None,
+            Entry::Vacant(_) => Ok(None),
         }
     }
     /// Set the expiration date for the entry.
     pub(crate) fn expire(
         &mut self,
         key: Vec<u8>,
-        expiration: Option<Instant>,
+        expiration: Option<u64>,
         set_condition: SetCondition,
     ) -> bool {
         match self.entries.entry(key) {
@@ -273,7 +742,10 @@ impl DBManager {
                             }
                         }
                         SetCondition::GT => {
-                            if expiration > entry.get().expiration {
+                            // No expiration date is treated as an infinite TTL.
+                            if expiration.unwrap_or(u64::MAX)
+                                > entry.get().expiration.unwrap_or(u64::MAX)
+                            {
                                 // Register expiration date.
                                 register_expiration!(self, entry.key().clone(), expiration);
                                 entry.get_mut().expiration = expiration;
@@ -283,7 +755,9 @@ impl DBManager {
                             }
                         }
                         SetCondition::LT => {
-                            if expiration < entry.get().expiration {
+                            if expiration.unwrap_or(u64::MAX)
+                                < entry.get().expiration.unwrap_or(u64::MAX)
+                            {
                                 // Register expiration date.
                                 register_expiration!(self, entry.key().clone(), expiration);
                                 entry.get_mut().expiration = expiration;
@@ -304,6 +778,83 @@ impl DBManager {
             Entry::Vacant(_) => false,
         }
     }
+    /// Block the client on the keys.
+    /// Return the ID of the blocked client and the channel to receive the response.
+    pub(crate) fn block(
+        &mut self,
+        keys: Vec<Vec<u8>>,
+        serve: ServeFn,
+    ) -> (u64, channel::Receiver<Data>) {
+        let id = self.waiter_id;
+        self.waiter_id = self.waiter_id.wrapping_add(1);
+
+        let mut unique_keys: Vec<Vec<u8>> = Vec::with_capacity(keys.len());
+        for key in keys {
+            if !unique_keys.contains(&key) {
+                self.blocked_keys.entry(key.clone()).or_default().push_back(id);
+                unique_keys.push(key);
+            }
+        }
+        let (sender, receiver) = channel::bounded(1);
+        self.waiters.insert(
+            id,
+            Waiter {
+                keys: unique_keys,
+                serve,
+                sender,
+            },
+        );
+        (id, receiver)
+    }
+    /// Unblock the client.
+    pub(crate) fn unblock(&mut self, id: u64) {
+        if let Some(waiter) = self.waiters.remove(&id) {
+            for key in waiter.keys {
+                if let Entry::Occupied(mut ids) = self.blocked_keys.entry(key) {
+                    ids.get_mut().retain(|blocked_id| *blocked_id != id);
+                    if ids.get().is_empty() {
+                        ids.remove();
+                    }
+                }
+            }
+        }
+    }
+    /// Serve the clients blocked on the ready keys.
+    /// Call this before releasing the lock when the command may create keys.
+    pub(crate) fn serve_blocked(&mut self) {
+        while let Some(key) = self.ready_keys.pop_front() {
+            let ids = match self.blocked_keys.get(&key) {
+                Some(ids) => ids.clone(),
+                None => continue,
+            };
+            // Serve the clients in the order they were blocked.
+            for id in ids {
+                if self.get(&key).is_none() {
+                    break;
+                }
+                let mut waiter = match self.waiters.remove(&id) {
+                    Some(waiter) => waiter,
+                    None => continue,
+                };
+                if waiter.sender.is_closed() {
+                    self.waiters.insert(id, waiter);
+                    self.unblock(id);
+                    continue;
+                }
+                match (waiter.serve)(self, &key) {
+                    Some(response) => {
+                        // The channel has room for the only response.
+                        let _ = waiter.sender.try_send(response);
+                        self.waiters.insert(id, waiter);
+                        self.unblock(id);
+                    }
+                    None => {
+                        self.waiters.insert(id, waiter);
+                    }
+                }
+            }
+        }
+    }
     /// Expired or not.
     fn expierd_opt(entry: Option<&BDEntry>) -> bool {
         if let Some(entry) = entry {
@@ -314,7 +865,7 @@ impl DBManager {
     /// Expired or not.
     fn expierd(entry: &BDEntry) -> bool {
         if let Some(expiration) = entry.expiration {
-            expiration < Instant::now()
+            expiration < unix_time_ms()
         } else {
             false
         }
@@ -322,25 +873,108 @@ impl DBManager {
     /// Check if there are any expired entries.
     fn check_expired(&self) -> bool {
         if let Some((&(when, _), _)) = self.expirations.iter().next() {
-            when < Instant::now()
+            when < unix_time_ms()
         } else {
             false
         }
     }
+    /// Check if there are any cancelled clients.
+    fn check_cancelled(&self) -> bool {
+        self.waiters.values().any(|waiter| waiter.sender.is_closed())
+    }
+    /// Remove the cancelled clients.
+    fn remove_cancelled(&mut self) {
+        let ids: Vec<u64> = self
+            .waiters
+            .iter()
+            .filter(|(_, waiter)| waiter.sender.is_closed())
+            .map(|(id, _)| *id)
+            .collect();
+        for id in ids {
+            self.unblock(id);
+        }
+    }
+    /// Check if there are any time series with the retention period.
+    fn check_retention(&self) -> bool {
+        !self.retention_keys.is_empty()
+    }
+    /// Register the time series to trim the samples older than the retention period.
+    pub(crate) fn register_retention(&mut self, key: &[u8]) {
+        self.retention_keys.insert(key.to_vec());
+    }
+    /// Trim the samples older than the retention period.
+    /// The keys no longer holding the time series are unregistered.
+    fn trim_time_series(&mut self) {
+        let entries = &mut self.entries;
+        self.retention_keys
+            .retain(|key| match entries.get_mut(key).map(|entry| &mut entry.value) {
+                Some(Value::TimeSeries(series)) if series.retention() > 0 => {
+                    series.trim();
+                    true
+                }
+                _ => false,
+            });
+    }
+    /// Get the full-text search index.
+    pub(crate) fn search_index(&self, name: &[u8]) -> Option<&SearchIndex> {
+        self.search_indexes.get(name)
+    }
+    /// Create the full-text search index, indexing the existing keys.
+    /// Return false if the index already exists.
+    pub(crate) fn create_search_index(&mut self, name: Vec<u8>, mut index: SearchIndex) -> bool {
+        if self.search_indexes.contains_key(&name) {
+            return false;
+        }
+        for (key, entry) in &self.entries {
+            if !Self::expierd(entry) && index.matches(key) {
+                if let Ok(value) = entry.value.as_string() {
+                    index.update(key, Some(&value));
+                }
+            }
+        }
+        self.search_indexes.insert(name, index);
+        true
+    }
+    /// Drop the full-text search index.
+    pub(crate) fn drop_search_index(&mut self, name: &[u8]) -> Option<SearchIndex> {
+        self.search_indexes.remove(name)
+    }
+    /// Update the search indexes of the key with the new value, or remove the key without the value.
+    /// The indexes are taken apart so that it can be called while the entry is borrowed.
+    fn update_search_indexes(
+        indexes: &mut HashMap<Vec<u8>, SearchIndex>,
+        key: &[u8],
+        value: Option<&Value>,
+    ) {
+        if indexes.is_empty() {
+            return;
+        }
+        // Only the strings are indexed.
+        let value = value.and_then(|value| value.as_string().ok());
+        for index in indexes.values_mut() {
+            if index.matches(key) {
+                index.update(key, value.as_deref());
+            }
+        }
+    }
     /// Remove the expired entries.
     fn remove_expired(&mut self) {
         while let Some((&(when, id), key)) = self.expirations.iter().next() {
-            if when > Instant::now() {
+            if when > unix_time_ms() {
                 break;
             }
             match self.entries.entry(key.clone()) {
                 Entry::Occupied(entry) => {
                     // Because the expiration date may have been updated.
                     if Self::expierd(entry.get()) {
+                        Self::update_search_indexes(&mut self.search_indexes, entry.key(), None);
                         entry.remove();
                     }
                 }
-                Entry::Vacant(_) => {}
+                // The expired entry may have been removed on access.
+                Entry::Vacant(entry) => {
+                    Self::update_search_indexes(&mut self.search_indexes, entry.key(), None);
+                }
             }
             self.expirations.remove(&(when, id));
         }
@@ -0,0 +1,361 @@
+//! Filter expression on the JSON attributes of the vector set elements.
+//!
+//! `.name` selects the member of the attributes.
+//! The expression supports the number, string, boolean, null and array literals,
+//! the arithmetic operators `+ - * / % **`, the comparisons `== != < <= > >=`,
+//! `in` for the membership of the array or the substring,
+//! and the logical operators `and`/`&&`, `or`/`||` and `not`/`!`.
+//! Elements without the attributes or the selected members do not match.
+//!
+use super::json::Json;
+
+/// Maximum nesting of the expressions.
+const MAX_DEPTH: usize = 128;
+
+/// Binary operator.
+#[derive(Clone, Copy)]
+enum Operator {
+    Or,
+    And,
+    Eq,
+    Ne,
+    In,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+/// Operators in ascending order of the precedence.
+/// `**` is right associative and parsed apart.
+const LEVELS: [&[(&[u8], Operator)]; 6] = [
+    &[(b"||", Operator::Or), (b"or", Operator::Or)],
+    &[(b"&&", Operator::And), (b"and", Operator::And)],
+    &[
+        (b"==", Operator::Eq),
+        (b"!=", Operator::Ne),
+        (b"in", Operator::In),
+    ],
+    &[
+        (b"<=", Operator::Le),
+        (b">=", Operator::Ge),
+        (b"<", Operator::Lt),
+        (b">", Operator::Gt),
+    ],
+    &[(b"+", Operator::Add), (b"-", Operator::Sub)],
+    &[
+        (b"*", Operator::Mul),
+        (b"/", Operator::Div),
+        (b"%", Operator::Rem),
+    ],
+];
+
+/// Expression node.
+enum Expression {
+    Literal(Json),
+    Selector(String),
+    Array(Vec<Expression>),
+    Not(Box<Expression>),
+    Negate(Box<Expression>),
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+/// Expression parser.
+struct Parser<'a> {
+    text: &'a [u8],
+    position: usize,
+    /// Nesting of the expressions being parsed.
+    depth: usize,
+}
+
+/// The byte is a part of the names and the keywords.
+fn is_word(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+    /// Consume the symbol or the keyword. The keyword must not continue with a word byte.
+    fn expect(&mut self, token: &[u8]) -> bool {
+        if !self.text[self.position..].starts_with(token) {
+            return false;
+        }
+        let next = self.text.get(self.position + token.len()).copied();
+        if is_word(token[0]) && next.is_some_and(is_word) {
+            return false;
+        }
+        // Keep `**` for the power, and `!=` for the inequality.
+        if (token == b"*" && next == Some(b'*')) || (token == b"!" && next == Some(b'=')) {
+            return false;
+        }
+        self.position += token.len();
+        true
+    }
+    /// Parse the binary operators of the precedence level and above.
+    fn parse_binary(&mut self, level: usize) -> Option<Expression> {
+        if level == LEVELS.len() {
+            return self.parse_unary();
+        }
+        let mut expression = self.parse_binary(level + 1)?;
+        'outer: loop {
+            self.skip_whitespace();
+            for &(token, operator) in LEVELS[level] {
+                if self.expect(token) {
+                    let right = self.parse_binary(level + 1)?;
+                    expression =
+                        Expression::Binary(Box::new(expression), operator, Box::new(right));
+                    continue 'outer;
+                }
+            }
+            return Some(expression);
+        }
+    }
+    /// Every nested expression is parsed here, so the depth is limited here.
+    fn parse_unary(&mut self) -> Option<Expression> {
+        if self.depth >= MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let expression = self.parse_factor();
+        self.depth -= 1;
+        expression
+    }
+    fn parse_factor(&mut self) -> Option<Expression> {
+        self.skip_whitespace();
+        if self.expect(b"!") || self.expect(b"not") {
+            return Some(Expression::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.expect(b"-") {
+            return Some(Expression::Negate(Box::new(self.parse_unary()?)));
+        }
+        let base = self.parse_primary()?;
+        self.skip_whitespace();
+        if self.expect(b"**") {
+            let exponent = self.parse_unary()?;
+            return Some(Expression::Binary(
+                Box::new(base),
+                Operator::Pow,
+                Box::new(exponent),
+            ));
+        }
+        Some(base)
+    }
+    fn parse_primary(&mut self) -> Option<Expression> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'(' => {
+                self.position += 1;
+                let expression = self.parse_binary(0)?;
+                self.skip_whitespace();
+                self.expect(b")").then_some(expression)
+            }
+            b'[' => {
+                self.position += 1;
+                let mut items = Vec::new();
+                self.skip_whitespace();
+                if !self.expect(b"]") {
+                    loop {
+                        items.push(self.parse_binary(0)?);
+                        self.skip_whitespace();
+                        if self.expect(b"]") {
+                            break;
+                        }
+                        if !self.expect(b",") {
+                            return None;
+                        }
+                    }
+                }
+                Some(Expression::Array(items))
+            }
+            b'.' => {
+                self.position += 1;
+                let name = self.parse_word();
+                if name.is_empty() {
+                    return None;
+                }
+                Some(Expression::Selector(name))
+            }
+            b'\'' | b'"' => Some(Expression::Literal(Json::String(self.parse_quoted()?))),
+            b'0'..=b'9' => {
+                let start = self.position;
+                while let Some(b'.' | b'0'..=b'9' | b'e' | b'E') = self.peek() {
+                    let exponent = matches!(self.peek(), Some(b'e' | b'E'));
+                    self.position += 1;
+                    if exponent && matches!(self.peek(), Some(b'+' | b'-')) {
+                        self.position += 1;
+                    }
+                }
+                let number = std::str::from_utf8(&self.text[start..self.position]).ok()?;
+                Some(Expression::Literal(Json::Float(number.parse().ok()?)))
+            }
+            _ => match self.parse_word().as_str() {
+                "true" => Some(Expression::Literal(Json::Bool(true))),
+                "false" => Some(Expression::Literal(Json::Bool(false))),
+                "null" => Some(Expression::Literal(Json::Null)),
+                _ => None,
+            },
+        }
+    }
+    fn parse_word(&mut self) -> String {
+        let start = self.position;
+        while self.peek().is_some_and(is_word) {
+            self.position += 1;
+        }
+        String::from_utf8_lossy(&self.text[start..self.position]).into_owned()
+    }
+    /// Parse the string quoted with the single or double quotes.
+    fn parse_quoted(&mut self) -> Option<String> {
+        let quote = self.peek()?;
+        self.position += 1;
+        let mut bytes = Vec::new();
+        loop {
+            match self.peek()? {
+                byte if byte == quote => break,
+                b'\\' => {
+                    self.position += 1;
+                    bytes.push(self.peek()?);
+                }
+                byte => bytes.push(byte),
+            }
+            self.position += 1;
+        }
+        self.position += 1;
+        String::from_utf8(bytes).ok()
+    }
+}
+
+/// Truth of the value.
+fn is_true(value: &Json) -> bool {
+    match value {
+        Json::Null => false,
+        Json::Bool(bool) => *bool,
+        Json::Integer(integer) => *integer != 0,
+        Json::Float(float) => *float != 0.0,
+        Json::String(string) => !string.is_empty(),
+        Json::Array(array) => !array.is_empty(),
+        Json::Object(_) => true,
+    }
+}
+
+/// Equality of the values. The integers and floats are compared by the numbers.
+fn is_equal(left: &Json, right: &Json) -> bool {
+    match (left.as_f64(), right.as_f64()) {
+        (Some(left), Some(right)) => left == right,
+        _ => left == right,
+    }
+}
+
+impl Expression {
+    /// Evaluate the expression. Return None for the missing members and the type mismatches.
+    fn evaluate(&self, attributes: &Json) -> Option<Json> {
+        match self {
+            Expression::Literal(value) => Some(value.clone()),
+            Expression::Selector(name) => attributes.member(name).cloned(),
+            Expression::Array(items) => Some(Json::Array(
+                items
+                    .iter()
+                    .map(|item| item.evaluate(attributes))
+                    .collect::<Option<_>>()?,
+            )),
+            Expression::Not(operand) => Some(Json::Bool(!is_true(&operand.evaluate(attributes)?))),
+            Expression::Negate(operand) => {
+                Some(Json::Float(-operand.evaluate(attributes)?.as_f64()?))
+            }
+            Expression::Binary(left, Operator::Or, right) => Some(Json::Bool(
+                left.evaluate(attributes)
+                    .is_some_and(|value| is_true(&value))
+                    || right
+                        .evaluate(attributes)
+                        .is_some_and(|value| is_true(&value)),
+            )),
+            Expression::Binary(left, Operator::And, right) => Some(Json::Bool(
+                left.evaluate(attributes)
+                    .is_some_and(|value| is_true(&value))
+                    && right
+                        .evaluate(attributes)
+                        .is_some_and(|value| is_true(&value)),
+            )),
+            Expression::Binary(left, operator, right) => {
+                let left = left.evaluate(attributes)?;
+                let right = right.evaluate(attributes)?;
+                match operator {
+                    Operator::Eq => Some(Json::Bool(is_equal(&left, &right))),
+                    Operator::Ne => Some(Json::Bool(!is_equal(&left, &right))),
+                    Operator::In => match (&left, &right) {
+                        (_, Json::Array(array)) => {
+                            Some(Json::Bool(array.iter().any(|item| is_equal(&left, item))))
+                        }
+                        (Json::String(needle), Json::String(haystack)) => {
+                            Some(Json::Bool(haystack.contains(needle.as_str())))
+                        }
+                        _ => None,
+                    },
+                    Operator::Lt | Operator::Le | Operator::Gt | Operator::Ge => {
+                        let ordering = match (&left, &right) {
+                            (Json::String(left), Json::String(right)) => left.partial_cmp(right),
+                            _ => left.as_f64()?.partial_cmp(&right.as_f64()?),
+                        }?;
+                        Some(Json::Bool(match operator {
+                            Operator::Lt => ordering.is_lt(),
+                            Operator::Le => ordering.is_le(),
+                            Operator::Gt => ordering.is_gt(),
+                            _ => ordering.is_ge(),
+                        }))
+                    }
+                    _ => {
+                        let (left, right) = (left.as_f64()?, right.as_f64()?);
+                        Some(Json::Float(match operator {
+                            Operator::Add => left + right,
+                            Operator::Sub => left - right,
+                            Operator::Mul => left * right,
+                            Operator::Div => left / right,
+                            Operator::Rem => left % right,
+                            _ => left.powf(right),
+                        }))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parsed filter expression.
+pub(crate) struct Filter {
+    expression: Expression,
+}
+
+impl Filter {
+    /// Parse the filter expression. Return None if it is invalid.
+    pub(crate) fn parse(text: &[u8]) -> Option<Self> {
+        let mut parser = Parser {
+            text,
+            position: 0,
+            depth: 0,
+        };
+        let expression = parser.parse_binary(0)?;
+        parser.skip_whitespace();
+        if parser.position < text.len() {
+            return None;
+        }
+        Some(Filter { expression })
+    }
+    /// The attributes satisfy the expression.
+    pub(crate) fn matches(&self, attributes: Option<&Json>) -> bool {
+        attributes
+            .and_then(|attributes| self.expression.evaluate(attributes))
+            .is_some_and(|value| is_true(&value))
+    }
+}
@@ -0,0 +1,178 @@
+//! Bitmap operations on string values.
+//!
+//! Bit offsets count from the most significant bit of the first byte.
+//!
+
+/// Bitwise operation between strings.
+#[derive(Clone, Copy)]
+pub(crate) enum BitOperation {
+    And,
+    Or,
+    Xor,
+    Not,
+}
+
+/// Overflow behavior of the bit fields.
+#[derive(Clone, Copy)]
+pub(crate) enum Overflow {
+    Wrap,
+    Sat,
+    Fail,
+}
+
+/// Mask of the bits before the bit offset in its byte.
+fn leading_mask(offset: usize) -> u8 {
+    !(0xff >> (offset % 8))
+}
+/// Mask of the bits after the bit offset in its byte.
+fn trailing_mask(offset: usize) -> u8 {
+    0x7f >> (offset % 8)
+}
+
+/// Get the bit at the offset.
+/// Bits beyond the end of the string are zero.
+pub(crate) fn getbit(bytes: &[u8], offset: usize) -> bool {
+    match bytes.get(offset / 8) {
+        Some(byte) => byte & (0x80 >> (offset % 8)) != 0,
+        None => false,
+    }
+}
+
+/// Set or clear the bit at the offset.
+/// Return the original bit.
+pub(crate) fn setbit(bytes: &mut [u8], offset: usize, bit: bool) -> bool {
+    let mask = 0x80 >> (offset % 8);
+    let byte = &mut bytes[offset / 8];
+    let original = *byte & mask != 0;
+    if bit {
+        *byte |= mask;
+    } else {
+        *byte &= !mask;
+    }
+    original
+}
+
+/// Count the set bits, eight bytes at a time.
+fn popcount(bytes: &[u8]) -> usize {
+    let mut chunks = bytes.chunks_exact(8);
+    let count: usize = chunks
+        .by_ref()
+        .map(|chunk| u64::from_ne_bytes(chunk.try_into().unwrap()).count_ones() as usize)
+        .sum();
+    count
+        + chunks
+            .remainder()
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum::<usize>()
+}
+
+/// Count the set bits between the start and end bit offsets, inclusive.
+/// The offsets must be within the string.
+pub(crate) fn bitcount(bytes: &[u8], start: usize, end: usize) -> usize {
+    let (first, last) = (start / 8, end / 8);
+    let count = popcount(&bytes[first..=last]);
+    // Exclude the bits out of the range in the first and last bytes.
+    count
+        - (bytes[first] & leading_mask(start)).count_ones() as usize
+        - (bytes[last] & trailing_mask(end)).count_ones() as usize
+}
+
+/// Find the first bit with the value between the start and end bit offsets, inclusive.
+/// The offsets must be within the string.
+pub(crate) fn bitpos(bytes: &[u8], bit: bool, start: usize, end: usize) -> Option<usize> {
+    let (first, last) = (start / 8, end / 8);
+    // Skip the bytes without the bit quickly.
+    let skip = if bit { 0x00 } else { 0xff };
+    (first..=last)
+        .filter(|&index| index == first || index == last || bytes[index] != skip)
+        .find_map(|index| {
+            // Look for the set bit.
+            let mut byte = if bit { bytes[index] } else { !bytes[index] };
+            if index == first {
+                byte &= !leading_mask(start);
+            }
+            if index == last {
+                byte &= !trailing_mask(end);
+            }
+            if byte == 0 {
+                None
+            } else {
+                Some(index * 8 + byte.leading_zeros() as usize)
+            }
+        })
+}
+
+/// Compute the bitwise operation between the strings.
+/// Shorter strings are padded with zeros.
+pub(crate) fn bitop(operation: BitOperation, sources: &[&[u8]]) -> Vec<u8> {
+    let len = sources.iter().map(|source| source.len()).max().unwrap_or(0);
+    let mut result = sources[0].to_vec();
+    result.resize(len, 0);
+
+    match operation {
+        BitOperation::Not => result.iter_mut().for_each(|byte| *byte = !*byte),
+        operation => {
+            for source in &sources[1..] {
+                for (index, byte) in result.iter_mut().enumerate() {
+                    let other = source.get(index).copied().unwrap_or(0);
+                    match operation {
+                        BitOperation::And => *byte &= other,
+                        BitOperation::Or => *byte |= other,
+                        _ => *byte ^= other,
+                    }
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Get the integer field of the width at the bit offset.
+/// Bits beyond the end of the string are zero.
+pub(crate) fn get_field(bytes: &[u8], offset: usize, bits: u32, signed: bool) -> i64 {
+    let mut value: u64 = 0;
+    for index in 0..bits as usize {
+        value = (value << 1) | getbit(bytes, offset + index) as u64;
+    }
+    // Extend the sign bit.
+    if signed && bits < 64 && value & (1 << (bits - 1)) != 0 {
+        value |= u64::MAX << bits;
+    }
+    value as i64
+}
+
+/// Set the integer field of the width at the bit offset.
+/// The string must contain the field.
+pub(crate) fn set_field(bytes: &mut [u8], offset: usize, bits: u32, value: i64) {
+    for index in 0..bits {
+        let bit = (value as u64 >> (bits - 1 - index)) & 1 != 0;
+        setbit(bytes, offset + index as usize, bit);
+    }
+}
+
+/// Fit the value into the range of the field.
+/// Return None if it overflows with FAIL.
+pub(crate) fn fit_field(value: i128, bits: u32, signed: bool, overflow: Overflow) -> Option<i64> {
+    let (min, max) = if signed {
+        (-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+    } else {
+        (0, (1 << bits) - 1)
+    };
+    if (min..=max).contains(&value) {
+        return Some(value as i64);
+    }
+    match overflow {
+        Overflow::Wrap => {
+            let modulus = 1 << bits;
+            let value = value.rem_euclid(modulus);
+            if value > max {
+                Some((value - modulus) as i64)
+            } else {
+                Some(value as i64)
+            }
+        }
+        Overflow::Sat => Some(value.clamp(min, max) as i64),
+        Overflow::Fail => None,
+    }
+}
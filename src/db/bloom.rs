@@ -0,0 +1,150 @@
+//! Scalable Bloom filter value.
+//!
+//! The filter is a chain of Bloom filters. When the last one reaches its capacity,
+//! a larger one with the tighter error rate is added,
+//! so the total error rate stays below the requested one.
+//!
+use super::hyperloglog::murmur_hash64a;
+
+/// Seed of the hash function.
+const SEED: u64 = 0xc6a4_a793_5bd1_e995;
+/// Error rate of each added filter relative to the previous one.
+const TIGHTENING_RATIO: f64 = 0.5;
+/// ln(2)^2
+const LN2_SQUARED: f64 = std::f64::consts::LN_2 * std::f64::consts::LN_2;
+/// Maximum size of each filter in bits.
+const MAX_SIZE: f64 = 512.0 * 1024.0 * 1024.0 * 8.0;
+
+/// Bloom filter with a fixed capacity.
+struct Layer {
+    bits: Vec<u64>,
+    /// Number of the bits.
+    size: u64,
+    /// Number of the hash functions.
+    hashes: u32,
+    capacity: u64,
+    /// Number of the added items.
+    items: u64,
+}
+
+impl Layer {
+    /// Create an empty filter.
+    /// Return None if it is too large.
+    fn new(capacity: u64, error_rate: f64) -> Option<Self> {
+        let bits_per_item = -error_rate.ln() / LN2_SQUARED;
+        let size = (capacity as f64 * bits_per_item).ceil();
+        if size > MAX_SIZE {
+            return None;
+        }
+        let size = (size as u64).max(64);
+        Some(Layer {
+            bits: vec![0; size.div_ceil(64) as usize],
+            size,
+            hashes: (std::f64::consts::LN_2 * bits_per_item).ceil() as u32,
+            capacity,
+            items: 0,
+        })
+    }
+    /// Bit indexes of the item by the double hashing.
+    fn indexes(&self, (h1, h2): (u64, u64)) -> impl Iterator<Item = usize> + '_ {
+        (0..self.hashes as u64)
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % self.size) as usize)
+    }
+    fn contains(&self, hash: (u64, u64)) -> bool {
+        self.indexes(hash)
+            .all(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+    }
+    fn add(&mut self, hash: (u64, u64)) {
+        let indexes: Vec<usize> = self.indexes(hash).collect();
+        for index in indexes {
+            self.bits[index / 64] |= 1 << (index % 64);
+        }
+        self.items += 1;
+    }
+}
+
+/// Scalable Bloom filter value.
+pub(crate) struct BloomFilter {
+    layers: Vec<Layer>,
+    /// Error rate of the last filter.
+    error_rate: f64,
+    /// Capacity multiplier of the added filter. None if the filter doesn't scale.
+    expansion: Option<u64>,
+}
+
+/// Error of adding the item to the full filter.
+pub(crate) struct BloomFilterFull;
+
+impl BloomFilter {
+    /// Error rate of the filter created implicitly.
+    pub(crate) const DEFAULT_ERROR_RATE: f64 = 0.01;
+    /// Capacity of the filter created implicitly.
+    pub(crate) const DEFAULT_CAPACITY: u64 = 100;
+    /// Capacity multiplier of the filter created implicitly.
+    pub(crate) const DEFAULT_EXPANSION: u64 = 2;
+    /// Maximum capacity multiplier, the same as RedisBloom.
+    pub(crate) const MAX_EXPANSION: u64 = 32768;
+
+    /// Create an empty filter.
+    /// Return None if it is too large.
+    pub(crate) fn new(capacity: u64, error_rate: f64, expansion: Option<u64>) -> Option<Self> {
+        let error_rate = error_rate * TIGHTENING_RATIO;
+        Some(BloomFilter {
+            layers: vec![Layer::new(capacity, error_rate)?],
+            error_rate,
+            expansion,
+        })
+    }
+    /// Hash the item.
+    fn hash(item: &[u8]) -> (u64, u64) {
+        let h1 = murmur_hash64a(item, SEED);
+        (h1, murmur_hash64a(item, h1))
+    }
+    /// The item may have been added or not.
+    pub(crate) fn contains(&self, item: &[u8]) -> bool {
+        let hash = Self::hash(item);
+        self.layers.iter().any(|layer| layer.contains(hash))
+    }
+    /// Add the item.
+    /// Return false if the item may have been added already.
+    /// The filter is full if it doesn't scale, or the added filter would be too large.
+    pub(crate) fn add(&mut self, item: &[u8]) -> Result<bool, BloomFilterFull> {
+        let hash = Self::hash(item);
+        if self.layers.iter().any(|layer| layer.contains(hash)) {
+            return Ok(false);
+        }
+        let last = self.layers.last().unwrap();
+        if last.items >= last.capacity {
+            let expansion = self.expansion.ok_or(BloomFilterFull)?;
+            let capacity = last.capacity.saturating_mul(expansion);
+            let layer = Layer::new(capacity, self.error_rate * TIGHTENING_RATIO);
+            self.layers.push(layer.ok_or(BloomFilterFull)?);
+            self.error_rate *= TIGHTENING_RATIO;
+        }
+        self.layers.last_mut().unwrap().add(hash);
+        Ok(true)
+    }
+    /// Total capacity of the filters.
+    pub(crate) fn capacity(&self) -> u64 {
+        self.layers.iter().map(|layer| layer.capacity).sum()
+    }
+    /// Memory size of the filters in bytes.
+    pub(crate) fn size(&self) -> u64 {
+        self.layers
+            .iter()
+            .map(|layer| layer.bits.len() as u64 * 8)
+            .sum()
+    }
+    /// Number of the filters.
+    pub(crate) fn filters(&self) -> usize {
+        self.layers.len()
+    }
+    /// Number of the added items.
+    pub(crate) fn items(&self) -> u64 {
+        self.layers.iter().map(|layer| layer.items).sum()
+    }
+    /// Capacity multiplier of the added filter.
+    pub(crate) fn expansion(&self) -> Option<u64> {
+        self.expansion
+    }
+}
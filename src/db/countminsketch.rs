@@ -0,0 +1,103 @@
+//! Count-Min Sketch value.
+//!
+//! Each row of the counters is indexed by a different hash of the item,
+//! and the smallest counter is the estimated count.
+//! The estimation is never smaller than the real count.
+//!
+use super::hyperloglog::murmur_hash64a;
+
+/// Seed of the hash function.
+const SEED: u64 = 0x5bd1_e995;
+/// Maximum number of the counters.
+const MAX_COUNTERS: u64 = 64 * 1024 * 1024;
+/// Maximum count, which is the largest integer in the response.
+const MAX_COUNT: u64 = i64::MAX as u64;
+
+/// Count-Min Sketch value.
+pub(crate) struct CountMinSketch {
+    width: usize,
+    depth: usize,
+    /// Counters of the rows.
+    counters: Vec<u64>,
+}
+
+impl CountMinSketch {
+    /// Create an empty sketch.
+    /// Return None if it is too large.
+    pub(crate) fn new(width: u64, depth: u64) -> Option<Self> {
+        if width.checked_mul(depth)? > MAX_COUNTERS {
+            return None;
+        }
+        Some(CountMinSketch {
+            width: width as usize,
+            depth: depth as usize,
+            counters: vec![0; (width * depth) as usize],
+        })
+    }
+    /// Create an empty sketch for the error rate of the estimation
+    /// and the probability of the estimation exceeding it.
+    /// Return None if it is too large.
+    pub(crate) fn with_error(error: f64, probability: f64) -> Option<Self> {
+        let width = (2.0 / error).ceil();
+        let depth = (probability.ln() / 0.5_f64.ln()).ceil().max(1.0);
+        if width * depth > MAX_COUNTERS as f64 {
+            return None;
+        }
+        Self::new(width as u64, depth as u64)
+    }
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+    pub(crate) fn depth(&self) -> usize {
+        self.depth
+    }
+    /// Counter indexes of the item by the double hashing.
+    fn indexes(&self, item: &[u8]) -> impl Iterator<Item = usize> + '_ {
+        let h1 = murmur_hash64a(item, SEED);
+        let h2 = murmur_hash64a(item, h1);
+        (0..self.depth).map(move |row| {
+            let hash = h1.wrapping_add((row as u64).wrapping_mul(h2));
+            row * self.width + (hash % self.width as u64) as usize
+        })
+    }
+    /// Increase the count of the item and return the estimated count.
+    /// The counters saturate at the maximum count.
+    pub(crate) fn incr_by(&mut self, item: &[u8], increment: u64) -> u64 {
+        let indexes: Vec<usize> = self.indexes(item).collect();
+        indexes
+            .into_iter()
+            .map(|index| {
+                let counter = &mut self.counters[index];
+                *counter = counter.saturating_add(increment).min(MAX_COUNT);
+                *counter
+            })
+            .min()
+            .unwrap_or(0)
+    }
+    /// Estimated count of the item.
+    pub(crate) fn query(&self, item: &[u8]) -> u64 {
+        self.indexes(item)
+            .map(|index| self.counters[index])
+            .min()
+            .unwrap_or(0)
+    }
+    /// Weighted sum of the sketches.
+    /// All the sketches have the same dimensions as the first one.
+    /// The counters are clamped between zero and the maximum count.
+    pub(crate) fn merge(sketches: &[(&CountMinSketch, i64)]) -> CountMinSketch {
+        let (first, _) = sketches[0];
+        CountMinSketch {
+            width: first.width,
+            depth: first.depth,
+            counters: (0..first.counters.len())
+                .map(|index| {
+                    let sum: i128 = sketches
+                        .iter()
+                        .map(|(sketch, weight)| sketch.counters[index] as i128 * *weight as i128)
+                        .sum();
+                    sum.clamp(0, MAX_COUNT as i128) as u64
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Cuckoo filter value.
+//!
+//! Each item is stored as an 8-bit fingerprint in one of its two buckets.
+//! The other bucket is derived from the bucket and the fingerprint,
+//! so the fingerprints can be moved between the buckets without the items.
+//! When an item can't find a place, a filter twice as large is added.
+//!
+use super::hyperloglog::murmur_hash64a;
+use crate::random;
+
+/// Seed of the hash function.
+const SEED: u64 = 0x9747_b28c;
+/// Number of the fingerprints in each bucket.
+const BUCKET_SIZE: usize = 2;
+/// Maximum number of the relocations before adding a filter.
+const MAX_ITERATIONS: usize = 20;
+/// Empty slot of the bucket.
+const EMPTY: u8 = 0;
+
+/// Cuckoo filter with a fixed number of buckets.
+struct Layer {
+    buckets: Vec<[u8; BUCKET_SIZE]>,
+}
+
+impl Layer {
+    fn new(buckets: usize) -> Self {
+        Layer {
+            buckets: vec![[EMPTY; BUCKET_SIZE]; buckets],
+        }
+    }
+    /// Index of the bucket.
+    /// The number of the buckets is a power of two, so `alternate` is its own inverse.
+    fn index(&self, hash: u64) -> usize {
+        (hash & (self.buckets.len() as u64 - 1)) as usize
+    }
+    /// The other bucket of the fingerprint.
+    fn alternate(&self, index: usize, fingerprint: u8) -> usize {
+        self.index(index as u64 ^ (fingerprint as u64).wrapping_mul(0x5bd1_e995))
+    }
+    /// Both buckets of the item.
+    fn candidates(&self, hash: u64, fingerprint: u8) -> (usize, usize) {
+        let index = self.index(hash);
+        (index, self.alternate(index, fingerprint))
+    }
+    /// Put the fingerprint in the free slot of the bucket.
+    fn put(&mut self, index: usize, fingerprint: u8) -> bool {
+        match self.buckets[index].iter_mut().find(|slot| **slot == EMPTY) {
+            Some(slot) => {
+                *slot = fingerprint;
+                true
+            }
+            None => false,
+        }
+    }
+    /// Count the fingerprint in the buckets of the item.
+    fn count(&self, hash: u64, fingerprint: u8) -> usize {
+        let (first, second) = self.candidates(hash, fingerprint);
+        let count = |index: usize| {
+            self.buckets[index]
+                .iter()
+                .filter(|&&slot| slot == fingerprint)
+                .count()
+        };
+        if first == second {
+            count(first)
+        } else {
+            count(first) + count(second)
+        }
+    }
+    /// Remove a fingerprint from the buckets of the item.
+    fn remove(&mut self, hash: u64, fingerprint: u8) -> bool {
+        let (first, second) = self.candidates(hash, fingerprint);
+        for index in [first, second] {
+            if let Some(slot) = self.buckets[index]
+                .iter_mut()
+                .find(|slot| **slot == fingerprint)
+            {
+                *slot = EMPTY;
+                return true;
+            }
+        }
+        false
+    }
+    /// Insert the fingerprint, relocating the other fingerprints if needed.
+    /// Return false without any change if the filter is full.
+    fn insert(&mut self, hash: u64, fingerprint: u8) -> bool {
+        let (first, second) = self.candidates(hash, fingerprint);
+        if self.put(first, fingerprint) || self.put(second, fingerprint) {
+            return true;
+        }
+        let mut index = if random::below(2) == 0 { first } else { second };
+        let mut fingerprint = fingerprint;
+        let mut swaps = Vec::with_capacity(MAX_ITERATIONS);
+        for _ in 0..MAX_ITERATIONS {
+            // Swap with a random victim and move it to its other bucket.
+            let slot = random::below(BUCKET_SIZE);
+            std::mem::swap(&mut fingerprint, &mut self.buckets[index][slot]);
+            swaps.push((index, slot));
+            index = self.alternate(index, fingerprint);
+            if self.put(index, fingerprint) {
+                return true;
+            }
+        }
+        // Put the victims back.
+        for (index, slot) in swaps.into_iter().rev() {
+            std::mem::swap(&mut fingerprint, &mut self.buckets[index][slot]);
+        }
+        false
+    }
+}
+
+/// Cuckoo filter value.
+pub(crate) struct CuckooFilter {
+    layers: Vec<Layer>,
+}
+
+impl CuckooFilter {
+    /// Capacity of the filter created implicitly.
+    pub(crate) const DEFAULT_CAPACITY: u64 = 1024;
+
+    /// Create an empty filter.
+    pub(crate) fn new(capacity: u64) -> Self {
+        let buckets = (capacity as usize)
+            .div_ceil(BUCKET_SIZE)
+            .next_power_of_two();
+        CuckooFilter {
+            layers: vec![Layer::new(buckets)],
+        }
+    }
+    /// Hash and fingerprint of the item.
+    fn hash(item: &[u8]) -> (u64, u8) {
+        let hash = murmur_hash64a(item, SEED);
+        // The fingerprint is never EMPTY.
+        (hash, (hash >> 56) as u8 % 255 + 1)
+    }
+    /// Add the item.
+    pub(crate) fn add(&mut self, item: &[u8]) {
+        let (hash, fingerprint) = Self::hash(item);
+        // Use the free slot of the older filters first.
+        let free = self.layers.iter_mut().any(|layer| {
+            let (first, second) = layer.candidates(hash, fingerprint);
+            layer.put(first, fingerprint) || layer.put(second, fingerprint)
+        });
+        if !free && !self.layers.last_mut().unwrap().insert(hash, fingerprint) {
+            let mut layer = Layer::new(self.layers.last().unwrap().buckets.len() * 2);
+            layer.insert(hash, fingerprint);
+            self.layers.push(layer);
+        }
+    }
+    /// Count the possible occurrences of the item.
+    pub(crate) fn count(&self, item: &[u8]) -> usize {
+        let (hash, fingerprint) = Self::hash(item);
+        self.layers
+            .iter()
+            .map(|layer| layer.count(hash, fingerprint))
+            .sum()
+    }
+    /// The item may have been added or not.
+    pub(crate) fn contains(&self, item: &[u8]) -> bool {
+        self.count(item) > 0
+    }
+    /// Remove an occurrence of the item.
+    /// Return false if the item is not found.
+    pub(crate) fn remove(&mut self, item: &[u8]) -> bool {
+        let (hash, fingerprint) = Self::hash(item);
+        self.layers
+            .iter_mut()
+            .rev()
+            .any(|layer| layer.remove(hash, fingerprint))
+    }
+}
@@ -0,0 +1,302 @@
+//! Geospatial index on the sorted set.
+//!
+//! Each member is scored with the 52-bit geohash of its position,
+//! the interleaved bits of the latitude and longitude, so the members
+//! in a geohash cell are a score range of the sorted set.
+//! A search scans the cell containing the center and its neighbours,
+//! with the cell size chosen to cover the searched area.
+//!
+use super::{ScoreRange, ZSet};
+
+/// Bits of each coordinate in the geohash.
+const STEP_MAX: u32 = 26;
+/// Minimum longitude.
+const LONGITUDE_MIN: f64 = -180.0;
+/// Maximum longitude.
+const LONGITUDE_MAX: f64 = 180.0;
+/// Minimum latitude of the Web Mercator projection.
+const LATITUDE_MIN: f64 = -85.051_128_78;
+/// Maximum latitude of the Web Mercator projection.
+const LATITUDE_MAX: f64 = 85.051_128_78;
+/// Earth radius in meters used by the distance calculations.
+const EARTH_RADIUS: f64 = 6_372_797.560_856;
+/// Half of the circumference of the Web Mercator projection in meters.
+const MERCATOR_MAX: f64 = 20_037_726.37;
+/// Alphabet of the geohash string.
+const BASE32: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Shape of the searched area in meters.
+pub(crate) enum GeoShape {
+    /// Circle of the radius.
+    Radius(f64),
+    /// Box of the width and height.
+    Box(f64, f64),
+}
+
+/// Rectangle of the coordinates.
+struct Area {
+    longitude_min: f64,
+    longitude_max: f64,
+    latitude_min: f64,
+    latitude_max: f64,
+}
+
+/// The position is within the limits of the index or not.
+pub(crate) fn is_valid_position(longitude: f64, latitude: f64) -> bool {
+    (LONGITUDE_MIN..=LONGITUDE_MAX).contains(&longitude)
+        && (LATITUDE_MIN..=LATITUDE_MAX).contains(&latitude)
+}
+
+/// Index of the coordinate in the range divided into 2^step cells.
+fn cell_index(value: f64, min: f64, max: f64, step: u32) -> u64 {
+    let cells = 1u64 << step;
+    (((value - min) / (max - min) * cells as f64) as u64).min(cells - 1)
+}
+
+/// Interleave the bits, the latitude in the even bits and the longitude in the odd bits.
+fn interleave(latitude: u64, longitude: u64, step: u32) -> u64 {
+    (0..step).fold(0, |bits, index| {
+        bits | ((latitude >> index) & 1) << (2 * index)
+            | ((longitude >> index) & 1) << (2 * index + 1)
+    })
+}
+
+/// Split the interleaved bits into the latitude and longitude indexes.
+fn deinterleave(bits: u64, step: u32) -> (u64, u64) {
+    (0..step).fold((0, 0), |(latitude, longitude), index| {
+        (
+            latitude | ((bits >> (2 * index)) & 1) << index,
+            longitude | ((bits >> (2 * index + 1)) & 1) << index,
+        )
+    })
+}
+
+/// Geohash of the position with the bits per coordinate.
+fn encode_step(longitude: f64, latitude: f64, step: u32) -> u64 {
+    interleave(
+        cell_index(latitude, LATITUDE_MIN, LATITUDE_MAX, step),
+        cell_index(longitude, LONGITUDE_MIN, LONGITUDE_MAX, step),
+        step,
+    )
+}
+
+/// Area of the geohash cell.
+fn decode_step(bits: u64, step: u32) -> Area {
+    let (latitude, longitude) = deinterleave(bits, step);
+    let cells = (1u64 << step) as f64;
+    let latitude_scale = LATITUDE_MAX - LATITUDE_MIN;
+    let longitude_scale = LONGITUDE_MAX - LONGITUDE_MIN;
+    Area {
+        longitude_min: LONGITUDE_MIN + longitude as f64 / cells * longitude_scale,
+        longitude_max: LONGITUDE_MIN + (longitude + 1) as f64 / cells * longitude_scale,
+        latitude_min: LATITUDE_MIN + latitude as f64 / cells * latitude_scale,
+        latitude_max: LATITUDE_MIN + (latitude + 1) as f64 / cells * latitude_scale,
+    }
+}
+
+/// Score of the position.
+pub(crate) fn geo_encode(longitude: f64, latitude: f64) -> f64 {
+    encode_step(longitude, latitude, STEP_MAX) as f64
+}
+
+/// Position of the score, the center of its geohash cell.
+pub(crate) fn geo_decode(score: f64) -> (f64, f64) {
+    let area = decode_step(score as u64, STEP_MAX);
+    (
+        ((area.longitude_min + area.longitude_max) / 2.0).clamp(LONGITUDE_MIN, LONGITUDE_MAX),
+        ((area.latitude_min + area.latitude_max) / 2.0).clamp(LATITUDE_MIN, LATITUDE_MAX),
+    )
+}
+
+/// Standard 11 characters geohash string of the position.
+pub(crate) fn geo_hash(longitude: f64, latitude: f64) -> String {
+    // The standard geohash uses the full latitude range.
+    let bits = interleave(
+        cell_index(latitude, -90.0, 90.0, STEP_MAX),
+        cell_index(longitude, LONGITUDE_MIN, LONGITUDE_MAX, STEP_MAX),
+        STEP_MAX,
+    );
+    (0..11)
+        .map(|index| {
+            // 52 bits fill 10 characters and the last one is padded with zeros.
+            let code = if index == 10 {
+                0
+            } else {
+                (bits >> (52 - (index + 1) * 5)) & 0x1f
+            };
+            BASE32[code as usize] as char
+        })
+        .collect()
+}
+
+/// Great-circle distance in meters by the haversine formula.
+pub(crate) fn geo_distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (longitude1, latitude1) = (from.0.to_radians(), from.1.to_radians());
+    let (longitude2, latitude2) = (to.0.to_radians(), to.1.to_radians());
+    let u = ((latitude2 - latitude1) / 2.0).sin();
+    let v = ((longitude2 - longitude1) / 2.0).sin();
+    2.0 * EARTH_RADIUS
+        * (u * u + latitude1.cos() * latitude2.cos() * v * v)
+            .sqrt()
+            .asin()
+}
+
+/// Distance of the point from the center if it is within the shape.
+fn distance_within(center: (f64, f64), point: (f64, f64), shape: &GeoShape) -> Option<f64> {
+    match *shape {
+        GeoShape::Radius(radius) => {
+            let distance = geo_distance(center, point);
+            if distance <= radius {
+                Some(distance)
+            } else {
+                None
+            }
+        }
+        GeoShape::Box(width, height) => {
+            // The latitude distance is cheaper, so it is checked first.
+            let latitude_distance =
+                EARTH_RADIUS * (point.1.to_radians() - center.1.to_radians()).abs();
+            if latitude_distance > height / 2.0 {
+                return None;
+            }
+            if geo_distance((center.0, point.1), point) > width / 2.0 {
+                return None;
+            }
+            Some(geo_distance(center, point))
+        }
+    }
+}
+
+/// Bounding box of the shape around the center.
+fn bounding_box(center: (f64, f64), shape: &GeoShape) -> Area {
+    let (longitude, latitude) = center;
+    let (half_width, half_height) = match *shape {
+        GeoShape::Radius(radius) => (radius, radius),
+        GeoShape::Box(width, height) => (width / 2.0, height / 2.0),
+    };
+    let latitude_delta = (half_height / EARTH_RADIUS).to_degrees();
+    let longitude_delta =
+        |latitude: f64| (half_width / EARTH_RADIUS / latitude.to_radians().cos()).to_degrees();
+    // The box is wider on the side closer to the pole.
+    let longitude_delta = if latitude < 0.0 {
+        longitude_delta(latitude - latitude_delta)
+    } else {
+        longitude_delta(latitude + latitude_delta)
+    };
+    Area {
+        longitude_min: longitude - longitude_delta,
+        longitude_max: longitude + longitude_delta,
+        latitude_min: latitude - latitude_delta,
+        latitude_max: latitude + latitude_delta,
+    }
+}
+
+/// Bits per coordinate of the cells large enough for the radius.
+fn estimate_step(radius: f64, latitude: f64) -> u32 {
+    if radius == 0.0 {
+        return STEP_MAX;
+    }
+    let mut radius = radius;
+    let mut step: i32 = 1;
+    while radius < MERCATOR_MAX {
+        radius *= 2.0;
+        step += 1;
+    }
+    // Make sure the radius is included in most of the cases.
+    step -= 2;
+    // The cells are narrower towards the poles.
+    if !(-66.0..=66.0).contains(&latitude) {
+        step -= 1;
+        if !(-80.0..=80.0).contains(&latitude) {
+            step -= 1;
+        }
+    }
+    step.clamp(1, STEP_MAX as i32) as u32
+}
+
+/// Neighbour cell moved by the deltas, wrapping around the edges.
+fn neighbour(bits: u64, step: u32, latitude_delta: i64, longitude_delta: i64) -> u64 {
+    let (latitude, longitude) = deinterleave(bits, step);
+    let mask = (1i64 << step) - 1;
+    interleave(
+        ((latitude as i64 + latitude_delta) & mask) as u64,
+        ((longitude as i64 + longitude_delta) & mask) as u64,
+        step,
+    )
+}
+
+/// Geohash cells to be scanned, with the bits per coordinate.
+fn cells(center: (f64, f64), shape: &GeoShape) -> (Vec<u64>, u32) {
+    let radius = match *shape {
+        GeoShape::Radius(radius) => radius,
+        GeoShape::Box(width, height) => (width / 2.0).hypot(height / 2.0),
+    };
+    let bounds = bounding_box(center, shape);
+    let mut step = estimate_step(radius, center.1);
+    let mut bits = encode_step(center.0, center.1, step);
+
+    // Use the larger cells if the neighbours don't cover the bounding box.
+    let north = decode_step(neighbour(bits, step, 1, 0), step);
+    let south = decode_step(neighbour(bits, step, -1, 0), step);
+    let east = decode_step(neighbour(bits, step, 0, 1), step);
+    let west = decode_step(neighbour(bits, step, 0, -1), step);
+    if step > 1
+        && (north.latitude_max < bounds.latitude_max
+            || south.latitude_min > bounds.latitude_min
+            || east.longitude_max < bounds.longitude_max
+            || west.longitude_min > bounds.longitude_min)
+    {
+        step -= 1;
+        bits = encode_step(center.0, center.1, step);
+    }
+
+    // Skip the neighbours outside of the bounding box.
+    let area = decode_step(bits, step);
+    let mut cells = Vec::with_capacity(9);
+    for latitude_delta in -1..=1 {
+        for longitude_delta in -1..=1 {
+            if step >= 2
+                && ((latitude_delta < 0 && area.latitude_min < bounds.latitude_min)
+                    || (latitude_delta > 0 && area.latitude_max > bounds.latitude_max)
+                    || (longitude_delta < 0 && area.longitude_min < bounds.longitude_min)
+                    || (longitude_delta > 0 && area.longitude_max > bounds.longitude_max))
+            {
+                continue;
+            }
+            let cell = neighbour(bits, step, latitude_delta, longitude_delta);
+            // Small steps wrap around to the same cells.
+            if !cells.contains(&cell) {
+                cells.push(cell);
+            }
+        }
+    }
+    (cells, step)
+}
+
+/// Search the members within the shape around the center.
+/// Stop when the limit of the members are found.
+/// Return the members with their scores and distances in meters.
+pub(crate) fn geo_search<'a>(
+    zset: &'a ZSet,
+    center: (f64, f64),
+    shape: &GeoShape,
+    limit: Option<usize>,
+) -> Vec<(&'a [u8], f64, f64)> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let (cells, step) = cells(center, shape);
+    let shift = 2 * (STEP_MAX - step);
+
+    let mut found = Vec::new();
+    for cell in cells {
+        let range = ScoreRange::half_open((cell << shift) as f64, ((cell + 1) << shift) as f64);
+        for (member, score) in zset.range_by_score(&range, false, 0, None) {
+            if let Some(distance) = distance_within(center, geo_decode(score), shape) {
+                found.push((member, score, distance));
+                if found.len() >= limit {
+                    return found;
+                }
+            }
+        }
+    }
+    found
+}
@@ -0,0 +1,216 @@
+//! Compressed chunk of the time series samples.
+//!
+//! The samples are compressed in the same way as Facebook's Gorilla.
+//! The timestamps are encoded as the variable length delta of the deltas,
+//! and the values are encoded as the meaningful bits of XOR with the previous value.
+//!
+/// Bit length of the timestamp and the value of the first sample.
+const FULL_BITS: u32 = 64;
+
+/// Encoding of the delta of the deltas: prefix, its length and the length of the value.
+const DELTA_CLASSES: [(u64, u32, u32); 4] = [
+    (0b10, 2, 7),
+    (0b110, 3, 9),
+    (0b1110, 4, 12),
+    (0b1111, 4, 64),
+];
+
+/// Append-only bit stream.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    /// Number of the written bits.
+    len: usize,
+}
+
+impl BitWriter {
+    /// Write the lower bits of the value, the most significant bit first.
+    fn write(&mut self, value: u64, bits: u32) {
+        for i in (0..bits).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if value >> i & 1 == 1 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+}
+
+/// Reader of the bit stream.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    /// Position of the next bit.
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, bits: u32) -> u64 {
+        let mut value = 0;
+        for _ in 0..bits {
+            let bit = self.bytes[self.position / 8] >> (7 - self.position % 8) & 1;
+            value = value << 1 | bit as u64;
+            self.position += 1;
+        }
+        value
+    }
+    /// Count the leading one bits up to the limit, consuming the terminating zero bit.
+    fn read_ones(&mut self, limit: u32) -> u32 {
+        let mut ones = 0;
+        while ones < limit && self.read(1) == 1 {
+            ones += 1;
+        }
+        ones
+    }
+}
+
+/// State of the previous sample shared by the encoder and the decoder.
+#[derive(Clone, Copy)]
+struct State {
+    timestamp: i64,
+    delta: i64,
+    value: u64,
+    /// Leading zeros of the last XOR window. 64 if there is no window.
+    leading: u32,
+    /// Trailing zeros of the last XOR window.
+    trailing: u32,
+}
+
+impl State {
+    fn new(timestamp: i64, value: u64) -> Self {
+        State {
+            timestamp,
+            delta: 0,
+            value,
+            leading: 64,
+            trailing: 64,
+        }
+    }
+}
+
+/// Compressed chunk of the samples in ascending order of the timestamps.
+pub(super) struct Chunk {
+    bits: BitWriter,
+    count: usize,
+    first: i64,
+    /// State of the last sample.
+    state: State,
+}
+
+impl Chunk {
+    /// Create a chunk of the samples.
+    pub(super) fn new(samples: &[(i64, f64)]) -> Self {
+        let (first, value) = samples[0];
+        let mut bits = BitWriter::default();
+        bits.write(first as u64, FULL_BITS);
+        bits.write(value.to_bits(), FULL_BITS);
+        let mut chunk = Chunk {
+            bits,
+            count: 1,
+            first,
+            state: State::new(first, value.to_bits()),
+        };
+        for &(timestamp, value) in &samples[1..] {
+            chunk.push(timestamp, value);
+        }
+        chunk
+    }
+    /// Append the sample newer than the last one.
+    pub(super) fn push(&mut self, timestamp: i64, value: f64) {
+        let state = &mut self.state;
+        let delta = timestamp - state.timestamp;
+        let delta_of_delta = delta.wrapping_sub(state.delta);
+        // Zigzag encoding, so the small negative numbers are small.
+        let zigzag = ((delta_of_delta << 1) ^ (delta_of_delta >> 63)) as u64;
+        if zigzag == 0 {
+            self.bits.write(0, 1);
+        } else {
+            let (prefix, prefix_bits, bits) = DELTA_CLASSES
+                .into_iter()
+                .find(|&(_, _, bits)| bits == 64 || zigzag < 1 << bits)
+                .unwrap();
+            self.bits.write(prefix, prefix_bits);
+            self.bits.write(zigzag, bits);
+        }
+        let xor = value.to_bits() ^ state.value;
+        if xor == 0 {
+            self.bits.write(0, 1);
+        } else {
+            let leading = xor.leading_zeros().min(31);
+            let trailing = xor.trailing_zeros();
+            if leading >= state.leading && trailing >= state.trailing {
+                // Reuse the previous window.
+                self.bits.write(0b10, 2);
+                let meaningful = 64 - state.leading - state.trailing;
+                self.bits.write(xor >> state.trailing, meaningful);
+            } else {
+                let meaningful = 64 - leading - trailing;
+                self.bits.write(0b11, 2);
+                self.bits.write(leading as u64, 5);
+                self.bits.write(meaningful as u64 - 1, 6);
+                self.bits.write(xor >> trailing, meaningful);
+                state.leading = leading;
+                state.trailing = trailing;
+            }
+        }
+        state.timestamp = timestamp;
+        state.delta = delta;
+        state.value = value.to_bits();
+        self.count += 1;
+    }
+    /// Decompress the samples.
+    pub(super) fn samples(&self) -> Vec<(i64, f64)> {
+        let mut reader = BitReader {
+            bytes: &self.bits.bytes,
+            position: 0,
+        };
+        let first = reader.read(FULL_BITS) as i64;
+        let value = reader.read(FULL_BITS);
+        let mut state = State::new(first, value);
+        let mut samples = Vec::with_capacity(self.count);
+        samples.push((first, f64::from_bits(value)));
+        for _ in 1..self.count {
+            let class = reader.read_ones(4) as usize;
+            let delta_of_delta = if class == 0 {
+                0
+            } else {
+                let zigzag = reader.read(DELTA_CLASSES[class - 1].2);
+                (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64)
+            };
+            state.delta = state.delta.wrapping_add(delta_of_delta);
+            state.timestamp += state.delta;
+            match reader.read_ones(2) {
+                0 => {}
+                1 => {
+                    let meaningful = 64 - state.leading - state.trailing;
+                    state.value ^= reader.read(meaningful) << state.trailing;
+                }
+                _ => {
+                    state.leading = reader.read(5) as u32;
+                    let meaningful = reader.read(6) as u32 + 1;
+                    state.trailing = 64 - state.leading - meaningful;
+                    state.value ^= reader.read(meaningful) << state.trailing;
+                }
+            }
+            samples.push((state.timestamp, f64::from_bits(state.value)));
+        }
+        samples
+    }
+    /// Timestamp of the first sample.
+    pub(super) fn first(&self) -> i64 {
+        self.first
+    }
+    /// Timestamp of the last sample.
+    pub(super) fn last(&self) -> i64 {
+        self.state.timestamp
+    }
+    /// Value of the last sample.
+    pub(super) fn last_value(&self) -> f64 {
+        f64::from_bits(self.state.value)
+    }
+    /// Size of the compressed samples in bytes.
+    pub(super) fn size(&self) -> usize {
+        self.bits.bytes.len()
+    }
+}
@@ -0,0 +1,94 @@
+//! Hash value.
+//!
+//! Small hashes are stored compactly as a vector of field-value pairs,
+//! and converted into a hash table when they grow.
+//!
+use std::collections::HashMap;
+
+/// Maximum number of entries of the compact hash.
+const MAX_COMPACT_ENTRIES: usize = 128;
+/// Maximum length of the field or value of the compact hash.
+const MAX_COMPACT_VALUE: usize = 64;
+
+/// Hash value.
+pub(crate) enum Hash {
+    /// Vector of field-value pairs.
+    Compact(Vec<(Vec<u8>, Vec<u8>)>),
+    /// Hash table.
+    Table(HashMap<Vec<u8>, Vec<u8>>),
+}
+
+impl Hash {
+    /// Create an empty hash.
+    pub(crate) fn new() -> Self {
+        Hash::Compact(Vec::new())
+    }
+    /// Number of the fields.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Hash::Compact(pairs) => pairs.len(),
+            Hash::Table(table) => table.len(),
+        }
+    }
+    /// No fields or not.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Get the value of the field.
+    pub(crate) fn get(&self, field: &[u8]) -> Option<&Vec<u8>> {
+        match self {
+            Hash::Compact(pairs) => pairs.iter().find(|(f, _)| f == field).map(|(_, v)| v),
+            Hash::Table(table) => table.get(field),
+        }
+    }
+    /// The field exists or not.
+    pub(crate) fn contains(&self, field: &[u8]) -> bool {
+        self.get(field).is_some()
+    }
+    /// Set the value of the field.
+    /// Return true if the field is new.
+    pub(crate) fn insert(&mut self, field: Vec<u8>, value: Vec<u8>) -> bool {
+        if let Hash::Compact(pairs) = self {
+            // The long field or value converts the hash below.
+            let fits = field.len() <= MAX_COMPACT_VALUE && value.len() <= MAX_COMPACT_VALUE;
+            match pairs.iter().position(|(f, _)| *f == field) {
+                Some(index) if fits => {
+                    pairs[index].1 = value;
+                    return false;
+                }
+                None if fits && pairs.len() < MAX_COMPACT_ENTRIES => {
+                    pairs.push((field, value));
+                    return true;
+                }
+                _ => {}
+            }
+            // Convert into the hash table.
+            *self = Hash::Table(std::mem::take(pairs).into_iter().collect());
+        }
+        match self {
+            Hash::Table(table) => table.insert(field, value).is_none(),
+            Hash::Compact(_) => unreachable!(),
+        }
+    }
+    /// Remove the field.
+    /// Return true if the field existed.
+    pub(crate) fn remove(&mut self, field: &[u8]) -> bool {
+        match self {
+            Hash::Compact(pairs) => match pairs.iter().position(|(f, _)| f == field) {
+                Some(index) => {
+                    pairs.swap_remove(index);
+                    true
+                }
+                None => false,
+            },
+            Hash::Table(table) => table.remove(field).is_some(),
+        }
+    }
+    /// Iterate over the field-value pairs.
+    pub(crate) fn iter(&self) -> Box<dyn ExactSizeIterator<Item = (&Vec<u8>, &Vec<u8>)> + '_> {
+        match self {
+            Hash::Compact(pairs) => Box::new(pairs.iter().map(|(f, v)| (f, v))),
+            Hash::Table(table) => Box::new(table.iter()),
+        }
+    }
+}
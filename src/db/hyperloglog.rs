@@ -0,0 +1,313 @@
+//! HyperLogLog value.
+//!
+//! The registers are stored in a string value with the same layout as Redis,
+//! so the values can be copied between the servers.
+//! The sparse encoding is a run-length encoding of the registers,
+//! and it is converted into the dense encoding, 6 bits per register,
+//! when it grows too large.
+//!
+use super::Error;
+
+/// Magic bytes of the header.
+const MAGIC: &[u8] = b"HYLL";
+/// Size of the header: magic, encoding, 3 unused bytes and 8 bytes cached cardinality.
+const HEADER_SIZE: usize = 16;
+/// Number of the hash bits used to select the register.
+const P: u32 = 14;
+/// Number of the hash bits used to count the leading zeros.
+const Q: u32 = 64 - P;
+/// Number of the registers.
+const REGISTERS: usize = 1 << P;
+/// Bits of each register of the dense encoding.
+const REGISTER_BITS: usize = 6;
+/// Maximum value of the register.
+const REGISTER_MAX: u8 = (1 << REGISTER_BITS) - 1;
+/// Size of the string of the dense encoding.
+const DENSE_SIZE: usize = HEADER_SIZE + (REGISTERS * REGISTER_BITS).div_ceil(8);
+/// Dense encoding.
+const DENSE: u8 = 0;
+/// Sparse encoding.
+const SPARSE: u8 = 1;
+/// Maximum size of the string of the sparse encoding.
+const SPARSE_MAX_BYTES: usize = 3000;
+/// Maximum value of the register of the sparse encoding.
+const SPARSE_VALUE_MAX: u8 = 32;
+/// Maximum run length of the ZERO opcode.
+const SPARSE_ZERO_MAX_LEN: usize = 64;
+/// Maximum run length of the VAL opcode.
+const SPARSE_VAL_MAX_LEN: usize = 4;
+/// Flag of the invalid cached cardinality in the last byte.
+const CACHE_INVALID: u8 = 0x80;
+/// Seed of the hash function.
+const SEED: u64 = 0xadc8_3b19;
+/// 0.5 / ln(2)
+const ALPHA_INF: f64 = 0.721_347_520_444_481_7;
+
+/// HyperLogLog value.
+pub(crate) struct HyperLogLog {
+    /// One register per byte.
+    registers: Vec<u8>,
+    /// Sparse encoding or not.
+    sparse: bool,
+}
+
+impl HyperLogLog {
+    /// Create an empty HyperLogLog.
+    pub(crate) fn new() -> Self {
+        HyperLogLog {
+            registers: vec![0; REGISTERS],
+            sparse: true,
+        }
+    }
+    /// The string is a HyperLogLog or not.
+    pub(crate) fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len() >= HEADER_SIZE
+            && bytes.starts_with(MAGIC)
+            && match bytes[4] {
+                DENSE => bytes.len() == DENSE_SIZE,
+                SPARSE => true,
+                _ => false,
+            }
+    }
+    /// Decode the string.
+    pub(crate) fn decode(bytes: &[u8]) -> crate::Result<Self> {
+        if !Self::is_valid(bytes) {
+            return Err(Box::new(Error::InvalidHyperLogLog));
+        }
+        let body = &bytes[HEADER_SIZE..];
+        if bytes[4] == DENSE {
+            return Ok(HyperLogLog {
+                registers: (0..REGISTERS).map(|index| dense_get(body, index)).collect(),
+                sparse: false,
+            });
+        }
+
+        let mut registers = Vec::with_capacity(REGISTERS);
+        let mut opcodes = body.iter();
+        while let Some(&opcode) = opcodes.next() {
+            let (value, len) = match opcode >> 6 {
+                // ZERO: 00xxxxxx
+                0b00 => (0, (opcode & 0x3f) as usize + 1),
+                // XZERO: 01xxxxxx yyyyyyyy
+                0b01 => match opcodes.next() {
+                    Some(&next) => (0, (((opcode & 0x3f) as usize) << 8 | next as usize) + 1),
+                    None => return Err(Box::new(Error::CorruptedHyperLogLog)),
+                },
+                // VAL: 1vvvvvxx
+                _ => (((opcode >> 2) & 0x1f) + 1, (opcode & 0x03) as usize + 1),
+            };
+            if registers.len() + len > REGISTERS {
+                return Err(Box::new(Error::CorruptedHyperLogLog));
+            }
+            registers.resize(registers.len() + len, value);
+        }
+        if registers.len() != REGISTERS {
+            return Err(Box::new(Error::CorruptedHyperLogLog));
+        }
+
+        Ok(HyperLogLog {
+            registers,
+            sparse: true,
+        })
+    }
+    /// Encode into the string.
+    /// The sparse encoding is converted into the dense encoding if it doesn't fit.
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let sparse = if self.sparse {
+            self.encode_sparse()
+        } else {
+            None
+        };
+
+        let mut bytes = Vec::with_capacity(DENSE_SIZE);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(if sparse.is_some() { SPARSE } else { DENSE });
+        bytes.extend_from_slice(&[0; 3]);
+        bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, CACHE_INVALID]);
+        match sparse {
+            Some(body) => bytes.extend_from_slice(&body),
+            None => {
+                bytes.resize(DENSE_SIZE, 0);
+                for (index, &value) in self.registers.iter().enumerate() {
+                    dense_set(&mut bytes[HEADER_SIZE..], index, value);
+                }
+            }
+        }
+        bytes
+    }
+    /// Run-length encode the registers.
+    /// Return None if the registers don't fit in the sparse encoding.
+    fn encode_sparse(&self) -> Option<Vec<u8>> {
+        let mut body = Vec::new();
+        let mut index = 0;
+        while index < REGISTERS {
+            let value = self.registers[index];
+            let mut len = self.registers[index..]
+                .iter()
+                .take_while(|&&register| register == value)
+                .count();
+            index += len;
+            if value == 0 {
+                // The run length is 1 to 16384.
+                if len <= SPARSE_ZERO_MAX_LEN {
+                    body.push((len - 1) as u8);
+                } else {
+                    body.push(0x40 | ((len - 1) >> 8) as u8);
+                    body.push(((len - 1) & 0xff) as u8);
+                }
+            } else {
+                if value > SPARSE_VALUE_MAX {
+                    return None;
+                }
+                while len > 0 {
+                    let run = len.min(SPARSE_VAL_MAX_LEN);
+                    body.push(0x80 | (value - 1) << 2 | (run - 1) as u8);
+                    len -= run;
+                }
+            }
+            if HEADER_SIZE + body.len() > SPARSE_MAX_BYTES {
+                return None;
+            }
+        }
+        Some(body)
+    }
+    /// Add the element.
+    /// Return true if any register has been updated.
+    pub(crate) fn add(&mut self, element: &[u8]) -> bool {
+        let hash = murmur_hash64a(element, SEED);
+        let index = (hash & (REGISTERS as u64 - 1)) as usize;
+        // The position of the first set bit, with the sentinel bit at Q.
+        let count = (((hash >> P) | 1 << Q).trailing_zeros() + 1) as u8;
+        if count > self.registers[index] {
+            self.registers[index] = count;
+            true
+        } else {
+            false
+        }
+    }
+    /// Merge the other HyperLogLog by taking the maximum of each register.
+    pub(crate) fn merge(&mut self, other: &HyperLogLog) {
+        for (register, &value) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(value);
+        }
+        if !other.sparse {
+            self.sparse = false;
+        }
+    }
+    /// Estimate the cardinality.
+    pub(crate) fn count(&self) -> u64 {
+        let mut histogram = [0usize; Q as usize + 2];
+        for &value in &self.registers {
+            // The dense encoding of the other servers may hold larger values.
+            histogram[(value as usize).min(Q as usize + 1)] += 1;
+        }
+
+        let m = REGISTERS as f64;
+        let mut z = m * tau((m - histogram[Q as usize + 1] as f64) / m);
+        for &count in histogram[1..=Q as usize].iter().rev() {
+            z += count as f64;
+            z *= 0.5;
+        }
+        z += m * sigma(histogram[0] as f64 / m);
+        (ALPHA_INF * m * m / z).round() as u64
+    }
+    /// Get the cached cardinality of the valid string.
+    pub(crate) fn cached_count(bytes: &[u8]) -> Option<u64> {
+        if bytes[HEADER_SIZE - 1] & CACHE_INVALID == 0 {
+            Some(u64::from_le_bytes(
+                bytes[8..HEADER_SIZE].try_into().unwrap(),
+            ))
+        } else {
+            None
+        }
+    }
+    /// Cache the cardinality in the valid string.
+    pub(crate) fn set_cached_count(bytes: &mut [u8], count: u64) {
+        bytes[8..HEADER_SIZE].copy_from_slice(&count.to_le_bytes());
+    }
+}
+
+/// Get the register of the dense encoding.
+fn dense_get(body: &[u8], index: usize) -> u8 {
+    let byte = index * REGISTER_BITS / 8;
+    let shift = index * REGISTER_BITS % 8;
+    let low = body[byte] as u16;
+    let high = body.get(byte + 1).copied().unwrap_or(0) as u16;
+    ((low >> shift | high << (8 - shift)) & REGISTER_MAX as u16) as u8
+}
+
+/// Set the register of the dense encoding.
+fn dense_set(body: &mut [u8], index: usize, value: u8) {
+    let byte = index * REGISTER_BITS / 8;
+    let shift = index * REGISTER_BITS % 8;
+    let bits = (value as u16) << shift;
+    let mask = !((REGISTER_MAX as u16) << shift);
+    body[byte] = (body[byte] & mask as u8) | bits as u8;
+    if let Some(next) = body.get_mut(byte + 1) {
+        *next = (*next & (mask >> 8) as u8) | (bits >> 8) as u8;
+    }
+}
+
+/// Helper function sigma of the estimator by Otmar Ertl.
+fn sigma(mut x: f64) -> f64 {
+    if x == 1.0 {
+        return f64::INFINITY;
+    }
+    let mut y = 1.0;
+    let mut z = x;
+    loop {
+        x *= x;
+        let previous = z;
+        z += x * y;
+        y += y;
+        if previous == z {
+            return z;
+        }
+    }
+}
+
+/// Helper function tau of the estimator by Otmar Ertl.
+fn tau(mut x: f64) -> f64 {
+    if x == 0.0 || x == 1.0 {
+        return 0.0;
+    }
+    let mut y = 1.0;
+    let mut z = 1.0 - x;
+    loop {
+        x = x.sqrt();
+        let previous = z;
+        y *= 0.5;
+        z -= (1.0 - x).powi(2) * y;
+        if previous == z {
+            return z / 3.0;
+        }
+    }
+}
+
+/// MurmurHash64A by Austin Appleby, reading the words in little endian.
+pub(super) fn murmur_hash64a(key: &[u8], seed: u64) -> u64 {
+    const M: u64 = 0xc6a4_a793_5bd1_e995;
+    const R: u32 = 47;
+
+    let mut h = seed ^ (key.len() as u64).wrapping_mul(M);
+    let mut chunks = key.chunks_exact(8);
+    for chunk in chunks.by_ref() {
+        let mut k = u64::from_le_bytes(chunk.try_into().unwrap());
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h ^= k;
+        h = h.wrapping_mul(M);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        for (index, &byte) in tail.iter().enumerate() {
+            h ^= (byte as u64) << (index * 8);
+        }
+        h = h.wrapping_mul(M);
+    }
+    h ^= h >> R;
+    h = h.wrapping_mul(M);
+    h ^= h >> R;
+    h
+}
@@ -0,0 +1,479 @@
+//! JSON value.
+//!
+//! Objects keep their members in insertion order.
+//! Integers and floating point numbers are kept apart
+//! to report their types and to increment integers exactly.
+//!
+use std::fmt::Write;
+
+/// Maximum nesting depth of the parsed documents.
+const MAX_DEPTH: usize = 128;
+
+/// JSON value.
+#[derive(Clone, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// Step from the value to its child.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum JsonStep {
+    Index(usize),
+    Key(String),
+}
+
+/// Formatting of the serialized document.
+#[derive(Default)]
+pub(crate) struct JsonFormat {
+    /// Indentation of each nesting level.
+    pub(crate) indent: String,
+    /// Separator after the brackets and commas.
+    pub(crate) newline: String,
+    /// Separator after the colons.
+    pub(crate) space: String,
+}
+
+/// JSON parser.
+struct Parser<'a> {
+    text: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Error message with the line and column of the current position.
+    fn error(&self, message: &str) -> String {
+        let consumed = &self.text[..self.position.min(self.text.len())];
+        let line = consumed.iter().filter(|&&byte| byte == b'\n').count() + 1;
+        let column = consumed
+            .iter()
+            .rev()
+            .take_while(|&&byte| byte != b'\n')
+            .count()
+            + 1;
+        format!("{} at line {} column {}", message, line, column)
+    }
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.text.get(self.position) {
+            self.position += 1;
+        }
+    }
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+    fn expect(&mut self, literal: &[u8]) -> bool {
+        if self.text[self.position..].starts_with(literal) {
+            self.position += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+    fn parse_value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => Ok(Json::String(self.parse_string()?)),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(b't') if self.expect(b"true") => Ok(Json::Bool(true)),
+            Some(b'f') if self.expect(b"false") => Ok(Json::Bool(false)),
+            Some(b'n') if self.expect(b"null") => Ok(Json::Null),
+            None => Err(self.error("EOF while parsing a value")),
+            _ => Err(self.error("expected value")),
+        }
+    }
+    /// Enter the nested value.
+    fn enter(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        self.position += 1;
+        if self.depth > MAX_DEPTH {
+            Err(self.error("recursion limit exceeded"))
+        } else {
+            Ok(())
+        }
+    }
+    fn parse_array(&mut self) -> Result<Json, String> {
+        self.enter()?;
+        let mut array = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            self.depth -= 1;
+            return Ok(Json::Array(array));
+        }
+        loop {
+            array.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    self.depth -= 1;
+                    return Ok(Json::Array(array));
+                }
+                None => return Err(self.error("EOF while parsing a list")),
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+    fn parse_object(&mut self) -> Result<Json, String> {
+        self.enter()?;
+        let mut object: Vec<(String, Json)> = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            self.depth -= 1;
+            return Ok(Json::Object(object));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("key must be a string"));
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            if !self.expect(b":") {
+                return Err(self.error("expected `:`"));
+            }
+            let value = self.parse_value()?;
+            // The last one wins for the duplicated keys.
+            match object.iter_mut().find(|(name, _)| *name == key) {
+                Some((_, member)) => *member = value,
+                None => object.push((key, value)),
+            }
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    self.depth -= 1;
+                    return Ok(Json::Object(object));
+                }
+                None => return Err(self.error("EOF while parsing an object")),
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+    fn parse_hex(&mut self) -> Result<u32, String> {
+        let hex = self
+            .text
+            .get(self.position..self.position + 4)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+        match hex {
+            Some(code) => {
+                self.position += 4;
+                Ok(code)
+            }
+            None => Err(self.error("invalid escape")),
+        }
+    }
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.position += 1;
+        let mut bytes = Vec::new();
+        loop {
+            let byte = match self.peek() {
+                Some(byte) => byte,
+                None => return Err(self.error("EOF while parsing a string")),
+            };
+            self.position += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = self.peek();
+                    self.position += 1;
+                    let char = match escaped {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let mut code = self.parse_hex()?;
+                            // Combine the surrogate pair.
+                            if (0xd800..0xdc00).contains(&code) && self.expect(b"\\u") {
+                                let low = self.parse_hex()?;
+                                if !(0xdc00..0xe000).contains(&low) {
+                                    return Err(self.error("lone leading surrogate in hex escape"));
+                                }
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            match char::from_u32(code) {
+                                Some(char) => char,
+                                None => return Err(self.error("invalid unicode code point")),
+                            }
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    bytes.extend_from_slice(char.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                0x00..=0x1f => {
+                    return Err(self.error("control character found while parsing a string"))
+                }
+                _ => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.error("invalid unicode"))
+    }
+    fn parse_number(&mut self) -> Result<Json, String> {
+        let start = self.position;
+        let mut float = false;
+        self.expect(b"-");
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'0'..=b'9' => {}
+                b'.' | b'e' | b'E' => float = true,
+                b'+' | b'-' if float => {}
+                _ => break,
+            }
+            self.position += 1;
+        }
+        let number = std::str::from_utf8(&self.text[start..self.position]).unwrap();
+        let digits = number.trim_start_matches('-').as_bytes();
+        let leading_zero = digits.len() > 1 && digits[0] == b'0' && digits[1].is_ascii_digit();
+        let valid = digits.first().is_some_and(u8::is_ascii_digit)
+            && !leading_zero
+            && !number.contains(".e")
+            && !number.contains(".E")
+            && number.ends_with(|char: char| char.is_ascii_digit());
+        if !valid {
+            return Err(self.error("invalid number"));
+        }
+        if !float {
+            if let Ok(integer) = number.parse() {
+                return Ok(Json::Integer(integer));
+            }
+        }
+        match number.parse::<f64>() {
+            Ok(float) if float.is_finite() => Ok(Json::Float(float)),
+            Ok(_) => Err(self.error("number out of range")),
+            Err(_) => Err(self.error("invalid number")),
+        }
+    }
+}
+
+impl Json {
+    /// Parse the JSON text.
+    pub(crate) fn parse(text: &[u8]) -> Result<Json, String> {
+        let mut parser = Parser {
+            text,
+            position: 0,
+            depth: 0,
+        };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.position < text.len() {
+            return Err(parser.error("trailing characters"));
+        }
+        Ok(value)
+    }
+    /// Serialize the value without the whitespaces.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes_with(&JsonFormat::default())
+    }
+    /// Serialize the value in the format.
+    pub(crate) fn to_bytes_with(&self, format: &JsonFormat) -> Vec<u8> {
+        let mut text = String::new();
+        self.write(&mut text, format, 0);
+        text.into_bytes()
+    }
+    fn write(&self, text: &mut String, format: &JsonFormat, level: usize) {
+        match self {
+            Json::Null => text.push_str("null"),
+            Json::Bool(bool) => text.push_str(if *bool { "true" } else { "false" }),
+            Json::Integer(integer) => write!(text, "{}", integer).unwrap(),
+            // Debug keeps ".0" of the integral values and uses the exponent for the large ones.
+            Json::Float(float) => write!(text, "{:?}", float).unwrap(),
+            Json::String(string) => write_string(text, string),
+            Json::Array(array) if array.is_empty() => text.push_str("[]"),
+            Json::Object(object) if object.is_empty() => text.push_str("{}"),
+            Json::Array(array) => {
+                text.push('[');
+                for (index, value) in array.iter().enumerate() {
+                    if index > 0 {
+                        text.push(',');
+                    }
+                    write_break(text, format, level + 1);
+                    value.write(text, format, level + 1);
+                }
+                write_break(text, format, level);
+                text.push(']');
+            }
+            Json::Object(object) => {
+                text.push('{');
+                for (index, (key, value)) in object.iter().enumerate() {
+                    if index > 0 {
+                        text.push(',');
+                    }
+                    write_break(text, format, level + 1);
+                    write_string(text, key);
+                    text.push(':');
+                    text.push_str(&format.space);
+                    value.write(text, format, level + 1);
+                }
+                write_break(text, format, level);
+                text.push('}');
+            }
+        }
+    }
+    /// Name of the type.
+    pub(crate) fn type_name(&self) -> &'static str {
+        match self {
+            Json::Null => "null",
+            Json::Bool(_) => "boolean",
+            Json::Integer(_) => "integer",
+            Json::Float(_) => "number",
+            Json::String(_) => "string",
+            Json::Array(_) => "array",
+            Json::Object(_) => "object",
+        }
+    }
+    /// Number of the value as a float.
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Integer(integer) => Some(*integer as f64),
+            Json::Float(float) => Some(*float),
+            _ => None,
+        }
+    }
+    /// Get the member of the object.
+    pub(crate) fn member(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(object) => object
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+    /// Children of the array or object with their steps.
+    pub(crate) fn children(&self) -> Vec<(JsonStep, &Json)> {
+        match self {
+            Json::Array(array) => array
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (JsonStep::Index(index), value))
+                .collect(),
+            Json::Object(object) => object
+                .iter()
+                .map(|(key, value)| (JsonStep::Key(key.clone()), value))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+    /// Get the child at the step.
+    fn child_mut(&mut self, step: &JsonStep) -> Option<&mut Json> {
+        match (self, step) {
+            (Json::Array(array), JsonStep::Index(index)) => array.get_mut(*index),
+            (Json::Object(object), JsonStep::Key(key)) => object
+                .iter_mut()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+    /// Get the value at the location.
+    pub(crate) fn get(&self, location: &[JsonStep]) -> Option<&Json> {
+        location
+            .iter()
+            .try_fold(self, |value, step| match (value, step) {
+                (Json::Array(array), JsonStep::Index(index)) => array.get(*index),
+                (value, JsonStep::Key(key)) => value.member(key),
+                _ => None,
+            })
+    }
+    /// Get the mutable value at the location.
+    pub(crate) fn get_mut(&mut self, location: &[JsonStep]) -> Option<&mut Json> {
+        location
+            .iter()
+            .try_fold(self, |value, step| value.child_mut(step))
+    }
+    /// Set the member of the object.
+    /// Return false if the value is not an object.
+    pub(crate) fn insert(&mut self, key: String, value: Json) -> bool {
+        match self {
+            Json::Object(object) => {
+                match object.iter_mut().find(|(name, _)| *name == key) {
+                    Some((_, member)) => *member = value,
+                    None => object.push((key, value)),
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+    /// Remove the values at the locations.
+    /// Return the number of the removed values.
+    pub(crate) fn remove(&mut self, mut locations: Vec<Vec<JsonStep>>) -> usize {
+        // Skip the values inside the other removed values.
+        locations.sort_unstable();
+        locations.dedup();
+        let mut outermost: Vec<Vec<JsonStep>> = Vec::new();
+        for location in locations {
+            if !outermost.iter().any(|parent| location.starts_with(parent)) {
+                outermost.push(location);
+            }
+        }
+        // Remove the later array elements first to keep the indexes valid.
+        let mut removed = 0;
+        for location in outermost.iter().rev() {
+            let (step, parent) = match location.split_last() {
+                Some(split) => split,
+                None => continue,
+            };
+            match (self.get_mut(parent), step) {
+                (Some(Json::Array(array)), JsonStep::Index(index)) if *index < array.len() => {
+                    array.remove(*index);
+                }
+                (Some(Json::Object(object)), JsonStep::Key(key)) => {
+                    match object.iter().position(|(name, _)| name == key) {
+                        Some(position) => {
+                            object.remove(position);
+                        }
+                        None => continue,
+                    }
+                }
+                _ => continue,
+            }
+            removed += 1;
+        }
+        removed
+    }
+}
+
+/// Write the newline and the indentation of the level.
+fn write_break(text: &mut String, format: &JsonFormat, level: usize) {
+    text.push_str(&format.newline);
+    for _ in 0..level {
+        text.push_str(&format.indent);
+    }
+}
+
+/// Write the quoted string.
+fn write_string(text: &mut String, string: &str) {
+    text.push('"');
+    for char in string.chars() {
+        match char {
+            '"' => text.push_str("\\\""),
+            '\\' => text.push_str("\\\\"),
+            '\n' => text.push_str("\\n"),
+            '\r' => text.push_str("\\r"),
+            '\t' => text.push_str("\\t"),
+            '\u{8}' => text.push_str("\\b"),
+            '\u{c}' => text.push_str("\\f"),
+            '\u{0}'..='\u{1f}' => write!(text, "\\u{:04x}", char as u32).unwrap(),
+            _ => text.push(char),
+        }
+    }
+    text.push('"');
+}
@@ -0,0 +1,550 @@
+//! JSONPath to select the values in the JSON document.
+//!
+//! The path starting with `$` is a JSONPath supporting the member names,
+//! indexes, slices, unions, wildcards, recursive descent and filter expressions.
+//! The other paths are the legacy paths such as `.` and `.a.b[0]`,
+//! which select a single value.
+//!
+use super::{Json, JsonStep};
+use std::cmp::Ordering;
+
+/// Maximum nesting of the filter expressions.
+const MAX_FILTER_DEPTH: usize = 128;
+
+/// Selector of the children.
+enum Selector {
+    /// `.name` or `['name']`
+    Key(String),
+    /// `[index]`
+    Index(i64),
+    /// `[start:end:step]`
+    Slice(Option<i64>, Option<i64>, i64),
+    /// `['name', index, ...]`
+    Union(Vec<Selector>),
+    /// `.*` or `[*]`
+    Wildcard,
+    /// `..selector`
+    Descendant(Box<Selector>),
+    /// `[?(expression)]`
+    Filter(Expression),
+}
+
+/// Filter expression.
+enum Expression {
+    Or(Box<Expression>, Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Not(Box<Expression>),
+    Compare(Operand, Comparison, Operand),
+    Exists(Operand),
+}
+
+/// Operand of the filter expression.
+enum Operand {
+    /// `@` followed by the selectors.
+    Current(Vec<Selector>),
+    /// `$` followed by the selectors.
+    Root(Vec<Selector>),
+    Literal(Json),
+}
+
+/// Comparison operator.
+#[derive(Clone, Copy)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Parsed path.
+pub(crate) struct JsonPath {
+    selectors: Vec<Selector>,
+    legacy: bool,
+}
+
+/// Path parser.
+struct Parser<'a> {
+    path: &'a [u8],
+    position: usize,
+    /// Nesting of the filter expressions being parsed.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.path.get(self.position).copied()
+    }
+    fn skip_whitespace(&mut self) {
+        while self.peek() == Some(b' ') {
+            self.position += 1;
+        }
+    }
+    fn expect(&mut self, literal: &[u8]) -> bool {
+        if self.path[self.position..].starts_with(literal) {
+            self.position += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+    /// Parse the selectors until the end of the path, or until the end of the operand.
+    fn parse_selectors(&mut self, operand: bool) -> Option<Vec<Selector>> {
+        let mut selectors = Vec::new();
+        loop {
+            match self.peek() {
+                Some(b'.') if self.expect(b"..") => {
+                    let selector = match self.peek() {
+                        Some(b'[') => self.parse_bracket()?,
+                        Some(b'*') => {
+                            self.position += 1;
+                            Selector::Wildcard
+                        }
+                        _ => Selector::Key(self.parse_name()?),
+                    };
+                    selectors.push(Selector::Descendant(Box::new(selector)));
+                }
+                Some(b'.') => {
+                    self.position += 1;
+                    if self.expect(b"*") {
+                        selectors.push(Selector::Wildcard);
+                    } else {
+                        selectors.push(Selector::Key(self.parse_name()?));
+                    }
+                }
+                Some(b'[') => selectors.push(self.parse_bracket()?),
+                None => return Some(selectors),
+                _ if operand => return Some(selectors),
+                _ => return None,
+            }
+        }
+    }
+    /// Parse the member name after the dot.
+    fn parse_name(&mut self) -> Option<String> {
+        let start = self.position;
+        while let Some(byte) = self.peek() {
+            if matches!(
+                byte,
+                b'.' | b'[' | b']' | b' ' | b'=' | b'!' | b'<' | b'>' | b'&' | b'|' | b')'
+            ) {
+                break;
+            }
+            self.position += 1;
+        }
+        if start == self.position {
+            return None;
+        }
+        String::from_utf8(self.path[start..self.position].to_vec()).ok()
+    }
+    /// Parse the selector in the brackets.
+    fn parse_bracket(&mut self) -> Option<Selector> {
+        self.position += 1;
+        self.skip_whitespace();
+        let selector = match self.peek()? {
+            b'*' => {
+                self.position += 1;
+                Selector::Wildcard
+            }
+            b'?' => {
+                self.position += 1;
+                self.skip_whitespace();
+                Selector::Filter(self.parse_or()?)
+            }
+            _ => {
+                let mut items = vec![self.parse_item()?];
+                self.skip_whitespace();
+                while self.expect(b",") {
+                    self.skip_whitespace();
+                    items.push(self.parse_item()?);
+                    self.skip_whitespace();
+                }
+                if items.len() == 1 {
+                    items.pop().unwrap()
+                } else {
+                    Selector::Union(items)
+                }
+            }
+        };
+        self.skip_whitespace();
+        if self.expect(b"]") {
+            Some(selector)
+        } else {
+            None
+        }
+    }
+    /// Parse the member name, index or slice in the brackets.
+    fn parse_item(&mut self) -> Option<Selector> {
+        match self.peek()? {
+            b'\'' | b'"' => Some(Selector::Key(self.parse_quoted()?)),
+            _ => {
+                let start = self.parse_integer();
+                self.skip_whitespace();
+                if !self.expect(b":") {
+                    return start.map(Selector::Index);
+                }
+                self.skip_whitespace();
+                let end = self.parse_integer();
+                self.skip_whitespace();
+                let step = if self.expect(b":") {
+                    self.skip_whitespace();
+                    self.parse_integer().unwrap_or(1)
+                } else {
+                    1
+                };
+                if step <= 0 {
+                    return None;
+                }
+                Some(Selector::Slice(start, end, step))
+            }
+        }
+    }
+    fn parse_integer(&mut self) -> Option<i64> {
+        let start = self.position;
+        self.expect(b"-");
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.path[start..self.position])
+            .ok()?
+            .parse()
+            .ok()
+    }
+    /// Parse the string quoted with the single or double quotes.
+    fn parse_quoted(&mut self) -> Option<String> {
+        let quote = self.peek()?;
+        self.position += 1;
+        let mut bytes = Vec::new();
+        loop {
+            match self.peek()? {
+                byte if byte == quote => break,
+                b'\\' => {
+                    self.position += 1;
+                    bytes.push(self.peek()?);
+                }
+                byte => bytes.push(byte),
+            }
+            self.position += 1;
+        }
+        self.position += 1;
+        String::from_utf8(bytes).ok()
+    }
+    fn parse_or(&mut self) -> Option<Expression> {
+        let mut expression = self.parse_and()?;
+        loop {
+            self.skip_whitespace();
+            if !self.expect(b"||") {
+                return Some(expression);
+            }
+            expression = Expression::Or(Box::new(expression), Box::new(self.parse_and()?));
+        }
+    }
+    fn parse_and(&mut self) -> Option<Expression> {
+        let mut expression = self.parse_unary()?;
+        loop {
+            self.skip_whitespace();
+            if !self.expect(b"&&") {
+                return Some(expression);
+            }
+            expression = Expression::And(Box::new(expression), Box::new(self.parse_unary()?));
+        }
+    }
+    /// Every nested expression is parsed here, so the depth is limited here.
+    fn parse_unary(&mut self) -> Option<Expression> {
+        if self.depth >= MAX_FILTER_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let expression = self.parse_term();
+        self.depth -= 1;
+        expression
+    }
+    fn parse_term(&mut self) -> Option<Expression> {
+        self.skip_whitespace();
+        if self.peek() == Some(b'!') && !self.path[self.position..].starts_with(b"!=") {
+            self.position += 1;
+            return Some(Expression::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.expect(b"(") {
+            let expression = self.parse_or()?;
+            self.skip_whitespace();
+            return if self.expect(b")") {
+                Some(expression)
+            } else {
+                None
+            };
+        }
+        let left = self.parse_operand()?;
+        self.skip_whitespace();
+        let comparison = if self.expect(b"==") {
+            Comparison::Eq
+        } else if self.expect(b"!=") {
+            Comparison::Ne
+        } else if self.expect(b"<=") {
+            Comparison::Le
+        } else if self.expect(b">=") {
+            Comparison::Ge
+        } else if self.expect(b"<") {
+            Comparison::Lt
+        } else if self.expect(b">") {
+            Comparison::Gt
+        } else {
+            return Some(Expression::Exists(left));
+        };
+        self.skip_whitespace();
+        Some(Expression::Compare(left, comparison, self.parse_operand()?))
+    }
+    fn parse_operand(&mut self) -> Option<Operand> {
+        match self.peek()? {
+            b'@' => {
+                self.position += 1;
+                Some(Operand::Current(self.parse_selectors(true)?))
+            }
+            b'$' => {
+                self.position += 1;
+                Some(Operand::Root(self.parse_selectors(true)?))
+            }
+            b'\'' | b'"' => Some(Operand::Literal(Json::String(self.parse_quoted()?))),
+            _ => {
+                // Numbers, true, false and null.
+                let start = self.position;
+                while let Some(b'-' | b'+' | b'.' | b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z') =
+                    self.peek()
+                {
+                    self.position += 1;
+                }
+                Some(Operand::Literal(
+                    Json::parse(&self.path[start..self.position]).ok()?,
+                ))
+            }
+        }
+    }
+}
+
+/// Normalize the index of the array.
+fn array_index(index: i64, len: usize) -> Option<usize> {
+    let index = if index < 0 { index + len as i64 } else { index };
+    if (0..len as i64).contains(&index) {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+/// Normalize the bound of the slice.
+fn slice_bound(bound: i64, len: usize) -> usize {
+    if bound < 0 {
+        (bound + len as i64).max(0) as usize
+    } else {
+        (bound as usize).min(len)
+    }
+}
+
+/// Compare the values of the filter expression.
+fn compare(left: &Json, comparison: Comparison, right: &Json) -> bool {
+    let ordering = match (left, right) {
+        (Json::String(left), Json::String(right)) => Some(left.cmp(right)),
+        (left, right) => match (left.as_f64(), right.as_f64()) {
+            (Some(left), Some(right)) => left.partial_cmp(&right),
+            _ if left == right => Some(Ordering::Equal),
+            _ => None,
+        },
+    };
+    match (comparison, ordering) {
+        (Comparison::Eq, Some(ordering)) => ordering == Ordering::Equal,
+        (Comparison::Eq, None) => false,
+        (Comparison::Ne, ordering) => ordering != Some(Ordering::Equal),
+        (Comparison::Lt, Some(ordering)) => ordering == Ordering::Less,
+        (Comparison::Le, Some(ordering)) => ordering != Ordering::Greater,
+        (Comparison::Gt, Some(ordering)) => ordering == Ordering::Greater,
+        (Comparison::Ge, Some(ordering)) => ordering != Ordering::Less,
+        _ => false,
+    }
+}
+
+impl Expression {
+    /// The value matches the filter or not.
+    fn matches(&self, value: &Json, root: &Json) -> bool {
+        match self {
+            Expression::Or(left, right) => left.matches(value, root) || right.matches(value, root),
+            Expression::And(left, right) => left.matches(value, root) && right.matches(value, root),
+            Expression::Not(expression) => !expression.matches(value, root),
+            Expression::Exists(operand) => operand.evaluate(value, root).is_some(),
+            Expression::Compare(left, comparison, right) => {
+                match (left.evaluate(value, root), right.evaluate(value, root)) {
+                    (Some(left), Some(right)) => compare(&left, *comparison, &right),
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+impl Operand {
+    /// The first value of the operand.
+    fn evaluate(&self, value: &Json, root: &Json) -> Option<Json> {
+        let (selectors, base) = match self {
+            Operand::Current(selectors) => (selectors, value),
+            Operand::Root(selectors) => (selectors, root),
+            Operand::Literal(literal) => return Some(literal.clone()),
+        };
+        let mut locations = Vec::new();
+        select(selectors, base, root, &mut Vec::new(), &mut locations);
+        let location = locations.first()?;
+        base.get(location).cloned()
+    }
+}
+
+/// Select the values matching the selectors and collect their locations.
+fn select(
+    selectors: &[Selector],
+    value: &Json,
+    root: &Json,
+    location: &mut Vec<JsonStep>,
+    locations: &mut Vec<Vec<JsonStep>>,
+) {
+    let (selector, rest) = match selectors.split_first() {
+        Some(split) => split,
+        None => {
+            locations.push(location.clone());
+            return;
+        }
+    };
+    let mut visit = |step: JsonStep, child: &Json, locations: &mut Vec<Vec<JsonStep>>| {
+        location.push(step);
+        select(rest, child, root, location, locations);
+        location.pop();
+    };
+    match (selector, value) {
+        (Selector::Key(key), Json::Object(_)) => {
+            if let Some(child) = value.member(key) {
+                visit(JsonStep::Key(key.clone()), child, locations);
+            }
+        }
+        (Selector::Index(index), Json::Array(array)) => {
+            if let Some(index) = array_index(*index, array.len()) {
+                visit(JsonStep::Index(index), &array[index], locations);
+            }
+        }
+        (Selector::Slice(start, end, step), Json::Array(array)) => {
+            let start = start.map_or(0, |start| slice_bound(start, array.len()));
+            let end = end.map_or(array.len(), |end| slice_bound(end, array.len()));
+            for index in (start..end).step_by(*step as usize) {
+                visit(JsonStep::Index(index), &array[index], locations);
+            }
+        }
+        (Selector::Union(items), _) => {
+            for item in items {
+                let mut selected = Vec::new();
+                select(
+                    std::slice::from_ref(item),
+                    value,
+                    root,
+                    &mut Vec::new(),
+                    &mut selected,
+                );
+                for mut step in selected {
+                    let child = value.get(&step).unwrap();
+                    visit(step.pop().unwrap(), child, locations);
+                }
+            }
+        }
+        (Selector::Wildcard, _) => {
+            for (step, child) in value.children() {
+                visit(step, child, locations);
+            }
+        }
+        (Selector::Filter(expression), _) => {
+            for (step, child) in value.children() {
+                if expression.matches(child, root) {
+                    visit(step, child, locations);
+                }
+            }
+        }
+        (Selector::Descendant(inner), _) => {
+            // Apply the selector to the value itself and to all of its descendants.
+            let mut selected = Vec::new();
+            select(
+                std::slice::from_ref(inner.as_ref()),
+                value,
+                root,
+                &mut Vec::new(),
+                &mut selected,
+            );
+            for mut step in selected {
+                let child = value.get(&step).unwrap();
+                visit(step.pop().unwrap(), child, locations);
+            }
+            for (step, child) in value.children() {
+                location.push(step);
+                select(selectors, child, root, location, locations);
+                location.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+impl JsonPath {
+    /// Parse the path.
+    /// Return None if the path is invalid.
+    pub(crate) fn parse(path: &[u8]) -> Option<JsonPath> {
+        let (path, legacy) = match path {
+            [b'$', rest @ ..] => (rest, false),
+            [b'.'] => (&path[1..], true),
+            [b'.' | b'[', ..] => (path, true),
+            _ => {
+                // The legacy path may start with the member name.
+                let mut parser = Parser {
+                    path,
+                    position: 0,
+                    depth: 0,
+                };
+                let mut selectors = vec![Selector::Key(parser.parse_name()?)];
+                selectors.extend(parser.parse_selectors(false)?);
+                return Some(JsonPath {
+                    selectors,
+                    legacy: true,
+                });
+            }
+        };
+        let mut parser = Parser {
+            path,
+            position: 0,
+            depth: 0,
+        };
+        Some(JsonPath {
+            selectors: parser.parse_selectors(false)?,
+            legacy,
+        })
+    }
+    /// The path is a legacy path or not.
+    pub(crate) fn is_legacy(&self) -> bool {
+        self.legacy
+    }
+    /// The path is the root or not.
+    pub(crate) fn is_root(&self) -> bool {
+        self.selectors.is_empty()
+    }
+    /// Locations of the selected values.
+    pub(crate) fn locate(&self, root: &Json) -> Vec<Vec<JsonStep>> {
+        let mut locations = Vec::new();
+        select(&self.selectors, root, root, &mut Vec::new(), &mut locations);
+        locations
+    }
+    /// Locations of the objects to add the new member selected by the path, with its name.
+    /// Return None if the last selector is not a member name.
+    pub(crate) fn locate_parents(&self, root: &Json) -> Option<(Vec<Vec<JsonStep>>, String)> {
+        let (last, parents) = self.selectors.split_last()?;
+        let key = match last {
+            Selector::Key(key) => key.clone(),
+            _ => return None,
+        };
+        let mut locations = Vec::new();
+        select(parents, root, root, &mut Vec::new(), &mut locations);
+        locations.retain(|location| {
+            matches!(root.get(location), Some(parent @ Json::Object(_)) if parent.member(&key).is_none())
+        });
+        Some((locations, key))
+    }
+}
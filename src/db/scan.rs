@@ -0,0 +1,117 @@
+//! Cursor based iteration and pattern matching.
+//!
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+/// Hash of the key that gives the iteration order.
+fn order(key: &[u8]) -> u64 {
+    // DefaultHasher::new() always uses the same keys.
+    let mut hasher = DefaultHasher::new();
+    hasher.write(key);
+    hasher.finish()
+}
+
+/// Scan the items with the cursor.
+///
+/// The items are visited in the order of the hash of their keys,
+/// so every item that exists during the full iteration is returned
+/// even if the collection is modified between the calls.
+///
+/// Return the next cursor and the items. The next cursor is zero at the end.
+pub(crate) fn scan<'a, T, I>(items: I, cursor: u64, count: usize) -> (u64, Vec<T>)
+where
+    I: Iterator<Item = (&'a [u8], T)>,
+{
+    let mut found: Vec<(u64, T)> = items
+        .map(|(key, item)| (order(key), item))
+        .filter(|(hash, _)| *hash >= cursor)
+        .collect();
+
+    if found.len() <= count {
+        return (0, found.into_iter().map(|(_, item)| item).collect());
+    }
+    found.select_nth_unstable_by_key(count - 1, |(hash, _)| *hash);
+    let last = found[count - 1].0;
+    // Items with the same hash are returned together.
+    let items = found
+        .into_iter()
+        .filter(|(hash, _)| *hash <= last)
+        .map(|(_, item)| item)
+        .collect();
+
+    (last.checked_add(1).unwrap_or(0), items)
+}
+
+/// Glob-style pattern matching.
+///
+/// Supports `*`, `?`, `[...]` with ranges and `^` negation, and `\` escape.
+pub(crate) fn glob_match(pattern: &[u8], string: &[u8]) -> bool {
+    let (mut p, mut s) = (0, 0);
+
+    while p < pattern.len() {
+        match pattern[p] {
+            b'*' => {
+                // Collapse consecutive stars.
+                while p + 1 < pattern.len() && pattern[p + 1] == b'*' {
+                    p += 1;
+                }
+                if p + 1 == pattern.len() {
+                    return true;
+                }
+                return (s..=string.len()).any(|i| glob_match(&pattern[p + 1..], &string[i..]));
+            }
+            b'?' => {
+                if s == string.len() {
+                    return false;
+                }
+                s += 1;
+            }
+            b'[' => {
+                if s == string.len() {
+                    return false;
+                }
+                p += 1;
+                let not = p < pattern.len() && pattern[p] == b'^';
+                if not {
+                    p += 1;
+                }
+                let mut matched = false;
+                while p < pattern.len() && pattern[p] != b']' {
+                    if pattern[p] == b'\\' && p + 1 < pattern.len() {
+                        p += 1;
+                        matched |= pattern[p] == string[s];
+                    } else if p + 2 < pattern.len() && pattern[p + 1] == b'-' {
+                        let (start, end) = if pattern[p] <= pattern[p + 2] {
+                            (pattern[p], pattern[p + 2])
+                        } else {
+                            (pattern[p + 2], pattern[p])
+                        };
+                        matched |= start <= string[s] && string[s] <= end;
+                        p += 2;
+                    } else {
+                        matched |= pattern[p] == string[s];
+                    }
+                    p += 1;
+                }
+                if matched == not {
+                    return false;
+                }
+                s += 1;
+            }
+            c => {
+                let c = if c == b'\\' && p + 1 < pattern.len() {
+                    p += 1;
+                    pattern[p]
+                } else {
+                    c
+                };
+                if s == string.len() || string[s] != c {
+                    return false;
+                }
+                s += 1;
+            }
+        }
+        p += 1;
+    }
+    s == string.len()
+}
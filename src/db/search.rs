@@ -0,0 +1,491 @@
+//! Full-text search index.
+//!
+//! The index keeps the string keys with the prefixes whose values are JSON objects.
+//! The fields of the schema are selected from the objects by JSONPath.
+//! The text is split into lowercase terms at the other characters than the alphanumerics,
+//! and each term maps to its positions in the documents to match the phrases.
+//! The tags map to the documents, and the numbers are kept in order to match the ranges.
+//! The matched documents are scored by TF-IDF weighted by the text fields.
+//!
+use super::json::Json;
+use super::jsonpath::JsonPath;
+use super::searchquery::{is_term_char, SearchQuery};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::Bound;
+
+/// Kind of the field.
+pub(crate) enum FieldKind {
+    Text {
+        weight: f64,
+    },
+    Tag {
+        separator: char,
+        case_sensitive: bool,
+    },
+    Numeric,
+}
+
+/// Field of the schema.
+pub(crate) struct SearchField {
+    /// JSONPath as given.
+    pub(crate) identifier: String,
+    /// Name of the field in the queries.
+    pub(crate) name: String,
+    pub(crate) path: JsonPath,
+    pub(crate) kind: FieldKind,
+    pub(crate) sortable: bool,
+}
+
+/// Number ordered totally.
+#[derive(Clone, Copy, PartialEq)]
+struct Number(f64);
+
+impl Eq for Number {}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Number {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Indexed document.
+struct Document {
+    key: Vec<u8>,
+    /// Distinct terms of the text fields.
+    terms: Vec<String>,
+    /// Tags with the indexes of their fields.
+    tags: Vec<(usize, String)>,
+    /// Numbers with the indexes of their fields.
+    numbers: Vec<(usize, f64)>,
+    /// First selected value of each field.
+    values: Vec<Option<Json>>,
+}
+
+/// Split the text into the lowercase terms.
+pub(super) fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !is_term_char(c))
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Text of the scalar value.
+fn text(value: &Json) -> Option<String> {
+    match value {
+        Json::String(string) => Some(string.clone()),
+        Json::Bool(_) | Json::Integer(_) | Json::Float(_) => {
+            Some(String::from_utf8_lossy(&value.to_bytes()).into_owned())
+        }
+        _ => None,
+    }
+}
+
+/// Order of the values to sort by. Numbers come before strings.
+fn compare_values(a: &Json, b: &Json) -> Ordering {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => text(a)
+            .map(|a| a.to_lowercase())
+            .cmp(&text(b).map(|b| b.to_lowercase())),
+    }
+}
+
+/// Full-text search index.
+pub(crate) struct SearchIndex {
+    prefixes: Vec<Vec<u8>>,
+    fields: Vec<SearchField>,
+    documents: HashMap<u32, Document>,
+    ids: HashMap<Vec<u8>, u32>,
+    next_id: u32,
+    /// Documents of each term with the field indexes and the positions.
+    terms: BTreeMap<String, HashMap<u32, Vec<(usize, u32)>>>,
+    /// Documents of each tag for each field.
+    tags: Vec<HashMap<String, HashSet<u32>>>,
+    /// Numbers with the documents for each field.
+    numbers: Vec<BTreeSet<(Number, u32)>>,
+    /// Number of the values failed to index.
+    failures: usize,
+}
+
+impl SearchIndex {
+    /// Create an empty index of the keys with the prefixes.
+    /// No prefixes means all the keys.
+    pub(crate) fn new(prefixes: Vec<Vec<u8>>, fields: Vec<SearchField>) -> Self {
+        SearchIndex {
+            prefixes,
+            tags: fields.iter().map(|_| HashMap::new()).collect(),
+            numbers: fields.iter().map(|_| BTreeSet::new()).collect(),
+            fields,
+            documents: HashMap::new(),
+            ids: HashMap::new(),
+            next_id: 0,
+            terms: BTreeMap::new(),
+            failures: 0,
+        }
+    }
+    pub(crate) fn prefixes(&self) -> &[Vec<u8>] {
+        &self.prefixes
+    }
+    pub(crate) fn fields(&self) -> &[SearchField] {
+        &self.fields
+    }
+    /// Number of the documents.
+    pub(crate) fn len(&self) -> usize {
+        self.documents.len()
+    }
+    /// Number of the distinct terms.
+    pub(crate) fn term_count(&self) -> usize {
+        self.terms.len()
+    }
+    /// Number of the pairs of the terms and the documents.
+    pub(crate) fn record_count(&self) -> usize {
+        self.terms.values().map(HashMap::len).sum()
+    }
+    pub(crate) fn failures(&self) -> usize {
+        self.failures
+    }
+    /// Keys of the documents.
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &[u8]> {
+        self.ids.keys().map(Vec::as_slice)
+    }
+    /// The key is indexed by this index or not.
+    pub(crate) fn matches(&self, key: &[u8]) -> bool {
+        self.prefixes.is_empty() || self.prefixes.iter().any(|prefix| key.starts_with(prefix))
+    }
+    /// Index the new value of the key, or remove the key if there is no value.
+    /// The values other than the JSON objects are not indexed.
+    pub(crate) fn update(&mut self, key: &[u8], value: Option<&[u8]>) {
+        let id = self.ids.remove(key);
+        if let Some(id) = id {
+            self.remove(id);
+        }
+        let value = match value {
+            Some(value) => value,
+            None => return,
+        };
+        let document = match Json::parse(value) {
+            Ok(document @ Json::Object(_)) => document,
+            _ => {
+                self.failures += 1;
+                return;
+            }
+        };
+        // The updated key keeps its ID, and its order in the results.
+        let id = id.unwrap_or_else(|| {
+            self.next_id += 1;
+            self.next_id
+        });
+        self.insert(id, key.to_vec(), &document);
+        self.ids.insert(key.to_vec(), id);
+    }
+    /// First selected value of the field of the key.
+    pub(crate) fn value(&self, key: &[u8], index: usize) -> Option<&Json> {
+        self.documents[self.ids.get(key)?].values[index].as_ref()
+    }
+    /// Index of the field with the name.
+    pub(crate) fn field(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.name == name)
+    }
+    /// Search the documents matching the query.
+    /// The documents are sorted by the field in the order, or in descending order of the scores.
+    /// Return the keys with the scores, or the error message.
+    pub(crate) fn search(
+        &self,
+        query: &SearchQuery,
+        sort_by: Option<(usize, bool)>,
+    ) -> Result<Vec<(&[u8], f64)>, String> {
+        let mut hits: Vec<(u32, f64)> = self.evaluate(query)?.into_iter().collect();
+        match sort_by {
+            None => hits.sort_unstable_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0))),
+            Some((index, ascending)) => hits.sort_unstable_by(|a, b| {
+                let ordering = match (
+                    &self.documents[&a.0].values[index],
+                    &self.documents[&b.0].values[index],
+                ) {
+                    (Some(a), Some(b)) if ascending => compare_values(a, b),
+                    (Some(a), Some(b)) => compare_values(b, a),
+                    // The documents without the value come last.
+                    (a, b) => a.is_none().cmp(&b.is_none()),
+                };
+                ordering.then(a.0.cmp(&b.0))
+            }),
+        }
+        Ok(hits
+            .into_iter()
+            .map(|(id, score)| (self.documents[&id].key.as_slice(), score))
+            .collect())
+    }
+    fn insert(&mut self, id: u32, key: Vec<u8>, value: &Json) {
+        let mut document = Document {
+            key,
+            terms: Vec::new(),
+            tags: Vec::new(),
+            numbers: Vec::new(),
+            values: Vec::with_capacity(self.fields.len()),
+        };
+        let mut terms = HashSet::new();
+        for (index, field) in self.fields.iter().enumerate() {
+            let selected: Vec<&Json> = field
+                .path
+                .locate(value)
+                .iter()
+                .filter_map(|location| value.get(location))
+                .collect();
+            document
+                .values
+                .push(selected.first().map(|&value| value.clone()));
+            // The elements of the arrays are indexed as the values.
+            let values = selected.into_iter().flat_map(|value| match value {
+                Json::Array(array) => array.iter().collect(),
+                value => vec![value],
+            });
+            match field.kind {
+                FieldKind::Text { .. } => {
+                    let mut position = 0;
+                    for text in values.filter_map(text) {
+                        for term in tokenize(&text) {
+                            self.terms
+                                .entry(term.clone())
+                                .or_default()
+                                .entry(id)
+                                .or_default()
+                                .push((index, position));
+                            terms.insert(term);
+                            position += 1;
+                        }
+                        // Phrases do not continue to the next value.
+                        position += 1;
+                    }
+                }
+                FieldKind::Tag {
+                    separator,
+                    case_sensitive,
+                } => {
+                    for text in values.filter_map(text) {
+                        for tag in text.split(separator).map(str::trim) {
+                            if tag.is_empty() {
+                                continue;
+                            }
+                            let tag = match case_sensitive {
+                                true => tag.to_string(),
+                                false => tag.to_lowercase(),
+                            };
+                            self.tags[index].entry(tag.clone()).or_default().insert(id);
+                            document.tags.push((index, tag));
+                        }
+                    }
+                }
+                FieldKind::Numeric => {
+                    for number in values.filter_map(Json::as_f64) {
+                        self.numbers[index].insert((Number(number), id));
+                        document.numbers.push((index, number));
+                    }
+                }
+            }
+        }
+        document.terms = terms.into_iter().collect();
+        self.documents.insert(id, document);
+    }
+    fn remove(&mut self, id: u32) {
+        let document = self.documents.remove(&id).unwrap();
+        for term in document.terms {
+            if let Some(postings) = self.terms.get_mut(&term) {
+                postings.remove(&id);
+                if postings.is_empty() {
+                    self.terms.remove(&term);
+                }
+            }
+        }
+        for (index, tag) in document.tags {
+            if let Some(ids) = self.tags[index].get_mut(&tag) {
+                ids.remove(&id);
+                if ids.is_empty() {
+                    self.tags[index].remove(&tag);
+                }
+            }
+        }
+        for (index, number) in document.numbers {
+            self.numbers[index].remove(&(Number(number), id));
+        }
+    }
+    /// Index of the field of the kind in the query.
+    fn query_field(&self, name: &str, kind: &str) -> Result<usize, String> {
+        let index = match self.field(name) {
+            Some(index) => index,
+            None => return Err(format!("Unknown field `{}`", name)),
+        };
+        let matched = match self.fields[index].kind {
+            FieldKind::Text { .. } => kind == "TEXT",
+            FieldKind::Tag { .. } => kind == "TAG",
+            FieldKind::Numeric => kind == "NUMERIC",
+        };
+        if !matched {
+            return Err(format!("Field `{}` is not a {} field", name, kind));
+        }
+        Ok(index)
+    }
+    /// Index of the text field restricting the terms. None means all the text fields.
+    fn text_field(&self, name: &Option<String>) -> Result<Option<usize>, String> {
+        name.as_deref()
+            .map(|name| self.query_field(name, "TEXT"))
+            .transpose()
+    }
+    fn weight(&self, index: usize) -> f64 {
+        match self.fields[index].kind {
+            FieldKind::Text { weight } => weight,
+            _ => 0.0,
+        }
+    }
+    /// Inverse document frequency of the term in the documents.
+    fn idf(&self, documents: usize) -> f64 {
+        (1.0 + self.documents.len() as f64 / documents as f64).ln()
+    }
+    /// Scores of the documents matching the query.
+    fn evaluate(&self, query: &SearchQuery) -> Result<HashMap<u32, f64>, String> {
+        let mut scores = HashMap::new();
+        match query {
+            SearchQuery::All => scores.extend(self.documents.keys().map(|&id| (id, 1.0))),
+            SearchQuery::Term {
+                field,
+                term,
+                prefix,
+            } => {
+                let field = self.text_field(field)?;
+                let postings: Vec<&HashMap<u32, Vec<(usize, u32)>>> = match prefix {
+                    true => self
+                        .terms
+                        .range::<String, _>((Bound::Included(term), Bound::Unbounded))
+                        .take_while(|(candidate, _)| candidate.starts_with(term.as_str()))
+                        .map(|(_, postings)| postings)
+                        .collect(),
+                    false => self.terms.get(term).into_iter().collect(),
+                };
+                for postings in postings {
+                    let idf = self.idf(postings.len());
+                    for (&id, positions) in postings {
+                        let frequency: f64 = positions
+                            .iter()
+                            .filter(|(index, _)| field.is_none_or(|field| field == *index))
+                            .map(|&(index, _)| self.weight(index))
+                            .sum();
+                        if frequency > 0.0 {
+                            *scores.entry(id).or_insert(0.0) += frequency * idf;
+                        }
+                    }
+                }
+            }
+            SearchQuery::Phrase { field, terms } => {
+                let field = self.text_field(field)?;
+                let postings: Vec<&HashMap<u32, Vec<(usize, u32)>>> =
+                    match terms.iter().map(|term| self.terms.get(term)).collect() {
+                        Some(postings) => postings,
+                        None => return Ok(scores),
+                    };
+                let idf: f64 = postings
+                    .iter()
+                    .map(|postings| self.idf(postings.len()))
+                    .sum();
+                for (&id, positions) in postings[0] {
+                    let frequency: f64 = positions
+                        .iter()
+                        .filter(|&&(index, position)| {
+                            field.is_none_or(|field| field == index)
+                                && postings[1..].iter().enumerate().all(|(offset, postings)| {
+                                    postings.get(&id).is_some_and(|positions| {
+                                        positions.contains(&(index, position + offset as u32 + 1))
+                                    })
+                                })
+                        })
+                        .map(|&(index, _)| self.weight(index))
+                        .sum();
+                    if frequency > 0.0 {
+                        scores.insert(id, frequency * idf);
+                    }
+                }
+            }
+            SearchQuery::Range { field, min, max } => {
+                let index = self.query_field(field, "NUMERIC")?;
+                let min = match *min {
+                    Bound::Included(min) => Bound::Included((Number(min), u32::MIN)),
+                    Bound::Excluded(min) => Bound::Excluded((Number(min), u32::MAX)),
+                    Bound::Unbounded => Bound::Unbounded,
+                };
+                let max = match *max {
+                    Bound::Included(max) => Bound::Included((Number(max), u32::MAX)),
+                    Bound::Excluded(max) => Bound::Excluded((Number(max), u32::MIN)),
+                    Bound::Unbounded => Bound::Unbounded,
+                };
+                // The empty range panics.
+                let empty = match (min, max) {
+                    (
+                        Bound::Included(min) | Bound::Excluded(min),
+                        Bound::Included(max) | Bound::Excluded(max),
+                    ) => min > max,
+                    _ => false,
+                };
+                if !empty {
+                    scores.extend(
+                        self.numbers[index]
+                            .range((min, max))
+                            .map(|&(_, id)| (id, 1.0)),
+                    );
+                }
+            }
+            SearchQuery::Tags { field, tags } => {
+                let index = self.query_field(field, "TAG")?;
+                let case_sensitive = matches!(
+                    self.fields[index].kind,
+                    FieldKind::Tag {
+                        case_sensitive: true,
+                        ..
+                    }
+                );
+                for tag in tags {
+                    let tag = match case_sensitive {
+                        true => tag.clone(),
+                        false => tag.to_lowercase(),
+                    };
+                    if let Some(ids) = self.tags[index].get(&tag) {
+                        scores.extend(ids.iter().map(|&id| (id, 1.0)));
+                    }
+                }
+            }
+            SearchQuery::And(queries) => {
+                scores = self.evaluate(&queries[0])?;
+                for query in &queries[1..] {
+                    let other = self.evaluate(query)?;
+                    scores.retain(|id, _| other.contains_key(id));
+                    for (id, score) in scores.iter_mut() {
+                        *score += other[id];
+                    }
+                }
+            }
+            SearchQuery::Or(queries) => {
+                for query in queries {
+                    for (id, score) in self.evaluate(query)? {
+                        *scores.entry(id).or_insert(0.0) += score;
+                    }
+                }
+            }
+            SearchQuery::Not(query) => {
+                let excluded = self.evaluate(query)?;
+                scores.extend(
+                    self.documents
+                        .keys()
+                        .filter(|id| !excluded.contains_key(id))
+                        .map(|&id| (id, 1.0)),
+                );
+            }
+        }
+        Ok(scores)
+    }
+}
@@ -0,0 +1,291 @@
+//! Query of the full-text search index.
+//!
+//! The terms separated by the spaces are intersected, `|` unites the alternatives,
+//! `-` negates the following term or group, and the parentheses group them.
+//! `|` binds looser than the intersection.
+//! `"..."` matches the phrase, `prefix*` matches the terms with the prefix,
+//! and `*` matches all the documents.
+//! `@field:` restricts the following term, phrase or group to the text field,
+//! `@field:[min max]` matches the numeric range where `(` excludes the bound,
+//! and `@field:{tag | ...}` matches any of the tags.
+//!
+use super::search::tokenize;
+use std::ops::Bound;
+
+/// Maximum nesting of the terms and the groups.
+const MAX_DEPTH: usize = 128;
+
+/// Parsed query.
+pub(crate) enum SearchQuery {
+    All,
+    /// The term in the text field, or in any text field.
+    Term {
+        field: Option<String>,
+        term: String,
+        prefix: bool,
+    },
+    /// The consecutive terms in the same text field.
+    Phrase {
+        field: Option<String>,
+        terms: Vec<String>,
+    },
+    Range {
+        field: String,
+        min: Bound<f64>,
+        max: Bound<f64>,
+    },
+    Tags {
+        field: String,
+        tags: Vec<String>,
+    },
+    And(Vec<SearchQuery>),
+    Or(Vec<SearchQuery>),
+    Not(Box<SearchQuery>),
+}
+
+/// Query parser.
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    /// Nesting of the terms and the groups being parsed.
+    depth: usize,
+}
+
+/// The character is a part of the terms.
+pub(super) fn is_term_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+    fn expect(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+    fn error(&self) -> String {
+        format!("Syntax error at offset {}", self.position)
+    }
+    fn parse_union(&mut self, field: Option<&str>) -> Result<SearchQuery, String> {
+        let mut alternatives = vec![self.parse_intersection(field)?];
+        loop {
+            self.skip_whitespace();
+            if !self.expect('|') {
+                break;
+            }
+            alternatives.push(self.parse_intersection(field)?);
+        }
+        Ok(match alternatives.len() {
+            1 => alternatives.pop().unwrap(),
+            _ => SearchQuery::Or(alternatives),
+        })
+    }
+    fn parse_intersection(&mut self, field: Option<&str>) -> Result<SearchQuery, String> {
+        let mut queries = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None | Some(')' | '|') => break,
+                _ => queries.push(self.parse_unary(field)?),
+            }
+        }
+        match queries.len() {
+            0 => Err(self.error()),
+            1 => Ok(queries.pop().unwrap()),
+            _ => Ok(SearchQuery::And(queries)),
+        }
+    }
+    /// Every nested term and group is parsed here, so the depth is limited here.
+    fn parse_unary(&mut self, field: Option<&str>) -> Result<SearchQuery, String> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.depth += 1;
+        let query = if self.expect('-') {
+            self.parse_unary(field)
+                .map(|query| SearchQuery::Not(Box::new(query)))
+        } else {
+            self.parse_primary(field)
+        };
+        self.depth -= 1;
+        query
+    }
+    fn parse_primary(&mut self, field: Option<&str>) -> Result<SearchQuery, String> {
+        match self.peek() {
+            Some('(') => {
+                self.position += 1;
+                let query = self.parse_union(field)?;
+                self.skip_whitespace();
+                if !self.expect(')') {
+                    return Err(self.error());
+                }
+                Ok(query)
+            }
+            Some('@') => {
+                self.position += 1;
+                let start = self.position;
+                while self.peek().is_some_and(|c| c != ':' && !c.is_whitespace()) {
+                    self.position += 1;
+                }
+                let name: String = self.chars[start..self.position].iter().collect();
+                if name.is_empty() || !self.expect(':') {
+                    return Err(self.error());
+                }
+                self.skip_whitespace();
+                match self.peek() {
+                    Some('[') => self.parse_range(name),
+                    Some('{') => self.parse_tags(name),
+                    _ => self.parse_unary(Some(&name)),
+                }
+            }
+            Some('"') => {
+                self.position += 1;
+                let start = self.position;
+                while self.peek().is_some_and(|c| c != '"') {
+                    self.position += 1;
+                }
+                let text: String = self.chars[start..self.position].iter().collect();
+                if !self.expect('"') {
+                    return Err(self.error());
+                }
+                let mut terms = tokenize(&text);
+                let field = field.map(str::to_string);
+                match terms.len() {
+                    0 => Err(self.error()),
+                    1 => Ok(SearchQuery::Term {
+                        field,
+                        term: terms.pop().unwrap(),
+                        prefix: false,
+                    }),
+                    _ => Ok(SearchQuery::Phrase { field, terms }),
+                }
+            }
+            Some('*') => {
+                self.position += 1;
+                Ok(SearchQuery::All)
+            }
+            _ => {
+                let term = self.parse_term();
+                if term.is_empty() {
+                    return Err(self.error());
+                }
+                Ok(SearchQuery::Term {
+                    field: field.map(str::to_string),
+                    term: term.to_lowercase(),
+                    prefix: self.expect('*'),
+                })
+            }
+        }
+    }
+    /// Parse the term. The backslash escapes the following character.
+    fn parse_term(&mut self) -> String {
+        let mut term = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\\' {
+                self.position += 1;
+                match self.peek() {
+                    Some(c) => term.push(c),
+                    None => break,
+                }
+            } else if is_term_char(c) {
+                term.push(c);
+            } else {
+                break;
+            }
+            self.position += 1;
+        }
+        term
+    }
+    fn parse_range(&mut self, field: String) -> Result<SearchQuery, String> {
+        self.position += 1;
+        let min = self.parse_bound(f64::NEG_INFINITY)?;
+        let max = self.parse_bound(f64::INFINITY)?;
+        self.skip_whitespace();
+        if !self.expect(']') {
+            return Err(self.error());
+        }
+        Ok(SearchQuery::Range { field, min, max })
+    }
+    /// Parse the bound of the range. The infinity on the open side is unbounded.
+    fn parse_bound(&mut self, open: f64) -> Result<Bound<f64>, String> {
+        self.skip_whitespace();
+        let exclusive = self.expect('(');
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c != ']' && c != ',' && !c.is_whitespace())
+        {
+            self.position += 1;
+        }
+        let text: String = self.chars[start..self.position].iter().collect();
+        self.skip_whitespace();
+        self.expect(',');
+        match text.parse::<f64>() {
+            Ok(value) if value == open => Ok(Bound::Unbounded),
+            Ok(value) if !value.is_nan() && exclusive => Ok(Bound::Excluded(value)),
+            Ok(value) if !value.is_nan() => Ok(Bound::Included(value)),
+            _ => Err(format!("Bad range bound `{}`", text)),
+        }
+    }
+    fn parse_tags(&mut self, field: String) -> Result<SearchQuery, String> {
+        self.position += 1;
+        let mut tags = Vec::new();
+        let mut tag = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error()),
+                Some('\\') => {
+                    self.position += 1;
+                    if let Some(c) = self.peek() {
+                        tag.push(c);
+                    }
+                }
+                Some(c @ ('|' | '}')) => {
+                    let trimmed = tag.trim();
+                    if !trimmed.is_empty() {
+                        tags.push(trimmed.to_string());
+                    }
+                    tag.clear();
+                    if c == '}' {
+                        self.position += 1;
+                        break;
+                    }
+                }
+                Some(c) => tag.push(c),
+            }
+            self.position += 1;
+        }
+        if tags.is_empty() {
+            return Err(self.error());
+        }
+        Ok(SearchQuery::Tags { field, tags })
+    }
+}
+
+impl SearchQuery {
+    /// Parse the query.
+    /// Return the error message if it is invalid.
+    pub(crate) fn parse(text: &[u8]) -> Result<SearchQuery, String> {
+        let mut parser = Parser {
+            chars: String::from_utf8_lossy(text).chars().collect(),
+            position: 0,
+            depth: 0,
+        };
+        let query = parser.parse_union(None)?;
+        parser.skip_whitespace();
+        if parser.position < parser.chars.len() {
+            return Err(parser.error());
+        }
+        Ok(query)
+    }
+}
@@ -0,0 +1,718 @@
+//! Stream value.
+//!
+//! Entries are stored in a chunked log of nodes keyed by their master ID.
+//! Each node packs up to MAX_NODE_ENTRIES entries into a byte buffer.
+//! IDs are encoded as deltas from the master ID, and field names are
+//! omitted when they are the same as the fields of the first entry.
+//!
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::ops::Bound;
+
+/// Maximum number of entries in a node.
+const MAX_NODE_ENTRIES: usize = 100;
+/// Maximum size of the byte buffer of a node.
+const MAX_NODE_BYTES: usize = 4096;
+/// The entry has been deleted.
+const FLAG_DELETED: u8 = 1;
+/// The entry has the same fields as the master fields.
+const FLAG_SAME_FIELDS: u8 = 2;
+
+/// ID of the stream entry.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct StreamId {
+    /// Milliseconds part.
+    pub(crate) ms: u64,
+    /// Sequence number part.
+    pub(crate) seq: u64,
+}
+
+impl StreamId {
+    /// The smallest ID.
+    pub(crate) const MIN: StreamId = StreamId { ms: 0, seq: 0 };
+    /// The largest ID.
+    pub(crate) const MAX: StreamId = StreamId {
+        ms: u64::MAX,
+        seq: u64::MAX,
+    };
+
+    /// Parse "ms-seq" or "ms".
+    /// The sequence number is default_seq if it is omitted.
+    pub(crate) fn parse(bytes: &[u8], default_seq: u64) -> Option<StreamId> {
+        let string = std::str::from_utf8(bytes).ok()?;
+        let (ms, seq) = match string.split_once('-') {
+            Some((ms, seq)) => (ms, Some(seq)),
+            None => (string, None),
+        };
+        let number = |s: &str| {
+            if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+                None
+            } else {
+                s.parse::<u64>().ok()
+            }
+        };
+        Some(StreamId {
+            ms: number(ms)?,
+            seq: match seq {
+                Some(seq) => number(seq)?,
+                None => default_seq,
+            },
+        })
+    }
+    /// The next ID.
+    pub(crate) fn next(self) -> Option<StreamId> {
+        match self.seq.checked_add(1) {
+            Some(seq) => Some(StreamId { ms: self.ms, seq }),
+            None => Some(StreamId {
+                ms: self.ms.checked_add(1)?,
+                seq: 0,
+            }),
+        }
+    }
+    /// The previous ID.
+    pub(crate) fn prev(self) -> Option<StreamId> {
+        match self.seq.checked_sub(1) {
+            Some(seq) => Some(StreamId { ms: self.ms, seq }),
+            None => Some(StreamId {
+                ms: self.ms.checked_sub(1)?,
+                seq: u64::MAX,
+            }),
+        }
+    }
+    /// "ms-seq" bytes.
+    pub(crate) fn to_bytes(self) -> Vec<u8> {
+        self.to_string().into_bytes()
+    }
+}
+
+impl fmt::Display for StreamId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.ms, self.seq)
+    }
+}
+
+/// Field-value pairs of the entry.
+pub(crate) type Fields = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Trimming strategy.
+pub(crate) enum Trim {
+    /// Keep at most this number of entries.
+    MaxLen(usize),
+    /// Remove the entries with IDs lower than this.
+    MinId(StreamId),
+}
+
+/// Entry delivered to a consumer and not yet acknowledged.
+pub(crate) struct PendingEntry {
+    /// Name of the consumer.
+    pub(crate) consumer: Vec<u8>,
+    /// Unix time of the last delivery in milliseconds.
+    pub(crate) delivery_time: u64,
+    /// Number of the deliveries.
+    pub(crate) delivery_count: u64,
+}
+
+/// Consumer of the group.
+pub(crate) struct Consumer {
+    /// Unix time of the last interaction in milliseconds.
+    pub(crate) seen_time: u64,
+    /// Unix time of the last successful interaction in milliseconds.
+    pub(crate) active_time: Option<u64>,
+    /// IDs of the pending entries of the consumer.
+    pub(crate) pending: BTreeSet<StreamId>,
+}
+
+/// Consumer group.
+pub(crate) struct Group {
+    /// The last ID delivered to the consumers.
+    pub(crate) last_id: StreamId,
+    /// Number of the entries read by the group, if it is known.
+    pub(crate) entries_read: Option<u64>,
+    /// Pending entries list.
+    pub(crate) pending: BTreeMap<StreamId, PendingEntry>,
+    /// Consumers by name.
+    pub(crate) consumers: BTreeMap<Vec<u8>, Consumer>,
+}
+
+impl Group {
+    /// Create a group without consumers.
+    pub(crate) fn new(last_id: StreamId, entries_read: Option<u64>) -> Self {
+        Group {
+            last_id,
+            entries_read,
+            pending: BTreeMap::new(),
+            consumers: BTreeMap::new(),
+        }
+    }
+    /// Create the consumer.
+    /// Return true if the consumer is new.
+    pub(crate) fn create_consumer(&mut self, name: &[u8], now: u64) -> bool {
+        if self.consumers.contains_key(name) {
+            return false;
+        }
+        self.consumer(name, now);
+        true
+    }
+    /// Get the consumer, creating it if it does not exist.
+    /// The seen time of the consumer is updated.
+    pub(crate) fn consumer(&mut self, name: &[u8], now: u64) -> &mut Consumer {
+        let consumer = self
+            .consumers
+            .entry(name.to_vec())
+            .or_insert_with(|| Consumer {
+                seen_time: now,
+                active_time: None,
+                pending: BTreeSet::new(),
+            });
+        consumer.seen_time = now;
+        consumer
+    }
+    /// Delete the consumer and its pending entries.
+    /// Return the number of the pending entries, or None if it does not exist.
+    pub(crate) fn delete_consumer(&mut self, name: &[u8]) -> Option<usize> {
+        let consumer = self.consumers.remove(name)?;
+        for id in &consumer.pending {
+            self.pending.remove(id);
+        }
+        Some(consumer.pending.len())
+    }
+    /// Acknowledge the pending entry.
+    /// Return true if the entry was pending.
+    pub(crate) fn ack(&mut self, id: StreamId) -> bool {
+        match self.pending.remove(&id) {
+            Some(entry) => {
+                if let Some(consumer) = self.consumers.get_mut(&entry.consumer) {
+                    consumer.pending.remove(&id);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+    /// Assign the pending entry to the consumer.
+    /// The entry is moved from the current owner if it is already pending.
+    pub(crate) fn assign(
+        &mut self,
+        id: StreamId,
+        consumer: &[u8],
+        delivery_time: u64,
+        delivery_count: u64,
+    ) {
+        if let Some(entry) = self.pending.get(&id) {
+            if let Some(owner) = self.consumers.get_mut(&entry.consumer) {
+                owner.pending.remove(&id);
+            }
+        }
+        self.pending.insert(
+            id,
+            PendingEntry {
+                consumer: consumer.to_vec(),
+                delivery_time,
+                delivery_count,
+            },
+        );
+        if let Some(owner) = self.consumers.get_mut(consumer) {
+            owner.pending.insert(id);
+        }
+    }
+}
+
+/// Node of the chunked log.
+struct Node {
+    /// Fields of the first entry.
+    master_fields: Vec<Vec<u8>>,
+    /// Encoded entries.
+    data: Vec<u8>,
+    /// Number of the entries including the deleted ones.
+    count: usize,
+    /// Number of the entries not deleted.
+    live: usize,
+    /// ID of the last entry.
+    last: StreamId,
+}
+
+/// Entry position in the node.
+struct RawEntry {
+    /// Offset of the flags.
+    offset: usize,
+    /// Entry ID.
+    id: StreamId,
+    /// Entry flags.
+    flags: u8,
+    /// Offset of the field-value pairs.
+    fields: usize,
+}
+
+/// Append the variable-length integer.
+fn put_varint(data: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        data.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    data.push(value as u8);
+}
+
+/// Read the variable-length integer.
+fn get_varint(data: &[u8], pos: &mut usize) -> u64 {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = data[*pos];
+        *pos += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte < 0x80 {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+/// Append the length-prefixed bytes.
+fn put_bytes(data: &mut Vec<u8>, bytes: &[u8]) {
+    put_varint(data, bytes.len() as u64);
+    data.extend_from_slice(bytes);
+}
+
+/// Read the length-prefixed bytes.
+fn get_bytes<'a>(data: &'a [u8], pos: &mut usize) -> &'a [u8] {
+    let len = get_varint(data, pos) as usize;
+    let bytes = &data[*pos..*pos + len];
+    *pos += len;
+    bytes
+}
+
+impl Node {
+    /// Create a node with the first entry.
+    fn new(master: StreamId, fields: &Fields) -> Self {
+        let mut node = Node {
+            master_fields: fields.iter().map(|(field, _)| field.clone()).collect(),
+            data: Vec::new(),
+            count: 0,
+            live: 0,
+            last: master,
+        };
+        node.push(master, master, fields);
+        node
+    }
+    /// The node has room for another entry or not.
+    fn has_room(&self) -> bool {
+        self.count < MAX_NODE_ENTRIES && self.data.len() < MAX_NODE_BYTES
+    }
+    /// Append the entry.
+    fn push(&mut self, master: StreamId, id: StreamId, fields: &Fields) {
+        let same_fields = fields.len() == self.master_fields.len()
+            && fields
+                .iter()
+                .zip(&self.master_fields)
+                .all(|((field, _), master_field)| field == master_field);
+        self.data
+            .push(if same_fields { FLAG_SAME_FIELDS } else { 0 });
+        put_varint(&mut self.data, id.ms - master.ms);
+        if id.ms == master.ms {
+            put_varint(&mut self.data, id.seq - master.seq);
+        } else {
+            put_varint(&mut self.data, id.seq);
+        }
+        put_varint(&mut self.data, fields.len() as u64);
+        for (field, value) in fields {
+            if !same_fields {
+                put_bytes(&mut self.data, field);
+            }
+            put_bytes(&mut self.data, value);
+        }
+        self.count += 1;
+        self.live += 1;
+        self.last = id;
+    }
+    /// Decode the positions of all entries including the deleted ones.
+    fn raw_entries(&self, master: StreamId) -> Vec<RawEntry> {
+        let mut entries = Vec::with_capacity(self.count);
+        let mut pos = 0;
+        while pos < self.data.len() {
+            let offset = pos;
+            let flags = self.data[pos];
+            pos += 1;
+            let ms_delta = get_varint(&self.data, &mut pos);
+            let seq = get_varint(&self.data, &mut pos);
+            let id = if ms_delta == 0 {
+                StreamId {
+                    ms: master.ms,
+                    seq: master.seq + seq,
+                }
+            } else {
+                StreamId {
+                    ms: master.ms + ms_delta,
+                    seq,
+                }
+            };
+            let fields = pos;
+            let len = get_varint(&self.data, &mut pos);
+            for _ in 0..len {
+                if flags & FLAG_SAME_FIELDS == 0 {
+                    get_bytes(&self.data, &mut pos);
+                }
+                get_bytes(&self.data, &mut pos);
+            }
+            entries.push(RawEntry {
+                offset,
+                id,
+                flags,
+                fields,
+            });
+        }
+        entries
+    }
+    /// Decode the field-value pairs of the entry.
+    fn fields(&self, entry: &RawEntry) -> Fields {
+        let mut pos = entry.fields;
+        let len = get_varint(&self.data, &mut pos) as usize;
+        let mut fields = Vec::with_capacity(len);
+        for i in 0..len {
+            let field = if entry.flags & FLAG_SAME_FIELDS != 0 {
+                self.master_fields[i].clone()
+            } else {
+                get_bytes(&self.data, &mut pos).to_vec()
+            };
+            let value = get_bytes(&self.data, &mut pos).to_vec();
+            fields.push((field, value));
+        }
+        fields
+    }
+    /// Mark the entry as deleted.
+    fn delete(&mut self, entry: &RawEntry) {
+        self.data[entry.offset] |= FLAG_DELETED;
+        self.live -= 1;
+    }
+}
+
+/// Stream value.
+pub(crate) struct Stream {
+    /// Nodes keyed by their master ID.
+    nodes: BTreeMap<StreamId, Node>,
+    /// Number of the entries.
+    length: usize,
+    /// The last generated ID.
+    last_id: StreamId,
+    /// The largest ID deleted.
+    max_deleted_id: StreamId,
+    /// Number of the entries added in the lifetime of the stream.
+    entries_added: u64,
+    /// Consumer groups.
+    pub(crate) groups: BTreeMap<Vec<u8>, Group>,
+}
+
+impl Stream {
+    /// Create an empty stream.
+    pub(crate) fn new() -> Self {
+        Stream {
+            nodes: BTreeMap::new(),
+            length: 0,
+            last_id: StreamId::MIN,
+            max_deleted_id: StreamId::MIN,
+            entries_added: 0,
+            groups: BTreeMap::new(),
+        }
+    }
+    /// Number of the entries.
+    pub(crate) fn len(&self) -> usize {
+        self.length
+    }
+    /// Number of the nodes.
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+    /// The last generated ID.
+    pub(crate) fn last_id(&self) -> StreamId {
+        self.last_id
+    }
+    /// The largest ID deleted.
+    pub(crate) fn max_deleted_id(&self) -> StreamId {
+        self.max_deleted_id
+    }
+    /// Number of the entries added in the lifetime of the stream.
+    pub(crate) fn entries_added(&self) -> u64 {
+        self.entries_added
+    }
+    /// ID of the first entry, or 0-0 if the stream is empty.
+    pub(crate) fn first_id(&self) -> StreamId {
+        self.first_entry().map_or(StreamId::MIN, |(id, _)| id)
+    }
+    /// The first entry.
+    pub(crate) fn first_entry(&self) -> Option<(StreamId, Fields)> {
+        self.range(StreamId::MIN, StreamId::MAX, Some(1), false)
+            .pop()
+    }
+    /// The last entry.
+    pub(crate) fn last_entry(&self) -> Option<(StreamId, Fields)> {
+        self.range(StreamId::MIN, StreamId::MAX, Some(1), true)
+            .pop()
+    }
+    /// Generate the ID greater than the last ID.
+    /// The milliseconds part is ms if it is given, otherwise the current time.
+    /// Return None if no ID can be generated.
+    pub(crate) fn next_id(&self, ms: Option<u64>, now: u64) -> Option<StreamId> {
+        match ms {
+            Some(ms) if ms == self.last_id.ms => self.last_id.next().filter(|id| id.ms == ms),
+            Some(ms) if ms > self.last_id.ms => Some(StreamId { ms, seq: 0 }),
+            Some(_) => None,
+            None if now > self.last_id.ms => Some(StreamId { ms: now, seq: 0 }),
+            None => self.last_id.next(),
+        }
+    }
+    /// Append the entry.
+    /// The ID must be greater than the last ID.
+    pub(crate) fn add(&mut self, id: StreamId, fields: Fields) {
+        match self.nodes.iter_mut().next_back() {
+            Some((master, node)) if node.has_room() => node.push(*master, id, &fields),
+            _ => {
+                self.nodes.insert(id, Node::new(id, &fields));
+            }
+        }
+        self.length += 1;
+        self.last_id = id;
+        self.entries_added += 1;
+    }
+    /// Entries between the IDs inclusive, at most count entries.
+    pub(crate) fn range(
+        &self,
+        start: StreamId,
+        end: StreamId,
+        count: Option<usize>,
+        reverse: bool,
+    ) -> Vec<(StreamId, Fields)> {
+        let count = count.unwrap_or(usize::MAX);
+        let mut entries = Vec::new();
+        if start > end || count == 0 {
+            return entries;
+        }
+        // The node containing the start ID may begin before it.
+        let first = match self.nodes.range(..=start).next_back() {
+            Some((master, _)) => *master,
+            None => start,
+        };
+        let nodes = self
+            .nodes
+            .range((Bound::Included(first), Bound::Included(end)));
+        let nodes: Box<dyn Iterator<Item = (&StreamId, &Node)>> = if reverse {
+            Box::new(nodes.rev())
+        } else {
+            Box::new(nodes)
+        };
+        for (master, node) in nodes {
+            let mut raw_entries = node.raw_entries(*master);
+            if reverse {
+                raw_entries.reverse();
+            }
+            for entry in raw_entries {
+                if entry.flags & FLAG_DELETED != 0 || entry.id < start || entry.id > end {
+                    continue;
+                }
+                entries.push((entry.id, node.fields(&entry)));
+                if entries.len() == count {
+                    return entries;
+                }
+            }
+        }
+        entries
+    }
+    /// Delete the entry.
+    /// Return true if the entry existed.
+    pub(crate) fn delete(&mut self, id: StreamId) -> bool {
+        let (master, node) = match self.nodes.range_mut(..=id).next_back() {
+            Some((master, node)) => (*master, node),
+            None => return false,
+        };
+        let entry = match node
+            .raw_entries(master)
+            .into_iter()
+            .find(|entry| entry.id == id && entry.flags & FLAG_DELETED == 0)
+        {
+            Some(entry) => entry,
+            None => return false,
+        };
+        node.delete(&entry);
+        if node.live == 0 {
+            self.nodes.remove(&master);
+        }
+        self.length -= 1;
+        self.max_deleted_id = self.max_deleted_id.max(id);
+        true
+    }
+    /// The entry of the ID.
+    pub(crate) fn entry(&self, id: StreamId) -> Option<Fields> {
+        self.range(id, id, Some(1), false)
+            .pop()
+            .map(|(_, fields)| fields)
+    }
+    /// Entries at or after the ID may have been deleted or not.
+    fn has_tombstones_from(&self, id: StreamId) -> bool {
+        self.length > 0
+            && self.max_deleted_id != StreamId::MIN
+            && self.max_deleted_id >= self.first_id()
+            && self.max_deleted_id >= id
+    }
+    /// Estimate the number of the entries added up to the ID.
+    /// Return None if it cannot be known because of the deleted entries.
+    fn estimate_entries_read(&self, id: StreamId) -> Option<u64> {
+        if self.entries_added == 0 {
+            return Some(0);
+        }
+        if self.length == 0 && id <= self.last_id {
+            return Some(self.entries_added);
+        }
+        if id == self.last_id {
+            return Some(self.entries_added);
+        }
+        if id > self.last_id {
+            return None;
+        }
+        let first_id = self.first_id();
+        if self.max_deleted_id == StreamId::MIN || self.max_deleted_id < first_id {
+            if id < first_id {
+                return Some(self.entries_added - self.length as u64);
+            }
+            if id == first_id {
+                return Some(self.entries_added - self.length as u64 + 1);
+            }
+        }
+        None
+    }
+    /// Number of the entries not yet delivered to the group, if it is known.
+    pub(crate) fn lag(&self, group: &Group) -> Option<u64> {
+        if self.entries_added == 0 {
+            return Some(0);
+        }
+        let entries_read = match group.entries_read {
+            Some(entries_read) if !self.has_tombstones_from(group.last_id) => Some(entries_read),
+            _ => self.estimate_entries_read(group.last_id),
+        };
+        entries_read.map(|entries_read| self.entries_added.saturating_sub(entries_read))
+    }
+    /// Deliver the new entries to the consumer of the group.
+    /// The entries are added to the pending entries list unless no_ack is true.
+    /// Return None if the group does not exist.
+    pub(crate) fn read_group(
+        &mut self,
+        group: &[u8],
+        consumer: &[u8],
+        count: Option<usize>,
+        no_ack: bool,
+        now: u64,
+    ) -> Option<Vec<(StreamId, Fields)>> {
+        let last_id = self.groups.get(group)?.last_id;
+        let entries = match last_id.next() {
+            Some(start) => self.range(start, StreamId::MAX, count, false),
+            None => Vec::new(),
+        };
+        let mut entries_read = self.groups[group].entries_read;
+        for (id, _) in &entries {
+            entries_read = match entries_read {
+                Some(entries_read) if !self.has_tombstones_from(*id) => Some(entries_read + 1),
+                _ => self.estimate_entries_read(*id),
+            };
+        }
+
+        let group = self.groups.get_mut(group)?;
+        let owner = group.consumer(consumer, now);
+        if entries.is_empty() {
+            return Some(entries);
+        }
+        owner.active_time = Some(now);
+        group.entries_read = entries_read;
+        for (id, _) in &entries {
+            group.last_id = *id;
+            if !no_ack {
+                group.assign(*id, consumer, now, 1);
+            }
+        }
+        Some(entries)
+    }
+    /// Deliver the pending entries of the consumer after the ID again.
+    /// Deleted entries have no fields.
+    /// Return None if the group does not exist.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn read_pending(
+        &mut self,
+        group: &[u8],
+        consumer: &[u8],
+        after: StreamId,
+        count: Option<usize>,
+        now: u64,
+    ) -> Option<Vec<(StreamId, Option<Fields>)>> {
+        let ids: Vec<StreamId> = match self.groups.get(group)?.consumers.get(consumer) {
+            Some(owner) => owner
+                .pending
+                .range((Bound::Excluded(after), Bound::Unbounded))
+                .take(count.unwrap_or(usize::MAX))
+                .copied()
+                .collect(),
+            None => Vec::new(),
+        };
+        let entries: Vec<(StreamId, Option<Fields>)> =
+            ids.into_iter().map(|id| (id, self.entry(id))).collect();
+
+        let group = self.groups.get_mut(group)?;
+        group.consumer(consumer, now);
+        for (id, _) in &entries {
+            if let Some(entry) = group.pending.get_mut(id) {
+                entry.delivery_time = now;
+                entry.delivery_count += 1;
+            }
+        }
+        Some(entries)
+    }
+    /// Trim the stream.
+    /// Only whole nodes are removed if approx is true,
+    /// and at most limit entries are removed in that case.
+    /// Return the number of the removed entries.
+    pub(crate) fn trim(&mut self, trim: &Trim, approx: bool, limit: Option<usize>) -> usize {
+        let limit = match (approx, limit) {
+            (true, Some(0)) | (false, _) => usize::MAX,
+            (true, Some(limit)) => limit,
+            (true, None) => 100 * MAX_NODE_ENTRIES,
+        };
+        let mut removed = 0;
+
+        // Remove whole nodes.
+        while let Some((&master, node)) = self.nodes.iter().next() {
+            let removable = match trim {
+                Trim::MaxLen(max_len) => self.length - node.live >= *max_len,
+                Trim::MinId(min_id) => node.last < *min_id,
+            };
+            if !removable || removed + node.live > limit {
+                break;
+            }
+            removed += node.live;
+            self.length -= node.live;
+            self.nodes.remove(&master);
+        }
+        if approx {
+            return removed;
+        }
+
+        // Remove the entries of the first node.
+        let (master, node) = match self.nodes.iter_mut().next() {
+            Some((master, node)) => (*master, node),
+            None => return removed,
+        };
+        for entry in node.raw_entries(master) {
+            if entry.flags & FLAG_DELETED != 0 {
+                continue;
+            }
+            let removable = match trim {
+                Trim::MaxLen(max_len) => self.length > *max_len,
+                Trim::MinId(min_id) => entry.id < *min_id,
+            };
+            if !removable {
+                break;
+            }
+            node.delete(&entry);
+            removed += 1;
+            self.length -= 1;
+        }
+        if node.live == 0 {
+            self.nodes.remove(&master);
+        }
+        removed
+    }
+}
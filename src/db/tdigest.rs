@@ -0,0 +1,262 @@
+//! T-digest value.
+//!
+//! The values are merged into the centroids sorted by the means.
+//! The weight of each centroid is limited by the scale function
+//! `k(q) = compression / π * asin(2q - 1)`,
+//! so the centroids near the both ends are small and the extreme quantiles are accurate.
+//! The centroid of one value keeps the value exactly.
+//!
+use std::f64::consts::PI;
+
+/// Mean of the values with the number of them.
+#[derive(Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// T-digest value.
+pub(crate) struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    /// Number of the values.
+    total: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Compression of the value created without it.
+    pub(crate) const DEFAULT_COMPRESSION: u64 = 100;
+
+    /// Create an empty value.
+    pub(crate) fn new(compression: u64) -> Self {
+        TDigest {
+            compression: compression as f64,
+            centroids: Vec::new(),
+            total: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+    pub(crate) fn compression(&self) -> u64 {
+        self.compression as u64
+    }
+    /// Remove all the values.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::new(self.compression());
+    }
+    /// Scale of the quantile.
+    fn scale(&self, q: f64) -> f64 {
+        self.compression / PI * (2.0 * q - 1.0).asin()
+    }
+    /// Quantile of the scale.
+    fn inverse_scale(&self, k: f64) -> f64 {
+        let angle = (k * PI / self.compression).min(PI / 2.0);
+        (angle.sin() + 1.0) / 2.0
+    }
+    /// Merge the centroids into this digest.
+    fn merge_centroids(&mut self, centroids: impl Iterator<Item = Centroid>) {
+        let mut centroids: Vec<Centroid> = self.centroids.drain(..).chain(centroids).collect();
+        if centroids.is_empty() {
+            return;
+        }
+        centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+        self.total = centroids.iter().map(|centroid| centroid.weight).sum();
+        let mut weight_so_far = 0.0;
+        let mut limit = self.total * self.inverse_scale(self.scale(0.0) + 1.0);
+        let mut current = centroids[0];
+        for next in centroids.into_iter().skip(1) {
+            if weight_so_far + current.weight + next.weight <= limit {
+                current.weight += next.weight;
+                current.mean += (next.mean - current.mean) * next.weight / current.weight;
+            } else {
+                weight_so_far += current.weight;
+                self.centroids.push(current);
+                limit =
+                    self.total * self.inverse_scale(self.scale(weight_so_far / self.total) + 1.0);
+                current = next;
+            }
+        }
+        self.centroids.push(current);
+    }
+    /// Add the values.
+    pub(crate) fn add(&mut self, values: &[f64]) {
+        for &value in values {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.merge_centroids(values.iter().map(|&value| Centroid {
+            mean: value,
+            weight: 1.0,
+        }));
+    }
+    /// Add the values of the other digest.
+    pub(crate) fn merge(&mut self, other: &TDigest) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.merge_centroids(other.centroids.iter().copied());
+    }
+    /// Smallest value, NaN if empty.
+    pub(crate) fn min(&self) -> f64 {
+        if self.total == 0.0 {
+            f64::NAN
+        } else {
+            self.min
+        }
+    }
+    /// Largest value, NaN if empty.
+    pub(crate) fn max(&self) -> f64 {
+        if self.total == 0.0 {
+            f64::NAN
+        } else {
+            self.max
+        }
+    }
+    /// Number of the values.
+    pub(crate) fn total(&self) -> f64 {
+        self.total
+    }
+    /// Centroids with the positions of the means in the sorted values.
+    /// The centroid of one value covers the unit range around the position.
+    fn positions(&self) -> impl Iterator<Item = (f64, Centroid)> + '_ {
+        self.centroids.iter().scan(0.0, |weight_so_far, centroid| {
+            let position = *weight_so_far + centroid.weight / 2.0;
+            *weight_so_far += centroid.weight;
+            Some((position, *centroid))
+        })
+    }
+    /// Estimated value at the quantile, NaN if empty.
+    pub(crate) fn quantile(&self, q: f64) -> f64 {
+        if self.total == 0.0 {
+            return f64::NAN;
+        }
+        let index = q * self.total;
+        if index < 1.0 {
+            return self.min;
+        }
+        if index > self.total - 1.0 {
+            return self.max;
+        }
+        let mut previous: Option<(f64, Centroid)> = None;
+        for (position, centroid) in self.positions() {
+            if index < position {
+                let (left, left_value) = match previous {
+                    None => (1.0, self.min),
+                    Some((left, previous)) => {
+                        if previous.weight == 1.0 && index - left < 0.5 {
+                            return previous.mean;
+                        }
+                        (left + unit(previous), previous.mean)
+                    }
+                };
+                if centroid.weight == 1.0 && position - index <= 0.5 {
+                    return centroid.mean;
+                }
+                let right = position - unit(centroid);
+                return interpolate(index, (left, left_value), (right, centroid.mean));
+            }
+            previous = Some((position, centroid));
+        }
+        // After the position of the last centroid.
+        let (left, last) = previous.unwrap();
+        if last.weight == 1.0 && index - left < 0.5 {
+            return last.mean;
+        }
+        interpolate(
+            index,
+            (left + unit(last), last.mean),
+            (self.total - 1.0, self.max),
+        )
+    }
+    /// Estimated fraction of the values smaller than the value,
+    /// plus the half of the values equal to it. NaN if empty.
+    pub(crate) fn cdf(&self, value: f64) -> f64 {
+        self.rank(value) / self.total
+    }
+    /// Estimated number of the values smaller than the value,
+    /// plus the half of the values equal to it. NaN if empty.
+    pub(crate) fn rank(&self, value: f64) -> f64 {
+        if self.total == 0.0 {
+            return f64::NAN;
+        }
+        if value < self.min {
+            return 0.0;
+        }
+        if value > self.max {
+            return self.total;
+        }
+        let mut weight_so_far = 0.0;
+        let mut previous: Option<(f64, Centroid)> = None;
+        let mut equal = 0.0;
+        for (position, centroid) in self.positions() {
+            if centroid.mean == value {
+                equal += centroid.weight;
+            } else if equal > 0.0 {
+                break;
+            } else if value < centroid.mean {
+                let (left, left_value) = match previous {
+                    None => (0.5, self.min),
+                    Some((left, previous)) => (left + unit(previous), previous.mean),
+                };
+                let right = position - unit(centroid);
+                return interpolate_inverse(value, (left, left_value), (right, centroid.mean));
+            } else {
+                weight_so_far += centroid.weight;
+            }
+            previous = Some((position, centroid));
+        }
+        if equal > 0.0 {
+            return weight_so_far + equal / 2.0;
+        }
+        // After the mean of the last centroid.
+        let (left, last) = previous.unwrap();
+        interpolate_inverse(
+            value,
+            (left + unit(last), last.mean),
+            (self.total - 0.5, self.max),
+        )
+    }
+    /// Estimated mean of the values between the quantiles, NaN if empty.
+    pub(crate) fn trimmed_mean(&self, low: f64, high: f64) -> f64 {
+        let (low, high) = (low * self.total, high * self.total);
+        let (mut sum, mut weight) = (0.0, 0.0);
+        let mut weight_so_far = 0.0;
+        for centroid in &self.centroids {
+            let start = weight_so_far;
+            weight_so_far += centroid.weight;
+            let covered = weight_so_far.min(high) - start.max(low);
+            if covered > 0.0 {
+                sum += centroid.mean * covered;
+                weight += covered;
+            }
+        }
+        sum / weight
+    }
+}
+
+/// Half of the unit range covered by the centroid of one value.
+fn unit(centroid: Centroid) -> f64 {
+    if centroid.weight == 1.0 {
+        0.5
+    } else {
+        0.0
+    }
+}
+
+/// Value at the position between the points.
+fn interpolate(position: f64, (x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+    if x2 <= x1 {
+        return y1;
+    }
+    let value = y1 + (y2 - y1) * (position - x1) / (x2 - x1);
+    value.clamp(y1.min(y2), y1.max(y2))
+}
+
+/// Position of the value between the points.
+fn interpolate_inverse(value: f64, (x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+    if y2 <= y1 {
+        return x1;
+    }
+    x1 + (x2 - x1) * (value - y1) / (y2 - y1)
+}
@@ -0,0 +1,419 @@
+//! Time series value.
+//!
+//! The samples are stored in the compressed chunks in ascending order of the timestamps.
+//! A sample older than the last one decompresses its chunk and compresses it again.
+//! The samples older than the retention period from the last sample are trimmed
+//! by the background worker.
+//!
+use super::gorilla::Chunk;
+
+/// Policy of adding the sample with the existing timestamp.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum DuplicatePolicy {
+    /// Reject the sample.
+    Block,
+    /// Keep the existing value.
+    First,
+    /// Overwrite with the new value.
+    Last,
+    /// Keep the smaller value.
+    Min,
+    /// Keep the larger value.
+    Max,
+    /// Add the new value to the existing one.
+    Sum,
+}
+
+impl DuplicatePolicy {
+    /// Parse the uppercase name.
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name {
+            "BLOCK" => Some(DuplicatePolicy::Block),
+            "FIRST" => Some(DuplicatePolicy::First),
+            "LAST" => Some(DuplicatePolicy::Last),
+            "MIN" => Some(DuplicatePolicy::Min),
+            "MAX" => Some(DuplicatePolicy::Max),
+            "SUM" => Some(DuplicatePolicy::Sum),
+            _ => None,
+        }
+    }
+    /// Value of the sample after adding the new value.
+    /// Return None if the sample is blocked.
+    fn apply(self, existing: f64, value: f64) -> Option<f64> {
+        match self {
+            DuplicatePolicy::Block => None,
+            DuplicatePolicy::First => Some(existing),
+            DuplicatePolicy::Last => Some(value),
+            DuplicatePolicy::Min => Some(existing.min(value)),
+            DuplicatePolicy::Max => Some(existing.max(value)),
+            DuplicatePolicy::Sum => Some(existing + value),
+        }
+    }
+}
+
+/// Function to aggregate the values.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Aggregator {
+    Avg,
+    Sum,
+    Min,
+    Max,
+    Range,
+    Count,
+    First,
+    Last,
+    /// Population standard deviation.
+    StdP,
+    /// Sample standard deviation.
+    StdS,
+    /// Population variance.
+    VarP,
+    /// Sample variance.
+    VarS,
+}
+
+impl Aggregator {
+    /// Parse the uppercase name.
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name {
+            "AVG" => Some(Aggregator::Avg),
+            "SUM" => Some(Aggregator::Sum),
+            "MIN" => Some(Aggregator::Min),
+            "MAX" => Some(Aggregator::Max),
+            "RANGE" => Some(Aggregator::Range),
+            "COUNT" => Some(Aggregator::Count),
+            "FIRST" => Some(Aggregator::First),
+            "LAST" => Some(Aggregator::Last),
+            "STD.P" => Some(Aggregator::StdP),
+            "STD.S" => Some(Aggregator::StdS),
+            "VAR.P" => Some(Aggregator::VarP),
+            "VAR.S" => Some(Aggregator::VarS),
+            _ => None,
+        }
+    }
+    /// Lowercase name.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Aggregator::Avg => "avg",
+            Aggregator::Sum => "sum",
+            Aggregator::Min => "min",
+            Aggregator::Max => "max",
+            Aggregator::Range => "range",
+            Aggregator::Count => "count",
+            Aggregator::First => "first",
+            Aggregator::Last => "last",
+            Aggregator::StdP => "std.p",
+            Aggregator::StdS => "std.s",
+            Aggregator::VarP => "var.p",
+            Aggregator::VarS => "var.s",
+        }
+    }
+}
+
+/// Running aggregation of the values.
+#[derive(Clone, Copy)]
+pub(crate) struct Aggregation {
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+    first: f64,
+    last: f64,
+    mean: f64,
+    /// Sum of the squared differences from the mean by Welford's algorithm.
+    m2: f64,
+}
+
+impl Aggregation {
+    /// Start the aggregation with the value.
+    pub(crate) fn new(value: f64) -> Self {
+        Aggregation {
+            count: 1,
+            sum: value,
+            min: value,
+            max: value,
+            first: value,
+            last: value,
+            mean: value,
+            m2: 0.0,
+        }
+    }
+    pub(crate) fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.last = value;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+    /// Aggregated value.
+    pub(crate) fn value(&self, aggregator: Aggregator) -> f64 {
+        let count = self.count as f64;
+        let sample_variance = if self.count > 1 {
+            self.m2 / (count - 1.0)
+        } else {
+            0.0
+        };
+        match aggregator {
+            Aggregator::Avg => self.sum / count,
+            Aggregator::Sum => self.sum,
+            Aggregator::Min => self.min,
+            Aggregator::Max => self.max,
+            Aggregator::Range => self.max - self.min,
+            Aggregator::Count => count,
+            Aggregator::First => self.first,
+            Aggregator::Last => self.last,
+            Aggregator::StdP => (self.m2 / count).sqrt(),
+            Aggregator::StdS => sample_variance.sqrt(),
+            Aggregator::VarP => self.m2 / count,
+            Aggregator::VarS => sample_variance,
+        }
+    }
+}
+
+/// Start of the bucket including the timestamp.
+/// The first bucket starting before zero starts at zero.
+pub(crate) fn bucket_start(timestamp: i64, bucket_duration: i64, align: i64) -> i64 {
+    (timestamp - (timestamp - align).rem_euclid(bucket_duration)).max(0)
+}
+
+/// Compaction rule writing the aggregation of each bucket to the destination.
+pub(crate) struct Rule {
+    destination: Vec<u8>,
+    aggregator: Aggregator,
+    bucket_duration: i64,
+    align: i64,
+    /// Start and the aggregation of the current bucket.
+    bucket: Option<(i64, Aggregation)>,
+}
+
+impl Rule {
+    pub(crate) fn new(
+        destination: Vec<u8>,
+        aggregator: Aggregator,
+        bucket_duration: i64,
+        align: i64,
+    ) -> Self {
+        Rule {
+            destination,
+            aggregator,
+            bucket_duration,
+            align,
+            bucket: None,
+        }
+    }
+    /// Aggregate the sample.
+    /// Return the sample of the closed bucket.
+    /// The sample older than the current bucket is ignored.
+    fn add(&mut self, timestamp: i64, value: f64) -> Option<(i64, f64)> {
+        let start = bucket_start(timestamp, self.bucket_duration, self.align);
+        match &mut self.bucket {
+            Some((current, aggregation)) if *current == start => {
+                aggregation.add(value);
+                None
+            }
+            Some((current, _)) if *current > start => None,
+            bucket => {
+                let closed = (*bucket)
+                    .map(|(current, aggregation)| (current, aggregation.value(self.aggregator)));
+                *bucket = Some((start, Aggregation::new(value)));
+                closed
+            }
+        }
+    }
+}
+
+/// Error of adding the sample.
+pub(crate) enum SampleError {
+    /// The timestamp is older than the retention period.
+    TooOld,
+    /// The timestamp exists and the policy is BLOCK.
+    Blocked,
+}
+
+/// Time series value.
+pub(crate) struct TimeSeries {
+    chunks: Vec<Chunk>,
+    /// Retention period in milliseconds. 0 means forever.
+    retention: i64,
+    duplicate_policy: DuplicatePolicy,
+    /// Size limit of each chunk in bytes.
+    chunk_size: usize,
+    labels: Vec<(Vec<u8>, Vec<u8>)>,
+    /// Compaction rules of this series as the source.
+    rules: Vec<Rule>,
+    /// Source key if this series is the destination of the compaction rule.
+    source: Option<Vec<u8>>,
+}
+
+impl TimeSeries {
+    /// Size limit of each chunk of the series created without it.
+    pub(crate) const DEFAULT_CHUNK_SIZE: usize = 4096;
+
+    /// Create an empty series.
+    pub(crate) fn new(
+        retention: i64,
+        duplicate_policy: DuplicatePolicy,
+        chunk_size: usize,
+        labels: Vec<(Vec<u8>, Vec<u8>)>,
+    ) -> Self {
+        TimeSeries {
+            chunks: Vec::new(),
+            retention,
+            duplicate_policy,
+            chunk_size,
+            labels,
+            rules: Vec::new(),
+            source: None,
+        }
+    }
+    pub(crate) fn retention(&self) -> i64 {
+        self.retention
+    }
+    pub(crate) fn labels(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        &self.labels
+    }
+    /// Value of the label.
+    pub(crate) fn label(&self, name: &[u8]) -> Option<&[u8]> {
+        self.labels
+            .iter()
+            .find(|(label, _)| label == name)
+            .map(|(_, value)| value.as_slice())
+    }
+    pub(crate) fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+    pub(crate) fn add_rule(&mut self, rule: Rule) {
+        self.rules.push(rule);
+    }
+    pub(crate) fn source(&self) -> Option<&[u8]> {
+        self.source.as_deref()
+    }
+    pub(crate) fn set_source(&mut self, source: Vec<u8>) {
+        self.source = Some(source);
+    }
+    /// The last sample.
+    pub(crate) fn last(&self) -> Option<(i64, f64)> {
+        self.chunks
+            .last()
+            .map(|chunk| (chunk.last(), chunk.last_value()))
+    }
+    /// Timestamp of the oldest sample kept by the retention.
+    fn retention_start(&self) -> Option<i64> {
+        match self.last() {
+            Some((last, _)) if self.retention > 0 => Some(last.saturating_sub(self.retention)),
+            _ => None,
+        }
+    }
+    /// Add the sample.
+    /// The policy overrides the duplicate policy of the series.
+    /// Return the samples of the closed buckets for the destinations of the rules.
+    pub(crate) fn add(
+        &mut self,
+        timestamp: i64,
+        value: f64,
+        policy: Option<DuplicatePolicy>,
+    ) -> Result<Vec<(Vec<u8>, i64, f64)>, SampleError> {
+        if self
+            .retention_start()
+            .is_some_and(|start| timestamp < start)
+        {
+            return Err(SampleError::TooOld);
+        }
+        let value = match self.chunks.last_mut() {
+            None => {
+                self.chunks.push(Chunk::new(&[(timestamp, value)]));
+                value
+            }
+            Some(chunk) if chunk.last() < timestamp => {
+                if chunk.size() >= self.chunk_size {
+                    self.chunks.push(Chunk::new(&[(timestamp, value)]));
+                } else {
+                    chunk.push(timestamp, value);
+                }
+                value
+            }
+            Some(_) => {
+                let policy = policy.unwrap_or(self.duplicate_policy);
+                self.upsert(timestamp, value, policy)
+                    .ok_or(SampleError::Blocked)?
+            }
+        };
+        let mut compactions = Vec::new();
+        for rule in &mut self.rules {
+            if let Some((bucket, aggregated)) = rule.add(timestamp, value) {
+                compactions.push((rule.destination.clone(), bucket, aggregated));
+            }
+        }
+        Ok(compactions)
+    }
+    /// Insert or update the sample not newer than the last one.
+    /// Return None if the sample is blocked.
+    fn upsert(&mut self, timestamp: i64, value: f64, policy: DuplicatePolicy) -> Option<f64> {
+        // The last chunk starting at or before the timestamp, or the first chunk.
+        let index = self
+            .chunks
+            .partition_point(|chunk| chunk.first() <= timestamp)
+            .saturating_sub(1);
+        let mut samples = self.chunks[index].samples();
+        let value = match samples.binary_search_by_key(&timestamp, |&(timestamp, _)| timestamp) {
+            Ok(position) => {
+                let value = policy.apply(samples[position].1, value)?;
+                samples[position].1 = value;
+                value
+            }
+            Err(position) => {
+                samples.insert(position, (timestamp, value));
+                value
+            }
+        };
+        let chunk = Chunk::new(&samples);
+        if chunk.size() > self.chunk_size * 2 {
+            let (left, right) = samples.split_at(samples.len() / 2);
+            self.chunks
+                .splice(index..=index, [Chunk::new(left), Chunk::new(right)]);
+        } else {
+            self.chunks[index] = chunk;
+        }
+        Some(value)
+    }
+    /// Samples between the timestamps in ascending order.
+    /// The samples older than the retention period are excluded even if not yet trimmed.
+    pub(crate) fn range(&self, from: i64, to: i64) -> Vec<(i64, f64)> {
+        let from = from.max(self.retention_start().unwrap_or(i64::MIN));
+        let mut samples = Vec::new();
+        for chunk in &self.chunks {
+            if chunk.last() < from || to < chunk.first() {
+                continue;
+            }
+            samples.extend(
+                chunk
+                    .samples()
+                    .into_iter()
+                    .filter(|&(timestamp, _)| from <= timestamp && timestamp <= to),
+            );
+        }
+        samples
+    }
+    /// Remove the samples older than the retention period.
+    pub(crate) fn trim(&mut self) {
+        let start = match self.retention_start() {
+            Some(start) => start,
+            None => return,
+        };
+        let expired = self.chunks.partition_point(|chunk| chunk.last() < start);
+        self.chunks.drain(..expired);
+        if let Some(chunk) = self.chunks.first_mut() {
+            if chunk.first() < start {
+                let samples: Vec<(i64, f64)> = chunk
+                    .samples()
+                    .into_iter()
+                    .filter(|&(timestamp, _)| timestamp >= start)
+                    .collect();
+                *chunk = Chunk::new(&samples);
+            }
+        }
+    }
+}
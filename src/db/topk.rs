@@ -0,0 +1,142 @@
+//! Top-K value.
+//!
+//! The counts are estimated by HeavyKeeper.
+//! Each row of the buckets is indexed by a different hash of the item,
+//! and a bucket of another item is decayed with the probability `decay ^ count`,
+//! so only the heavy hitters keep the large counts.
+//! The top k items are kept with the estimated counts.
+//!
+use super::hyperloglog::murmur_hash64a;
+use crate::random;
+
+/// Seed of the hash function.
+const SEED: u64 = 0x27d4_eb2d;
+/// Maximum number of the buckets.
+const MAX_BUCKETS: u64 = 64 * 1024 * 1024;
+
+/// Counter of the item with the fingerprint.
+#[derive(Clone, Copy)]
+struct Bucket {
+    fingerprint: u32,
+    count: u64,
+}
+
+/// Top-K value.
+pub(crate) struct TopK {
+    k: usize,
+    width: usize,
+    depth: usize,
+    decay: f64,
+    buckets: Vec<Bucket>,
+    /// The top k items with the counts in no particular order.
+    top: Vec<(Vec<u8>, u64)>,
+}
+
+impl TopK {
+    /// Number of the buckets in each row of the value created without the dimensions.
+    pub(crate) const DEFAULT_WIDTH: u64 = 8;
+    /// Number of the rows of the value created without the dimensions.
+    pub(crate) const DEFAULT_DEPTH: u64 = 7;
+    /// Decay of the value created without the dimensions.
+    pub(crate) const DEFAULT_DECAY: f64 = 0.9;
+    /// Maximum increment at a time.
+    pub(crate) const MAX_INCREMENT: u64 = 100_000;
+
+    /// Create an empty value.
+    /// Return None if it is too large.
+    pub(crate) fn new(k: u64, width: u64, depth: u64, decay: f64) -> Option<Self> {
+        if k > MAX_BUCKETS || width.checked_mul(depth)? > MAX_BUCKETS {
+            return None;
+        }
+        let bucket = Bucket {
+            fingerprint: 0,
+            count: 0,
+        };
+        Some(TopK {
+            k: k as usize,
+            width: width as usize,
+            depth: depth as usize,
+            decay,
+            buckets: vec![bucket; (width * depth) as usize],
+            top: Vec::new(),
+        })
+    }
+    pub(crate) fn k(&self) -> usize {
+        self.k
+    }
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+    pub(crate) fn depth(&self) -> usize {
+        self.depth
+    }
+    pub(crate) fn decay(&self) -> f64 {
+        self.decay
+    }
+    /// Increase the count of the item.
+    /// Return the item expelled from the top k items.
+    pub(crate) fn incr_by(&mut self, item: &[u8], increment: u64) -> Option<Vec<u8>> {
+        let h1 = murmur_hash64a(item, SEED);
+        let h2 = murmur_hash64a(item, h1);
+        let fingerprint = (h1 >> 32) as u32;
+        let mut max_count = 0;
+        for row in 0..self.depth {
+            let hash = h1.wrapping_add((row as u64).wrapping_mul(h2));
+            let bucket = &mut self.buckets[row * self.width + (hash % self.width as u64) as usize];
+            if bucket.count == 0 || bucket.fingerprint == fingerprint {
+                bucket.fingerprint = fingerprint;
+                bucket.count = bucket.count.saturating_add(increment);
+                max_count = max_count.max(bucket.count);
+                continue;
+            }
+            for remaining in (1..=increment).rev() {
+                let probability = self.decay.powf(bucket.count as f64);
+                if random_unit() < probability {
+                    bucket.count -= 1;
+                    if bucket.count == 0 {
+                        bucket.fingerprint = fingerprint;
+                        bucket.count = remaining;
+                        max_count = max_count.max(remaining);
+                        break;
+                    }
+                }
+            }
+        }
+        if let Some((_, count)) = self.top.iter_mut().find(|(top, _)| top == item) {
+            *count = max_count;
+            return None;
+        }
+        if max_count == 0 {
+            return None;
+        }
+        if self.top.len() < self.k {
+            self.top.push((item.to_vec(), max_count));
+            return None;
+        }
+        let min = self
+            .top
+            .iter_mut()
+            .min_by_key(|(_, count)| *count)
+            .filter(|(_, count)| *count < max_count)?;
+        Some(std::mem::replace(min, (item.to_vec(), max_count)).0)
+    }
+    /// The item is in the top k items or not.
+    pub(crate) fn contains(&self, item: &[u8]) -> bool {
+        self.top.iter().any(|(top, _)| top == item)
+    }
+    /// The top k items with the counts in descending order of the counts.
+    pub(crate) fn list(&self) -> Vec<(&[u8], u64)> {
+        let mut list: Vec<(&[u8], u64)> = self
+            .top
+            .iter()
+            .map(|(item, count)| (item.as_slice(), *count))
+            .collect();
+        list.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        list
+    }
+}
+
+/// Random number in [0, 1).
+fn random_unit() -> f64 {
+    (random::next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,177 @@
+//! Trie of the suggestions.
+//!
+//! The strings are matched by their lowercase characters,
+//! and the suggestions keep the strings as added.
+//! The fuzzy search walks the trie with the rows of the Levenshtein distance
+//! to find the nodes within the distance 1 from the prefix.
+//!
+use std::collections::BTreeMap;
+
+/// Maximum Levenshtein distance of the fuzzy search.
+const MAX_DISTANCE: usize = 1;
+
+/// Suggestion with its score and payload.
+pub(crate) struct Suggestion {
+    pub(crate) string: Vec<u8>,
+    pub(crate) score: f64,
+    pub(crate) payload: Option<Vec<u8>>,
+}
+
+/// Node of the trie.
+#[derive(Default)]
+struct Node {
+    children: BTreeMap<char, Node>,
+    suggestion: Option<Suggestion>,
+}
+
+impl Node {
+    /// Collect the suggestions of the node and its descendants.
+    fn collect<'a>(&'a self, suggestions: &mut Vec<&'a Suggestion>) {
+        suggestions.extend(&self.suggestion);
+        for child in self.children.values() {
+            child.collect(suggestions);
+        }
+    }
+    /// Collect the suggestions under the nodes within the distance from the prefix.
+    /// `row` is the distances between the prefixes of the prefix and the path to the node.
+    fn collect_fuzzy<'a>(
+        &'a self,
+        prefix: &[char],
+        row: &[usize],
+        suggestions: &mut Vec<&'a Suggestion>,
+    ) {
+        if row[prefix.len()] <= MAX_DISTANCE {
+            self.collect(suggestions);
+            return;
+        }
+        if row
+            .iter()
+            .min()
+            .is_some_and(|&distance| distance > MAX_DISTANCE)
+        {
+            return;
+        }
+        for (&c, child) in &self.children {
+            let mut next = vec![row[0] + 1];
+            for (i, &p) in prefix.iter().enumerate() {
+                let substitution = row[i] + (p != c) as usize;
+                next.push(substitution.min(row[i + 1] + 1).min(next[i] + 1));
+            }
+            child.collect_fuzzy(prefix, &next, suggestions);
+        }
+    }
+    /// Remove the suggestion of the characters under the node.
+    /// Return true if it existed.
+    fn remove(&mut self, chars: &[char]) -> bool {
+        match chars.split_first() {
+            None => self.suggestion.take().is_some(),
+            Some((c, rest)) => {
+                let child = match self.children.get_mut(c) {
+                    Some(child) => child,
+                    None => return false,
+                };
+                let removed = child.remove(rest);
+                if child.suggestion.is_none() && child.children.is_empty() {
+                    self.children.remove(c);
+                }
+                removed
+            }
+        }
+    }
+}
+
+/// Characters to match the string.
+fn chars(string: &[u8]) -> Vec<char> {
+    String::from_utf8_lossy(string)
+        .to_lowercase()
+        .chars()
+        .collect()
+}
+
+/// Trie of the suggestions.
+#[derive(Default)]
+pub(crate) struct Trie {
+    root: Node,
+    len: usize,
+}
+
+impl Trie {
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Add the suggestion, or replace the score of the existing one.
+    /// The score is added to the existing one with `increment`.
+    /// The payload replaces the existing one if it is given.
+    pub(crate) fn add(
+        &mut self,
+        string: Vec<u8>,
+        score: f64,
+        increment: bool,
+        payload: Option<Vec<u8>>,
+    ) {
+        let mut node = &mut self.root;
+        for c in chars(&string) {
+            node = node.children.entry(c).or_default();
+        }
+        match &mut node.suggestion {
+            Some(suggestion) => {
+                suggestion.string = string;
+                suggestion.score = match increment {
+                    true => suggestion.score + score,
+                    false => score,
+                };
+                if payload.is_some() {
+                    suggestion.payload = payload;
+                }
+            }
+            None => {
+                node.suggestion = Some(Suggestion {
+                    string,
+                    score,
+                    payload,
+                });
+                self.len += 1;
+            }
+        }
+    }
+    /// Remove the suggestion. Return true if it existed.
+    pub(crate) fn remove(&mut self, string: &[u8]) -> bool {
+        let removed = self.root.remove(&chars(string));
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+    /// Search the suggestions starting with the prefix,
+    /// or with the prefix within the Levenshtein distance 1 if `fuzzy`.
+    /// Return at most `max` suggestions in descending order of the scores.
+    /// The shorter strings come first at the same score.
+    pub(crate) fn search(&self, prefix: &[u8], fuzzy: bool, max: usize) -> Vec<&Suggestion> {
+        let prefix = chars(prefix);
+        let mut suggestions = Vec::new();
+        if fuzzy {
+            let row: Vec<usize> = (0..=prefix.len()).collect();
+            self.root.collect_fuzzy(&prefix, &row, &mut suggestions);
+        } else {
+            let mut node = &self.root;
+            for c in &prefix {
+                node = match node.children.get(c) {
+                    Some(child) => child,
+                    None => return suggestions,
+                };
+            }
+            node.collect(&mut suggestions);
+        }
+        suggestions.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(a.string.len().cmp(&b.string.len()))
+                .then(a.string.cmp(&b.string))
+        });
+        suggestions.truncate(max);
+        suggestions
+    }
+}
@@ -0,0 +1,478 @@
+//! Vector set value.
+//!
+//! The vectors are normalized and compared by the cosine similarity.
+//! They are searched on the hierarchical navigable small world (HNSW) graph,
+//! where each level links the nodes to their nearest neighbors and
+//! the upper levels hold exponentially fewer nodes to route the search.
+//! Small sets and the exact queries are searched by brute force.
+//! The vectors are optionally quantized to 8 bit integers with the scale of each vector.
+//!
+use super::attrfilter::Filter;
+use super::json::Json;
+use crate::random;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Maximum level of the nodes.
+const MAX_LEVEL: usize = 16;
+/// Sets up to this number of the elements are searched by brute force.
+const BRUTE_FORCE_LIMIT: usize = 1000;
+
+/// Quantization of the stored vectors.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Quantization {
+    /// 32 bit floats.
+    NoQuant,
+    /// 8 bit integers.
+    Q8,
+}
+
+/// Stored normalized vector.
+enum Vector {
+    Float(Vec<f32>),
+    /// The components are `value * scale`.
+    Int8 {
+        values: Vec<i8>,
+        scale: f32,
+    },
+}
+
+impl Vector {
+    fn new(vector: &[f32], quantization: Quantization) -> Self {
+        match quantization {
+            Quantization::NoQuant => Vector::Float(vector.to_vec()),
+            Quantization::Q8 => {
+                let max = vector.iter().fold(0.0f32, |max, x| max.max(x.abs()));
+                let scale = if max > 0.0 { max / 127.0 } else { 1.0 };
+                Vector::Int8 {
+                    values: vector.iter().map(|x| (x / scale).round() as i8).collect(),
+                    scale,
+                }
+            }
+        }
+    }
+    fn to_vec(&self) -> Vec<f32> {
+        match self {
+            Vector::Float(vector) => vector.clone(),
+            Vector::Int8 { values, scale } => values.iter().map(|&x| x as f32 * scale).collect(),
+        }
+    }
+    /// Dot product with the query.
+    fn dot(&self, query: &[f32]) -> f32 {
+        match self {
+            Vector::Float(vector) => vector.iter().zip(query).map(|(a, b)| a * b).sum(),
+            Vector::Int8 { values, scale } => {
+                values
+                    .iter()
+                    .zip(query)
+                    .map(|(&a, b)| a as f32 * b)
+                    .sum::<f32>()
+                    * scale
+            }
+        }
+    }
+    /// Dot product with the other stored vector.
+    fn similarity(&self, other: &Vector) -> f32 {
+        match (self, other) {
+            (
+                Vector::Int8 { values, scale },
+                Vector::Int8 {
+                    values: other,
+                    scale: other_scale,
+                },
+            ) => {
+                values
+                    .iter()
+                    .zip(other)
+                    .map(|(&a, &b)| a as i32 * b as i32)
+                    .sum::<i32>() as f32
+                    * scale
+                    * other_scale
+            }
+            (_, Vector::Float(other)) => self.dot(other),
+            (Vector::Float(vector), _) => other.dot(vector),
+        }
+    }
+}
+
+/// Normalize the vector. Return the normalized vector and the original norm.
+fn normalize(vector: &[f32]) -> (Vec<f32>, f32) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return (vector.to_vec(), norm);
+    }
+    (vector.iter().map(|x| x / norm).collect(), norm)
+}
+
+/// Node with its similarity to the query.
+#[derive(Clone, Copy)]
+struct Scored {
+    similarity: f32,
+    id: u32,
+}
+
+impl PartialEq for Scored {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Scored {}
+
+impl PartialOrd for Scored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Scored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.similarity
+            .total_cmp(&other.similarity)
+            .then(self.id.cmp(&other.id))
+    }
+}
+
+/// Element of the set.
+struct Node {
+    element: Vec<u8>,
+    vector: Vector,
+    /// Norm of the original vector.
+    norm: f32,
+    attributes: Option<Json>,
+    /// Neighbors on each level up to the level of the node.
+    /// They may contain the removed nodes, which are skipped.
+    neighbors: Vec<Vec<u32>>,
+}
+
+/// Vector set value.
+pub(crate) struct VectorSet {
+    dimension: usize,
+    quantization: Quantization,
+    /// Maximum number of the neighbors on the upper levels. Doubled on the bottom level.
+    m: usize,
+    nodes: HashMap<u32, Node>,
+    elements: HashMap<Vec<u8>, u32>,
+    next_id: u32,
+    /// Node on the top level where the searches start.
+    entry: Option<u32>,
+    max_level: usize,
+}
+
+impl VectorSet {
+    /// Maximum number of the neighbors of the value created without M.
+    pub(crate) const DEFAULT_M: usize = 16;
+    /// Default size of the candidate list of the searches.
+    pub(crate) const DEFAULT_EF: usize = 200;
+
+    /// Create an empty value.
+    pub(crate) fn new(dimension: usize, quantization: Quantization, m: usize) -> Self {
+        VectorSet {
+            dimension,
+            quantization,
+            m,
+            nodes: HashMap::new(),
+            elements: HashMap::new(),
+            next_id: 0,
+            entry: None,
+            max_level: 0,
+        }
+    }
+    pub(crate) fn dimension(&self) -> usize {
+        self.dimension
+    }
+    pub(crate) fn quantization(&self) -> Quantization {
+        self.quantization
+    }
+    pub(crate) fn len(&self) -> usize {
+        self.elements.len()
+    }
+    pub(crate) fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+    /// Add the element, or replace the vector of the existing element keeping its attributes.
+    /// `ef` is the size of the candidate list to find the neighbors.
+    /// Return true if the element is added.
+    pub(crate) fn add(&mut self, element: Vec<u8>, vector: &[f32], ef: usize) -> bool {
+        let attributes = match self.elements.get(&element) {
+            Some(id) => {
+                let attributes = self.nodes.get_mut(id).unwrap().attributes.take();
+                self.remove(&element);
+                Some(attributes)
+            }
+            None => None,
+        };
+        let added = attributes.is_none();
+        self.insert(element, vector, attributes.flatten(), ef.max(self.m));
+        added
+    }
+    /// Remove the element. Return true if it existed.
+    pub(crate) fn remove(&mut self, element: &[u8]) -> bool {
+        let id = match self.elements.remove(element) {
+            Some(id) => id,
+            None => return false,
+        };
+        let node = self.nodes.remove(&id).unwrap();
+        // Reconnect the neighbors of the node with each other.
+        for (level, links) in node.neighbors.iter().enumerate() {
+            let links: Vec<u32> = links
+                .iter()
+                .copied()
+                .filter(|link| self.nodes.contains_key(link))
+                .collect();
+            for &neighbor in &links {
+                let neighbors = &mut self.nodes.get_mut(&neighbor).unwrap().neighbors[level];
+                neighbors.retain(|&link| link != id);
+                for &link in &links {
+                    if link != neighbor && !neighbors.contains(&link) {
+                        neighbors.push(link);
+                    }
+                }
+                if neighbors.len() > self.max_links(level) {
+                    self.prune(neighbor, level);
+                }
+            }
+        }
+        if self.entry == Some(id) {
+            self.entry = self
+                .nodes
+                .iter()
+                .max_by_key(|(_, node)| node.neighbors.len())
+                .map(|(&id, _)| id);
+            self.max_level = self
+                .entry
+                .map_or(0, |entry| self.nodes[&entry].neighbors.len() - 1);
+        }
+        true
+    }
+    /// Original vector of the element, approximated if it is quantized.
+    pub(crate) fn embedding(&self, element: &[u8]) -> Option<Vec<f32>> {
+        let node = &self.nodes[self.elements.get(element)?];
+        Some(
+            node.vector
+                .to_vec()
+                .into_iter()
+                .map(|x| x * node.norm)
+                .collect(),
+        )
+    }
+    /// Set or remove the attributes of the element. Return false if it does not exist.
+    pub(crate) fn set_attributes(&mut self, element: &[u8], attributes: Option<Json>) -> bool {
+        match self.elements.get(element) {
+            Some(id) => {
+                self.nodes.get_mut(id).unwrap().attributes = attributes;
+                true
+            }
+            None => false,
+        }
+    }
+    /// Search the elements most similar to the query, in descending order of the similarity.
+    /// The filter visits at most `filter_ef` nodes of the graph.
+    /// Return the elements with the scores from 0 to 1.
+    pub(crate) fn search(
+        &self,
+        query: &[f32],
+        count: usize,
+        ef: usize,
+        filter: Option<&Filter>,
+        filter_ef: usize,
+        exact: bool,
+    ) -> Vec<(&[u8], f32)> {
+        let (query, _) = normalize(query);
+        let accept =
+            |node: &Node| filter.is_none_or(|filter| filter.matches(node.attributes.as_ref()));
+        let mut results: Vec<Scored> = match self.entry {
+            None => Vec::new(),
+            Some(_) if exact || self.len() <= BRUTE_FORCE_LIMIT => self
+                .nodes
+                .iter()
+                .filter(|(_, node)| accept(node))
+                .map(|(&id, node)| Scored {
+                    similarity: node.vector.dot(&query),
+                    id,
+                })
+                .collect(),
+            Some(mut entry) => {
+                for level in (1..=self.max_level).rev() {
+                    entry =
+                        self.search_layer(&query, &[entry], 1, level, &|_| true, usize::MAX)[0].id;
+                }
+                let limit = match filter {
+                    Some(_) => filter_ef,
+                    None => usize::MAX,
+                };
+                self.search_layer(&query, &[entry], ef.max(count), 0, &accept, limit)
+            }
+        };
+        results.sort_unstable_by(|a, b| b.cmp(a));
+        results.truncate(count);
+        results
+            .into_iter()
+            .map(|scored| {
+                let score = ((1.0 + scored.similarity) / 2.0).clamp(0.0, 1.0);
+                (self.nodes[&scored.id].element.as_slice(), score)
+            })
+            .collect()
+    }
+    fn max_links(&self, level: usize) -> usize {
+        if level == 0 {
+            self.m * 2
+        } else {
+            self.m
+        }
+    }
+    /// Random level of the new node. The probability decreases by 1/M for each level.
+    fn random_level(&self) -> usize {
+        let uniform = (random::next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        let level = -(1.0 - uniform).ln() / (self.m as f64).ln();
+        (level as usize).min(MAX_LEVEL)
+    }
+    fn insert(&mut self, element: Vec<u8>, vector: &[f32], attributes: Option<Json>, ef: usize) {
+        let (vector, norm) = normalize(vector);
+        let id = self.next_id;
+        self.next_id += 1;
+        let level = self.random_level();
+        let mut neighbors = vec![Vec::new(); level + 1];
+        if let Some(mut entry) = self.entry {
+            for level in (level + 1..=self.max_level).rev() {
+                entry = self.search_layer(&vector, &[entry], 1, level, &|_| true, usize::MAX)[0].id;
+            }
+            let mut entries = vec![entry];
+            for level in (0..=level.min(self.max_level)).rev() {
+                let candidates =
+                    self.search_layer(&vector, &entries, ef, level, &|_| true, usize::MAX);
+                neighbors[level] = self.select_neighbors(&candidates, self.max_links(level));
+                entries = candidates.iter().map(|candidate| candidate.id).collect();
+            }
+        }
+        self.nodes.insert(
+            id,
+            Node {
+                element: element.clone(),
+                vector: Vector::new(&vector, self.quantization),
+                norm,
+                attributes,
+                neighbors: neighbors.clone(),
+            },
+        );
+        self.elements.insert(element, id);
+        for (level, links) in neighbors.into_iter().enumerate() {
+            for neighbor in links {
+                let links = &mut self.nodes.get_mut(&neighbor).unwrap().neighbors[level];
+                links.push(id);
+                if links.len() > self.max_links(level) {
+                    self.prune(neighbor, level);
+                }
+            }
+        }
+        if self.entry.is_none() || level > self.max_level {
+            self.entry = Some(id);
+            self.max_level = level;
+        }
+    }
+    /// Search the level from the entries keeping the `ef` nearest accepted nodes.
+    /// The search stops after visiting `limit` nodes.
+    /// Return the nodes in descending order of the similarity.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entries: &[u32],
+        ef: usize,
+        level: usize,
+        accept: &dyn Fn(&Node) -> bool,
+        limit: usize,
+    ) -> Vec<Scored> {
+        let mut visited: HashSet<u32> = entries.iter().copied().collect();
+        let mut candidates = BinaryHeap::new();
+        let mut results = BinaryHeap::new();
+        for &id in entries {
+            let node = &self.nodes[&id];
+            let scored = Scored {
+                similarity: node.vector.dot(query),
+                id,
+            };
+            candidates.push(scored);
+            if accept(node) {
+                results.push(Reverse(scored));
+            }
+        }
+        while results.len() > ef {
+            results.pop();
+        }
+        'search: while let Some(candidate) = candidates.pop() {
+            if results.len() >= ef && candidate < results.peek().unwrap().0 {
+                break;
+            }
+            for &id in &self.nodes[&candidate.id].neighbors[level] {
+                if visited.len() >= limit {
+                    break 'search;
+                }
+                if !visited.insert(id) {
+                    continue;
+                }
+                let node = match self.nodes.get(&id) {
+                    Some(node) => node,
+                    None => continue,
+                };
+                let scored = Scored {
+                    similarity: node.vector.dot(query),
+                    id,
+                };
+                if results.len() < ef || scored > results.peek().unwrap().0 {
+                    candidates.push(scored);
+                    if accept(node) {
+                        results.push(Reverse(scored));
+                        if results.len() > ef {
+                            results.pop();
+                        }
+                    }
+                }
+            }
+        }
+        results
+            .into_sorted_vec()
+            .into_iter()
+            .map(|scored| scored.0)
+            .collect()
+    }
+    /// Select the neighbors from the candidates in descending order of the similarity.
+    /// A candidate more similar to a selected neighbor than to the base is skipped to spread the links,
+    /// and the skipped ones fill the rest.
+    fn select_neighbors(&self, candidates: &[Scored], max: usize) -> Vec<u32> {
+        let mut selected: Vec<u32> = Vec::with_capacity(max);
+        let mut skipped = Vec::new();
+        for candidate in candidates {
+            if selected.len() == max {
+                break;
+            }
+            let vector = &self.nodes[&candidate.id].vector;
+            if selected
+                .iter()
+                .all(|id| vector.similarity(&self.nodes[id].vector) < candidate.similarity)
+            {
+                selected.push(candidate.id);
+            } else {
+                skipped.push(candidate.id);
+            }
+        }
+        let rest = max - selected.len();
+        selected.extend(skipped.into_iter().take(rest));
+        selected
+    }
+    /// Reduce the neighbors of the node on the level, dropping the removed ones.
+    fn prune(&mut self, id: u32, level: usize) {
+        let node = &self.nodes[&id];
+        let mut candidates: Vec<Scored> = node.neighbors[level]
+            .iter()
+            .filter_map(|&link| {
+                self.nodes.get(&link).map(|neighbor| Scored {
+                    similarity: neighbor.vector.similarity(&node.vector),
+                    id: link,
+                })
+            })
+            .collect();
+        candidates.sort_unstable_by(|a, b| b.cmp(a));
+        let links = self.select_neighbors(&candidates, self.max_links(level));
+        self.nodes.get_mut(&id).unwrap().neighbors[level] = links;
+    }
+}
@@ -0,0 +1,629 @@
+//! Sorted set value.
+//!
+//! Members are ordered by score and then by member in a skip list,
+//! and a hash table maps each member to its score.
+//! The skip list keeps the span of every link, so ranks are found in logarithmic time.
+//! The nodes are stored in a vector and linked by their indexes.
+//!
+use crate::random;
+use std::collections::HashMap;
+
+/// Maximum level of the skip list.
+const MAX_LEVEL: usize = 32;
+/// Index of the header node.
+const HEAD: usize = 0;
+
+/// Score range. The bounds may be exclusive.
+pub(crate) struct ScoreRange {
+    min: f64,
+    max: f64,
+    min_exclusive: bool,
+    max_exclusive: bool,
+}
+
+impl ScoreRange {
+    /// Range from the min inclusive to the max exclusive.
+    pub(crate) fn half_open(min: f64, max: f64) -> ScoreRange {
+        ScoreRange {
+            min,
+            max,
+            min_exclusive: false,
+            max_exclusive: true,
+        }
+    }
+    /// Parse the bounds like "1.5", "(1.5", "-inf" and "+inf".
+    pub(crate) fn parse(min: &[u8], max: &[u8]) -> Option<ScoreRange> {
+        let (min, min_exclusive) = Self::parse_bound(min)?;
+        let (max, max_exclusive) = Self::parse_bound(max)?;
+        Some(ScoreRange {
+            min,
+            max,
+            min_exclusive,
+            max_exclusive,
+        })
+    }
+    /// Parse the bound.
+    fn parse_bound(bound: &[u8]) -> Option<(f64, bool)> {
+        let (bound, exclusive) = match bound.strip_prefix(b"(") {
+            Some(bound) => (bound, true),
+            None => (bound, false),
+        };
+        match std::str::from_utf8(bound).ok()?.parse::<f64>() {
+            Ok(value) if !value.is_nan() => Some((value, exclusive)),
+            _ => None,
+        }
+    }
+    /// The score is greater than the min or not.
+    fn gte_min(&self, score: f64) -> bool {
+        if self.min_exclusive {
+            score > self.min
+        } else {
+            score >= self.min
+        }
+    }
+    /// The score is less than the max or not.
+    fn lte_max(&self, score: f64) -> bool {
+        if self.max_exclusive {
+            score < self.max
+        } else {
+            score <= self.max
+        }
+    }
+}
+
+/// Bound of the lexicographical range.
+pub(crate) enum LexBound {
+    /// "-"
+    NegativeInfinity,
+    /// "+"
+    PositiveInfinity,
+    /// "\[member"
+    Inclusive(Vec<u8>),
+    /// "(member"
+    Exclusive(Vec<u8>),
+}
+
+/// Lexicographical range.
+pub(crate) struct LexRange {
+    min: LexBound,
+    max: LexBound,
+}
+
+impl LexRange {
+    /// Parse the bounds like "\[a", "(a", "-" and "+".
+    pub(crate) fn parse(min: &[u8], max: &[u8]) -> Option<LexRange> {
+        Some(LexRange {
+            min: Self::parse_bound(min)?,
+            max: Self::parse_bound(max)?,
+        })
+    }
+    /// Parse the bound.
+    fn parse_bound(bound: &[u8]) -> Option<LexBound> {
+        match bound.split_first() {
+            Some((b'-', [])) => Some(LexBound::NegativeInfinity),
+            Some((b'+', [])) => Some(LexBound::PositiveInfinity),
+            Some((b'[', member)) => Some(LexBound::Inclusive(member.to_vec())),
+            Some((b'(', member)) => Some(LexBound::Exclusive(member.to_vec())),
+            _ => None,
+        }
+    }
+    /// The member is greater than the min or not.
+    fn gte_min(&self, member: &[u8]) -> bool {
+        match &self.min {
+            LexBound::NegativeInfinity => true,
+            LexBound::PositiveInfinity => false,
+            LexBound::Inclusive(min) => member >= &min[..],
+            LexBound::Exclusive(min) => member > &min[..],
+        }
+    }
+    /// The member is less than the max or not.
+    fn lte_max(&self, member: &[u8]) -> bool {
+        match &self.max {
+            LexBound::NegativeInfinity => false,
+            LexBound::PositiveInfinity => true,
+            LexBound::Inclusive(max) => member <= &max[..],
+            LexBound::Exclusive(max) => member < &max[..],
+        }
+    }
+}
+
+/// Link to the next node.
+#[derive(Clone, Copy)]
+struct Level {
+    forward: Option<usize>,
+    /// Number of the nodes skipped by the link.
+    span: usize,
+}
+
+/// Skip list node.
+struct Node {
+    member: Vec<u8>,
+    score: f64,
+    backward: Option<usize>,
+    levels: Vec<Level>,
+}
+
+impl Node {
+    /// The node is ordered before the score and the member or not.
+    fn before(&self, score: f64, member: &[u8]) -> bool {
+        self.score < score || (self.score == score && &self.member[..] < member)
+    }
+}
+
+/// Skip list with spans.
+struct SkipList {
+    /// Nodes. The first node is the header.
+    nodes: Vec<Node>,
+    /// Indexes of the removed nodes for reuse.
+    free: Vec<usize>,
+    tail: Option<usize>,
+    length: usize,
+    level: usize,
+}
+
+impl SkipList {
+    /// Create an empty skip list.
+    fn new() -> Self {
+        SkipList {
+            nodes: vec![Node {
+                member: Vec::new(),
+                score: 0.0,
+                backward: None,
+                levels: vec![
+                    Level {
+                        forward: None,
+                        span: 0,
+                    };
+                    MAX_LEVEL
+                ],
+            }],
+            free: Vec::new(),
+            tail: None,
+            length: 0,
+            level: 1,
+        }
+    }
+    /// Random level of the new node. Each level has the probability 1/4.
+    fn random_level() -> usize {
+        let mut level = 1;
+        while level < MAX_LEVEL && random::next_u64() & 3 == 0 {
+            level += 1;
+        }
+        level
+    }
+    /// Find the last nodes before the score and the member at each level.
+    /// Return the nodes and their ranks.
+    fn find_update(&self, score: f64, member: &[u8]) -> ([usize; MAX_LEVEL], [usize; MAX_LEVEL]) {
+        let mut update = [HEAD; MAX_LEVEL];
+        let mut rank = [0; MAX_LEVEL];
+        let mut x = HEAD;
+        for i in (0..self.level).rev() {
+            rank[i] = if i == self.level - 1 { 0 } else { rank[i + 1] };
+            while let Some(forward) = self.nodes[x].levels[i].forward {
+                if !self.nodes[forward].before(score, member) {
+                    break;
+                }
+                rank[i] += self.nodes[x].levels[i].span;
+                x = forward;
+            }
+            update[i] = x;
+        }
+        (update, rank)
+    }
+    /// Insert the new node.
+    fn insert(&mut self, score: f64, member: Vec<u8>) {
+        let (mut update, mut rank) = self.find_update(score, &member);
+
+        let level = Self::random_level();
+        if level > self.level {
+            for i in self.level..level {
+                rank[i] = 0;
+                update[i] = HEAD;
+                self.nodes[HEAD].levels[i].span = self.length;
+            }
+            self.level = level;
+        }
+        let node = Node {
+            member,
+            score,
+            backward: if update[0] == HEAD {
+                None
+            } else {
+                Some(update[0])
+            },
+            levels: vec![
+                Level {
+                    forward: None,
+                    span: 0,
+                };
+                level
+            ],
+        };
+        let x = match self.free.pop() {
+            Some(x) => {
+                self.nodes[x] = node;
+                x
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        for i in 0..level {
+            let prev = self.nodes[update[i]].levels[i];
+            self.nodes[x].levels[i] = Level {
+                forward: prev.forward,
+                span: prev.span - (rank[0] - rank[i]),
+            };
+            self.nodes[update[i]].levels[i] = Level {
+                forward: Some(x),
+                span: rank[0] - rank[i] + 1,
+            };
+        }
+        for (i, &prev) in update.iter().enumerate().take(self.level).skip(level) {
+            self.nodes[prev].levels[i].span += 1;
+        }
+        match self.nodes[x].levels[0].forward {
+            Some(forward) => self.nodes[forward].backward = Some(x),
+            None => self.tail = Some(x),
+        }
+        self.length += 1;
+    }
+    /// Unlink the node.
+    fn delete_node(&mut self, x: usize, update: &[usize; MAX_LEVEL]) {
+        for (i, &prev) in update.iter().enumerate().take(self.level) {
+            if self.nodes[prev].levels[i].forward == Some(x) {
+                self.nodes[prev].levels[i].span += self.nodes[x].levels[i].span;
+                self.nodes[prev].levels[i].span -= 1;
+                self.nodes[prev].levels[i].forward = self.nodes[x].levels[i].forward;
+            } else {
+                self.nodes[prev].levels[i].span -= 1;
+            }
+        }
+        match self.nodes[x].levels[0].forward {
+            Some(forward) => self.nodes[forward].backward = self.nodes[x].backward,
+            None => self.tail = self.nodes[x].backward,
+        }
+        while self.level > 1 && self.nodes[HEAD].levels[self.level - 1].forward.is_none() {
+            self.level -= 1;
+        }
+        self.length -= 1;
+        // Release the memory of the member.
+        self.nodes[x].member = Vec::new();
+        self.nodes[x].levels = Vec::new();
+        self.free.push(x);
+    }
+    /// Delete the node of the score and the member.
+    fn delete(&mut self, score: f64, member: &[u8]) -> Option<Vec<u8>> {
+        let (update, _) = self.find_update(score, member);
+        let x = self.nodes[update[0]].levels[0].forward?;
+        if self.nodes[x].score == score && self.nodes[x].member == member {
+            let member = std::mem::take(&mut self.nodes[x].member);
+            self.delete_node(x, &update);
+            Some(member)
+        } else {
+            None
+        }
+    }
+    /// 1-based rank of the score and the member.
+    fn rank(&self, score: f64, member: &[u8]) -> Option<usize> {
+        let mut rank = 0;
+        let mut x = HEAD;
+        for i in (0..self.level).rev() {
+            while let Some(forward) = self.nodes[x].levels[i].forward {
+                let node = &self.nodes[forward];
+                if !(node.before(score, member) || node.member == member) {
+                    break;
+                }
+                rank += self.nodes[x].levels[i].span;
+                x = forward;
+            }
+            if x != HEAD && self.nodes[x].member == member {
+                return Some(rank);
+            }
+        }
+        None
+    }
+    /// Node of the 1-based rank.
+    fn by_rank(&self, rank: usize) -> Option<usize> {
+        let mut traversed = 0;
+        let mut x = HEAD;
+        for i in (0..self.level).rev() {
+            while let Some(forward) = self.nodes[x].levels[i].forward {
+                if traversed + self.nodes[x].levels[i].span > rank {
+                    break;
+                }
+                traversed += self.nodes[x].levels[i].span;
+                x = forward;
+            }
+            if traversed == rank {
+                return if x == HEAD { None } else { Some(x) };
+            }
+        }
+        None
+    }
+    /// First node for which the predicate is false and its 1-based rank.
+    /// The predicate must be true for the leading nodes only.
+    fn first_not<F>(&self, below: F) -> Option<(usize, usize)>
+    where
+        F: Fn(&Node) -> bool,
+    {
+        let mut rank = 0;
+        let mut x = HEAD;
+        for i in (0..self.level).rev() {
+            while let Some(forward) = self.nodes[x].levels[i].forward {
+                if !below(&self.nodes[forward]) {
+                    break;
+                }
+                rank += self.nodes[x].levels[i].span;
+                x = forward;
+            }
+        }
+        self.nodes[x].levels[0].forward.map(|x| (x, rank + 1))
+    }
+    /// Last node for which the predicate is true and its 1-based rank.
+    /// The predicate must be true for the leading nodes only.
+    fn last_within<F>(&self, within: F) -> Option<(usize, usize)>
+    where
+        F: Fn(&Node) -> bool,
+    {
+        let mut rank = 0;
+        let mut x = HEAD;
+        for i in (0..self.level).rev() {
+            while let Some(forward) = self.nodes[x].levels[i].forward {
+                if !within(&self.nodes[forward]) {
+                    break;
+                }
+                rank += self.nodes[x].levels[i].span;
+                x = forward;
+            }
+        }
+        if x == HEAD {
+            None
+        } else {
+            Some((x, rank))
+        }
+    }
+}
+
+/// Sorted set value.
+pub(crate) struct ZSet {
+    /// Scores of the members.
+    scores: HashMap<Vec<u8>, f64>,
+    /// Members ordered by score.
+    list: SkipList,
+}
+
+impl ZSet {
+    /// Create an empty sorted set.
+    pub(crate) fn new() -> Self {
+        ZSet {
+            scores: HashMap::new(),
+            list: SkipList::new(),
+        }
+    }
+    /// Number of the members.
+    pub(crate) fn len(&self) -> usize {
+        self.scores.len()
+    }
+    /// No members or not.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+    /// Score of the member.
+    pub(crate) fn score(&self, member: &[u8]) -> Option<f64> {
+        self.scores.get(member).copied()
+    }
+    /// Set the score of the member.
+    /// Return true if the member is new.
+    pub(crate) fn insert(&mut self, member: Vec<u8>, score: f64) -> bool {
+        match self.scores.get_mut(&member) {
+            Some(current) => {
+                if *current != score {
+                    let old = *current;
+                    *current = score;
+                    let member = self.list.delete(old, &member).unwrap();
+                    self.list.insert(score, member);
+                }
+                false
+            }
+            None => {
+                self.scores.insert(member.clone(), score);
+                self.list.insert(score, member);
+                true
+            }
+        }
+    }
+    /// Remove the member.
+    /// Return true if the member existed.
+    pub(crate) fn remove(&mut self, member: &[u8]) -> bool {
+        match self.scores.remove(member) {
+            Some(score) => {
+                self.list.delete(score, member);
+                true
+            }
+            None => false,
+        }
+    }
+    /// 0-based rank of the member.
+    pub(crate) fn rank(&self, member: &[u8], reverse: bool) -> Option<usize> {
+        let score = self.score(member)?;
+        let rank = self.list.rank(score, member)?;
+        if reverse {
+            Some(self.len() - rank)
+        } else {
+            Some(rank - 1)
+        }
+    }
+    /// Iterate over the members in order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&[u8], f64)> + '_ {
+        self.walk(self.list.nodes[HEAD].levels[0].forward, false)
+    }
+    /// Member at the 0-based rank.
+    pub(crate) fn by_rank(&self, rank: usize) -> Option<(&[u8], f64)> {
+        if rank < self.len() {
+            self.walk(self.list.by_rank(rank + 1), false).next()
+        } else {
+            None
+        }
+    }
+    /// Members between the 0-based ranks inclusive.
+    pub(crate) fn range_by_rank(
+        &self,
+        start: usize,
+        stop: usize,
+        reverse: bool,
+    ) -> Vec<(&[u8], f64)> {
+        let first = if reverse {
+            self.list.by_rank(self.len() - start)
+        } else {
+            self.list.by_rank(start + 1)
+        };
+        self.walk(first, reverse).take(stop - start + 1).collect()
+    }
+    /// Members in the score range.
+    /// Skip offset members and take count members at most.
+    pub(crate) fn range_by_score(
+        &self,
+        range: &ScoreRange,
+        reverse: bool,
+        offset: usize,
+        count: Option<usize>,
+    ) -> Vec<(&[u8], f64)> {
+        let first = if reverse {
+            self.list.last_within(|node| range.lte_max(node.score))
+        } else {
+            self.list.first_not(|node| !range.gte_min(node.score))
+        };
+        self.walk_from(first, reverse, offset)
+            .take_while(|(_, score)| {
+                if reverse {
+                    range.gte_min(*score)
+                } else {
+                    range.lte_max(*score)
+                }
+            })
+            .take(count.unwrap_or(usize::MAX))
+            .collect()
+    }
+    /// Members in the lexicographical range.
+    /// Skip offset members and take count members at most.
+    pub(crate) fn range_by_lex(
+        &self,
+        range: &LexRange,
+        reverse: bool,
+        offset: usize,
+        count: Option<usize>,
+    ) -> Vec<(&[u8], f64)> {
+        let first = if reverse {
+            self.list.last_within(|node| range.lte_max(&node.member))
+        } else {
+            self.list.first_not(|node| !range.gte_min(&node.member))
+        };
+        self.walk_from(first, reverse, offset)
+            .take_while(|(member, _)| {
+                if reverse {
+                    range.gte_min(member)
+                } else {
+                    range.lte_max(member)
+                }
+            })
+            .take(count.unwrap_or(usize::MAX))
+            .collect()
+    }
+    /// Number of the members in the score range.
+    pub(crate) fn count_by_score(&self, range: &ScoreRange) -> usize {
+        let first = self.list.first_not(|node| !range.gte_min(node.score));
+        let last = self.list.last_within(|node| range.lte_max(node.score));
+        match (first, last) {
+            (Some((_, first)), Some((_, last))) if first <= last => last - first + 1,
+            _ => 0,
+        }
+    }
+    /// Number of the members in the lexicographical range.
+    pub(crate) fn count_by_lex(&self, range: &LexRange) -> usize {
+        let first = self.list.first_not(|node| !range.gte_min(&node.member));
+        let last = self.list.last_within(|node| range.lte_max(&node.member));
+        match (first, last) {
+            (Some((_, first)), Some((_, last))) if first <= last => last - first + 1,
+            _ => 0,
+        }
+    }
+    /// Remove the members between the 0-based ranks inclusive.
+    pub(crate) fn remove_range_by_rank(&mut self, start: usize, stop: usize) -> usize {
+        let members = self.owned(self.range_by_rank(start, stop, false));
+        self.remove_all(members)
+    }
+    /// Remove the members in the score range.
+    pub(crate) fn remove_range_by_score(&mut self, range: &ScoreRange) -> usize {
+        let members = self.owned(self.range_by_score(range, false, 0, None));
+        self.remove_all(members)
+    }
+    /// Remove the members in the lexicographical range.
+    pub(crate) fn remove_range_by_lex(&mut self, range: &LexRange) -> usize {
+        let members = self.owned(self.range_by_lex(range, false, 0, None));
+        self.remove_all(members)
+    }
+    /// Remove and return the members with the lowest or the highest scores.
+    pub(crate) fn pop(&mut self, max: bool, count: usize) -> Vec<(Vec<u8>, f64)> {
+        let first = if max {
+            self.list.tail
+        } else {
+            self.list.nodes[HEAD].levels[0].forward
+        };
+        let members = self.owned(self.walk(first, max).take(count).collect());
+        for (member, _) in &members {
+            self.remove(member);
+        }
+        members
+    }
+    /// Copy the members.
+    fn owned(&self, members: Vec<(&[u8], f64)>) -> Vec<(Vec<u8>, f64)> {
+        members
+            .into_iter()
+            .map(|(member, score)| (member.to_vec(), score))
+            .collect()
+    }
+    /// Remove the members.
+    fn remove_all(&mut self, members: Vec<(Vec<u8>, f64)>) -> usize {
+        for (member, _) in &members {
+            self.remove(member);
+        }
+        members.len()
+    }
+    /// Walk from the node with the rank after skipping offset nodes.
+    fn walk_from(
+        &self,
+        first: Option<(usize, usize)>,
+        reverse: bool,
+        offset: usize,
+    ) -> impl Iterator<Item = (&[u8], f64)> + '_ {
+        let start = match first {
+            Some((x, _)) if offset == 0 => Some(x),
+            Some((_, rank)) => {
+                if reverse {
+                    rank.checked_sub(offset)
+                        .and_then(|rank| self.list.by_rank(rank))
+                } else {
+                    rank.checked_add(offset)
+                        .and_then(|rank| self.list.by_rank(rank))
+                }
+            }
+            None => None,
+        };
+        self.walk(start, reverse)
+    }
+    /// Walk the nodes from the node.
+    fn walk(&self, start: Option<usize>, reverse: bool) -> impl Iterator<Item = (&[u8], f64)> + '_ {
+        let mut x = start;
+        std::iter::from_fn(move || {
+            let node = &self.list.nodes[x?];
+            x = if reverse {
+                node.backward
+            } else {
+                node.levels[0].forward
+            };
+            Some((&node.member[..], node.score))
+        })
+    }
+}
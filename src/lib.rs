@@ -6,6 +6,7 @@ pub mod client;
 pub mod protocol;
 pub mod command;
 pub mod db;
+mod random;
 
 /// Dynamic error type.
 pub type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
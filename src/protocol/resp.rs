@@ -3,6 +3,7 @@
 //! <https://redis.io/topics/protocol>
 //! 
 use async_std::io::{prelude::*, Read, Write};
+use futures::future::{BoxFuture, FutureExt};
 use std::{str, str::FromStr, vec};
 
 ///　RESP Data
@@ -22,7 +23,7 @@ pub(crate) enum Data {
 
 impl Data {
     /// Bulk bytes max size.
-    const MAX_BULK_BYTE: i64 = 512 * 1000 * 1000;
+    pub(crate) const MAX_BULK_BYTE: i64 = 512 * 1000 * 1000;
 
     /// helper function. return OK RESP string.
     pub(crate) fn ok() -> Data {
@@ -34,12 +35,12 @@ impl Data {
     }
     /// helper function. return error RESP string.    
     pub(crate) fn error(msg: &str) -> Data {
-        Data::Error(msg.as_bytes().to_vec())
+        Data::Error(format!("ERR {}", msg).into_bytes())
     }
     /// helper function. return error RESP string.    
     pub(crate) fn checked_bulk(value: Vec<u8>) -> Data {
         if value.len() as i64 > Self::MAX_BULK_BYTE {
-            Data::Error(b"Data size is too large.".to_vec())
+            Data::error("Data size is too large.")
         } else {
             Data::Bulk(value)
         }
@@ -58,32 +59,30 @@ impl Encoder {
     }
     /// Encode Data struct into byte data.
     /// 
-    /// Array do not contain arrays.
+    /// Array may contain arrays.
     /// 
     pub(crate) async fn encode<T>(&mut self, stream: &mut T) -> crate::Result<()>
     where
         T: Write + std::marker::Unpin + std::marker::Send,
     {
-        match &mut self.data {
-            Data::Array(array) => {
-                stream.write_all(b"*").await?;
-                stream.write_all(&array.len().to_string().as_bytes()).await?;
-                stream.write_all(b"\r\n").await?;
-                for data in array {
-                    Encoder::_encode(stream, data).await?;
-                }
-            }
-            _ => {
-                Encoder::_encode(stream, &mut self.data).await?;
-            }
-        }
+        Encoder::_encode(stream, &mut self.data).await?;
         stream.flush().await?;
         Ok(())
     }
     /// Encode Data struct into byte data.
     /// 
     /// internal function.
-    async fn _encode<T>(stream: &mut T, data: &mut Data) -> crate::Result<()>
+    /// Boxed to encode nested arrays recursively.
+    fn _encode<'a, T>(stream: &'a mut T, data: &'a mut Data) -> BoxFuture<'a, crate::Result<()>>
+    where
+        T: Write + Unpin + std::marker::Send,
+    {
+        async move { Encoder::encode_data(stream, data).await }.boxed()
+    }
+    /// Encode Data struct into byte data.
+    /// 
+    /// internal function.
+    async fn encode_data<T>(stream: &mut T, data: &mut Data) -> crate::Result<()>
     where
         T: Write + Unpin + std::marker::Send,
     {
@@ -94,7 +93,7 @@ impl Encoder {
                 stream.write_all(b"\r\n").await?;
             }
             Data::Error(error) => {
-                stream.write_all(b"-ERR ").await?;
+                stream.write_all(b"-").await?;
                 stream.write_all(&error[..]).await?;
                 stream.write_all(b"\r\n").await?;
             }
@@ -116,10 +115,16 @@ impl Encoder {
             Data::NullArray => {
                 stream.write_all(b"*-1\r\n").await?;
             }
-            _ => { //ignore.
+            Data::Array(array) => {
+                stream.write_all(b"*").await?;
+                stream.write_all(array.len().to_string().as_bytes()).await?;
+                stream.write_all(b"\r\n").await?;
+                for data in array {
+                    Encoder::_encode(stream, data).await?;
+                }
             }
         }
-        return Ok(());
+        Ok(())
     }
 }
 
@@ -127,13 +132,27 @@ impl Encoder {
 pub(crate) struct Decoder {
     /// Read buffer.
     buffer: Vec<u8>,
+    /// Maximum number of the nested array levels.
+    max_depth: usize,
 }
 
 impl Decoder {
-    /// Create Decoder instance.
+    /// Maximum nesting of the arrays in the responses.
+    const MAX_RESPONSE_DEPTH: usize = 32;
+
+    /// Create Decoder instance for the requests.
+    /// The requests are arrays of bulk strings, so nested arrays are rejected like Redis.
     pub(crate) fn new() -> Self {
         Decoder {
             buffer: Vec::with_capacity(4 * 1024),
+            max_depth: 1,
+        }
+    }
+    /// Create Decoder instance for the responses, which may contain nested arrays.
+    pub(crate) fn for_responses() -> Self {
+        Decoder {
+            buffer: Vec::with_capacity(4 * 1024),
+            max_depth: Self::MAX_RESPONSE_DEPTH,
         }
     }
     /// Decode byte data into Data struct.
@@ -142,60 +161,73 @@ impl Decoder {
         T: BufReadExt + std::marker::Unpin + std::marker::Send
     {
         self.read(stream).await?;
-
+        self._decode(stream, 0).await
+    }
+    /// Decode byte data into Data struct.
+    /// intelnal
+    /// Boxed to decode nested arrays recursively.
+    /// `depth` is the number of the arrays containing the data.
+    fn _decode<'a, T>(
+        &'a mut self,
+        stream: &'a mut T,
+        depth: usize,
+    ) -> BoxFuture<'a, crate::Result<Data>>
+    where
+        T: BufReadExt + std::marker::Unpin + std::marker::Send
+    {
+        async move { self.decode_data(stream, depth).await }.boxed()
+    }
+    /// Decode byte data into Data struct.
+    /// intelnal
+    async fn decode_data<T>(&mut self, stream: &mut T, depth: usize) -> crate::Result<Data>
+    where
+        T: BufReadExt + std::marker::Unpin + std::marker::Send
+    {
         match self.peek_byte() {
             //Arrays
             b'*' => match self.get_integer() {
                 Some(size) => {
-                    if size < 1 {
-                        return Ok(Data::NullArray);
+                    if depth >= self.max_depth {
+                        Err(Box::new(super::Error::ProtcolError))
+                    } else if size < 0 {
+                        Ok(Data::NullArray)
                     } else {
                         let mut array = Vec::with_capacity(size as usize);
                         for _ in 0..size {
                             self.read(stream).await?;
-                            let data = self._decode(stream).await?;
+                            let data = self._decode(stream, depth + 1).await?;
                             array.push(data);
                         }
-                        return Ok(Data::Array(array));
+                        Ok(Data::Array(array))
                     }
                 }
-                None => return Err("protocol error 1".into()),
+                None => Err("protocol error 1".into()),
             },
-            _ => return self._decode(stream).await,
-        }
-    }
-    /// Decode byte data into Data struct.
-    /// intelnal
-    async fn _decode<T>(&mut self, stream: &mut T) -> crate::Result<Data>
-    where
-        T: BufReadExt + std::marker::Unpin + std::marker::Send
-    {
-        match self.peek_byte() {
             //Bulk Strings
             b'$' => match self.get_integer() {
                 Some(len) => {
                     if Data::MAX_BULK_BYTE < len {
-                        return Err("Bulk length is too long".into());
-                    } else if len < 1 {
-                        return Ok(Data::NullBulk);
+                        Err("Bulk length is too long".into())
+                    } else if len < 0 {
+                        Ok(Data::NullBulk)
                     } else {
                         let bulk = self.read_bulk(stream, (len + 2) as usize).await?;
-                        return Ok(Data::Bulk(bulk));
+                        Ok(Data::Bulk(bulk))
                     }
                 }
-                None => return Err("protocol error 2".into()),
+                None => Err("protocol error 2".into()),
             },
             //Integers
             b':' => match self.get_integer() {
-                Some(integer) => return Ok(Data::Integer(integer)),
-                None => return Err("protocol error 3".into()),
+                Some(integer) => Ok(Data::Integer(integer)),
+                None => Err("protocol error 3".into()),
             },
             //Simple Strings
-            b'+' => return Ok(Data::SimpleString(self.get_bytes())),
+            b'+' => Ok(Data::SimpleString(self.get_bytes())),
             //Errors
-            b'-' => return Ok(Data::Error(self.get_bytes())),
+            b'-' => Ok(Data::Error(self.get_bytes())),
             //Unknown
-            _ => return Err("protocol error 4".into()),
+            _ => Err("protocol error 4".into()),
         }
     }
     /// Read bytes from the stream until crlf.
@@ -261,10 +293,7 @@ impl Decoder {
     /// get integer from the buffer.
     fn get_integer(&mut self) -> Option<i64> {
         match std::str::from_utf8(&self.buffer[1..]) {
-            Ok(integer) => match i64::from_str(integer) {
-                Ok(integer) => Some(integer),
-                Err(_e) => None,
-            },
+            Ok(integer) => i64::from_str(integer).ok(),
             Err(_e) => None,
         }
     }    
@@ -304,15 +333,14 @@ impl Parser {
             _ => Err("protocol error 5".into()),
         }
     }
-    ///　Parses Data::Array to extract a u64.
-    pub(crate) fn next_u64(&mut self) -> crate::Result<Option<u64>> {
-        match self.inter.next() {
-            Some(Data::Bulk(bulk)) => Ok(Some(std::str::from_utf8(&bulk[..])?.parse()?)),
-            Some(Data::SimpleString(string)) => {
-                Ok(Some(std::str::from_utf8(&string[..])?.parse()?))
-            }
+    ///　Parses Data::Array to extract a i64.
+    pub(crate) fn next_i64(&mut self) -> crate::Result<Option<i64>> {
+        match self.next_bytes()? {
+            Some(bytes) => match crate::command::parse_integer(&bytes) {
+                Some(integer) => Ok(Some(integer)),
+                None => Err("value is not an integer or out of range".into()),
+            },
             None => Ok(None),
-            _ => Err("protocol error 6".into()),
         }
     }
     /// Parses Data::Array to extract bytes.
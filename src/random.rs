@@ -0,0 +1,41 @@
+//! Pseudo random number generator.
+//!
+//! xorshift64* generator seeded randomly for each thread.
+//! It is not cryptographically secure.
+//!
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+thread_local! {
+    /// Generator state.
+    static STATE: Cell<u64> = Cell::new(seed());
+}
+
+/// Make a random seed.
+fn seed() -> u64 {
+    // RandomState is randomly keyed.
+    let seed = RandomState::new().build_hasher().finish();
+    if seed == 0 {
+        0x9E37_79B9_7F4A_7C15
+    } else {
+        seed
+    }
+}
+
+/// Random u64.
+pub(crate) fn next_u64() -> u64 {
+    STATE.with(|state| {
+        let mut x = state.get();
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        state.set(x);
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    })
+}
+
+/// Random number in [0, n).
+pub(crate) fn below(n: usize) -> usize {
+    (next_u64() % n as u64) as usize
+}
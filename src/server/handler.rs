@@ -13,7 +13,7 @@ use async_std::{
     channel,
     net::TcpStream, 
     prelude::*};
-use futures::{select, FutureExt};
+use futures::{future, select, select_biased, FutureExt};
 use std::net::Shutdown;
 
 // Handler
@@ -77,17 +77,41 @@ impl Handler {
                 }
             };
             //　Execute requested command.
-            let response = command::execute(data).await?;
+            // The blocking command is cancelled by a disconnection or a shutdown.
+            // Other commands run to completion even if the client has half-closed.
+            let response = if command::is_blocking(&data) {
+                select_biased! {
+                    ret = command::execute(data).fuse() => ret?,
+                    _ = Self::disconnected(&self.stream).fuse() => return Ok(()),
+                    void = shutdown_event.next().fuse() => match void {
+                        Some(void) => match void {},
+                        None => {
+                            self.close();
+                            return Ok(());
+                        },
+                    }
+                }
+            } else {
+                command::execute(data).await?
+            };
             // Return a response.
             let mut encoder = Encoder::new(response);
             encoder.encode(&mut writer).await?;
         }
     }
+    /// Wait until the client disconnects.
+    /// Pending forever if the client has sent the next request.
+    async fn disconnected(stream: &TcpStream) {
+        let mut buf = [0; 1];
+        match stream.peek(&mut buf).await {
+            Ok(0) | Err(_) => {}
+            Ok(_) => future::pending().await,
+        }
+    }
     /// Close handler.
     pub(crate) fn close(&mut self) {
-        match self.stream.shutdown(Shutdown::Both) {
-            Err(e) => {eprintln!("{}", e)}
-            Ok(()) => {}
+        if let Err(e) = self.stream.shutdown(Shutdown::Both) {
+            eprintln!("{}", e)
         }
     }
 }
@@ -33,7 +33,7 @@ impl Listener {
         db::open(shutdown_rx.clone()).await;
 
         //Signals to handle
-        let mut signals = Signals::new(&[SIGHUP, SIGTERM, SIGINT, SIGQUIT])?;
+        let mut signals = Signals::new([SIGHUP, SIGTERM, SIGINT, SIGQUIT])?;
 
         loop {
             let mut incoming = self.tcp_listener.incoming();